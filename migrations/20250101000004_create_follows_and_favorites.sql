@@ -0,0 +1,15 @@
+CREATE TABLE user_follows (
+    follower_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    followee_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    PRIMARY KEY (follower_id, followee_id)
+);
+
+CREATE INDEX user_follows_followee_id_idx ON user_follows (followee_id);
+
+CREATE TABLE article_favorites (
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+    PRIMARY KEY (user_id, article_id)
+);
+
+CREATE INDEX article_favorites_article_id_idx ON article_favorites (article_id);
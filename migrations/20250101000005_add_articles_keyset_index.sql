@@ -0,0 +1,4 @@
+-- Keyset pagination orders by (created_at, id); the id tiebreaker keeps pages
+-- stable when several articles share a timestamp.
+DROP INDEX articles_created_at_idx;
+CREATE INDEX articles_created_at_id_idx ON articles (created_at DESC, id DESC);
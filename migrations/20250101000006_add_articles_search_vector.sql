@@ -0,0 +1,9 @@
+-- Weighted so title matches outrank description matches, which outrank body.
+ALTER TABLE articles
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', title), 'A') ||
+        setweight(to_tsvector('english', description), 'B') ||
+        setweight(to_tsvector('english', body), 'C')
+    ) STORED;
+
+CREATE INDEX articles_search_vector_idx ON articles USING GIN (search_vector);
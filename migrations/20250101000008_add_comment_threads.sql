@@ -0,0 +1,8 @@
+-- Replies point at their parent. A deleted comment that still has replies is
+-- kept as a placeholder (deleted_at set, body cleared) so the thread survives.
+ALTER TABLE comments
+    ADD COLUMN parent_id UUID REFERENCES comments (id) ON DELETE CASCADE,
+    ADD COLUMN depth SMALLINT NOT NULL DEFAULT 0,
+    ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX comments_parent_id_idx ON comments (parent_id);
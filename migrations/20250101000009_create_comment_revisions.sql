@@ -0,0 +1,13 @@
+ALTER TABLE comments ADD COLUMN edited_at TIMESTAMPTZ;
+
+-- Bodies replaced by an edit, one row per edit. `created_at` is when that body
+-- was written, `replaced_at` when the edit superseded it.
+CREATE TABLE comment_revisions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    comment_id UUID NOT NULL REFERENCES comments (id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    replaced_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX comment_revisions_comment_id_idx ON comment_revisions (comment_id, replaced_at);
@@ -0,0 +1,20 @@
+-- Immutable snapshots of an article, one per create or edit. `number` counts
+-- from 1 per article; `changed_fields` lists what differs from the previous
+-- revision. Articles that predate this table get their current state as
+-- revision 1.
+CREATE TABLE article_revisions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+    number INTEGER NOT NULL,
+    author_id UUID REFERENCES users (id) ON DELETE SET NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT NOT NULL,
+    body TEXT NOT NULL,
+    changed_fields TEXT[] NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (article_id, number)
+);
+
+INSERT INTO article_revisions (article_id, number, author_id, title, description, body, changed_fields, created_at)
+SELECT id, 1, author_id, title, description, body, ARRAY['title', 'description', 'body'], updated_at
+FROM articles;
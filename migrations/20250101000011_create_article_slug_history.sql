@@ -0,0 +1,10 @@
+-- Slugs an article had before a title change, so old links keep resolving.
+-- A slug is never both live and retired: it leaves this table when an article
+-- takes it again.
+CREATE TABLE article_slug_history (
+    slug VARCHAR(255) PRIMARY KEY,
+    article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX article_slug_history_article_id_idx ON article_slug_history (article_id);
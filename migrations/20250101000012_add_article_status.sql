@@ -0,0 +1,12 @@
+-- Articles can be written ahead of time and only go live once published.
+-- Existing articles were all live, so they start out published.
+ALTER TABLE articles
+    ADD COLUMN status TEXT NOT NULL DEFAULT 'published'
+        CHECK (status IN ('draft', 'scheduled', 'published', 'archived')),
+    ADD COLUMN publish_at TIMESTAMPTZ,
+    ADD CONSTRAINT articles_scheduled_publish_at_check
+        CHECK (status <> 'scheduled' OR publish_at IS NOT NULL);
+
+-- Lets the scheduler find articles that are due without scanning the table.
+CREATE INDEX articles_scheduled_publish_at_idx ON articles (publish_at)
+    WHERE status = 'scheduled';
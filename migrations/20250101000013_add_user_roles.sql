@@ -0,0 +1,4 @@
+-- Moderators can remove any article or comment; admins can also manage users.
+ALTER TABLE users
+    ADD COLUMN role TEXT NOT NULL DEFAULT 'user'
+        CHECK (role IN ('user', 'moderator', 'admin'));
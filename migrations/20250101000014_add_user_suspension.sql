@@ -0,0 +1,6 @@
+-- Admins can lock an account, or make its owner choose a new password before
+-- signing in again.
+ALTER TABLE users
+    ADD COLUMN suspended_at TIMESTAMPTZ,
+    ADD COLUMN suspension_reason TEXT,
+    ADD COLUMN password_reset_required BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,28 @@
+-- Moderators can hide reported content; it stays in place for them only.
+ALTER TABLE articles ADD COLUMN hidden_at TIMESTAMPTZ;
+ALTER TABLE comments ADD COLUMN hidden_at TIMESTAMPTZ;
+
+-- Reports point at an article, or at one of its comments when comment_id is
+-- set. They stay open until a moderator resolves every report on the target.
+CREATE TABLE reports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+    comment_id UUID REFERENCES comments (id) ON DELETE CASCADE,
+    reporter_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    reason TEXT NOT NULL
+        CHECK (reason IN ('spam', 'harassment', 'hate_speech', 'misinformation', 'other')),
+    details TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ,
+    resolved_by UUID REFERENCES users (id) ON DELETE SET NULL,
+    resolution TEXT CHECK (resolution IN ('dismiss', 'hide', 'suspend_author')),
+    CONSTRAINT reports_resolved_check CHECK ((resolved_at IS NULL) = (resolution IS NULL))
+);
+
+-- One open report per reader and target.
+CREATE UNIQUE INDEX reports_open_reporter_key ON reports (reporter_id, article_id, comment_id)
+    NULLS NOT DISTINCT
+    WHERE resolved_at IS NULL;
+
+CREATE INDEX reports_open_target_idx ON reports (article_id, comment_id)
+    WHERE resolved_at IS NULL;
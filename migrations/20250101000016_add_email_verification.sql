@@ -0,0 +1,4 @@
+-- Accounts from before verification existed keep working as they did.
+ALTER TABLE users ADD COLUMN email_verified_at TIMESTAMPTZ;
+
+UPDATE users SET email_verified_at = created_at;
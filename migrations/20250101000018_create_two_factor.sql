@@ -0,0 +1,21 @@
+-- TOTP credentials, at most one per user. The shared secret is encrypted with
+-- TOTP_ENCRYPTION_KEY, and 2FA is on once the user has confirmed a first code.
+CREATE TABLE user_totp (
+    user_id UUID PRIMARY KEY REFERENCES users (id) ON DELETE CASCADE,
+    secret_encrypted BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    confirmed_at TIMESTAMPTZ,
+    -- Time step of the last accepted code, so no code works twice.
+    last_used_step BIGINT
+);
+
+-- Single-use codes for when the authenticator is lost; only their SHA-256 is
+-- stored.
+CREATE TABLE totp_recovery_codes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    code_hash TEXT NOT NULL,
+    used_at TIMESTAMPTZ
+);
+
+CREATE INDEX totp_recovery_codes_user_id_idx ON totp_recovery_codes (user_id);
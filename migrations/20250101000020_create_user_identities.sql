@@ -0,0 +1,28 @@
+-- Accounts created through an identity provider have no password until the
+-- user sets one.
+ALTER TABLE users ALTER COLUMN password_hash DROP NOT NULL;
+
+-- Accounts at OpenID Connect providers that can sign in as a user. `subject`
+-- is the provider's stable id for the account; the email is kept for display.
+CREATE TABLE user_identities (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    provider TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    email TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (provider, subject)
+);
+
+CREATE INDEX user_identities_user_id_idx ON user_identities (user_id);
+
+-- Sign-ins in progress: the PKCE verifier and nonce for a `state` handed to
+-- the provider. `user_id` is set when a signed-in user links an identity.
+CREATE TABLE oidc_logins (
+    state_hash TEXT PRIMARY KEY,
+    provider TEXT NOT NULL,
+    code_verifier TEXT NOT NULL,
+    nonce TEXT NOT NULL,
+    user_id UUID REFERENCES users (id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ NOT NULL
+);
@@ -1,3 +1,8 @@
+use crate::model::values::slug::{SlugFallback, SlugOptions};
+use crate::utils::rate_limit::{LockoutPolicy, TokenBucket};
+use serde::Deserialize;
+use std::fmt::{Debug, Formatter};
+use std::time::Duration;
 use tryphon::{Config, ConfigValueDecoder, ErrorPrintMode, Secret};
 
 #[derive(Debug, Config, Clone)]
@@ -36,6 +41,9 @@ pub struct DatabaseConfig {
     #[env("DATABASE_MAX_CONNECTIONS")]
     #[default(50)]
     pub(crate) max_connections: u32,
+    #[env("DATABASE_RUN_MIGRATIONS")]
+    #[default(true)]
+    pub(crate) run_migrations: bool,
 }
 
 impl DatabaseConfig {
@@ -52,6 +60,311 @@ pub struct SecretsConfig {
     #[env("JWT_SECRET")]
     #[default(Secret("default_jwt_secret_change_in_production".to_string()))]
     pub jwt: Secret<String>,
+    /// Encrypts the TOTP secrets of users with 2FA. Changing it turns their
+    /// authenticators useless, leaving only their recovery codes.
+    #[env("TOTP_ENCRYPTION_KEY")]
+    #[default(Secret("default_totp_key_change_in_production".to_string()))]
+    pub totp_encryption_key: Secret<String>,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct JwtConfig {
+    /// Directory of `<kid>.pem` RSA or Ed25519 private keys. When unset,
+    /// access tokens are signed with HS256 using `JWT_SECRET`.
+    #[env("JWT_KEYS_DIR")]
+    pub keys_dir: Option<String>,
+    /// Key in `keys_dir` that signs new tokens; the others only verify.
+    #[env("JWT_ACTIVE_KEY_ID")]
+    pub active_key_id: Option<String>,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct AuthConfig {
+    #[env("AUTH_ACCESS_TOKEN_TTL_SECS")]
+    #[default(900)]
+    pub(crate) access_token_ttl_secs: i64,
+    #[env("AUTH_REFRESH_TOKEN_TTL_DAYS")]
+    #[default(30)]
+    pub(crate) refresh_token_ttl_days: i64,
+    /// Whether users must verify their email before publishing.
+    #[env("AUTH_EMAIL_VERIFICATION_REQUIRED")]
+    #[default(true)]
+    pub(crate) email_verification_required: bool,
+    #[env("AUTH_EMAIL_VERIFICATION_TTL_HOURS")]
+    #[default(24)]
+    pub(crate) email_verification_ttl_hours: i64,
+    #[env("AUTH_PASSWORD_RESET_TTL_MINS")]
+    #[default(60)]
+    pub(crate) password_reset_ttl_mins: i64,
+    /// Least time between two reset emails to the same user.
+    #[env("AUTH_PASSWORD_RESET_INTERVAL_SECS")]
+    #[default(60)]
+    pub(crate) password_reset_interval_secs: i64,
+    /// Name authenticator apps list the account under.
+    #[env("AUTH_TOTP_ISSUER")]
+    #[default("Conduit")]
+    pub(crate) totp_issuer: String,
+    /// Time a user with 2FA has to enter a code after their password.
+    #[env("AUTH_LOGIN_CHALLENGE_TTL_SECS")]
+    #[default(300)]
+    pub(crate) login_challenge_ttl_secs: i64,
+}
+
+#[derive(Debug, Clone, Copy, ConfigValueDecoder)]
+pub enum CookieSameSite {
+    Strict,
+    Lax,
+    /// Sent on cross-site requests too; browsers require `Secure` with it.
+    None,
+}
+
+/// Opt-in sessions for browser apps: logins also set the access and refresh
+/// tokens as HttpOnly cookies, which then authenticate requests in place of
+/// the `Authorization` header. Unsafe methods authenticated by cookie must
+/// echo the `csrf_token` cookie in an `X-CSRF-Token` header.
+#[derive(Debug, Config, Clone)]
+pub struct SessionCookieConfig {
+    #[env("SESSION_COOKIES_ENABLED")]
+    #[default(false)]
+    pub(crate) enabled: bool,
+    #[env("SESSION_COOKIE_SAME_SITE")]
+    #[default(CookieSameSite::Lax)]
+    pub(crate) same_site: CookieSameSite,
+    /// Only send the cookies over HTTPS; turn off for local development.
+    #[env("SESSION_COOKIE_SECURE")]
+    #[default(true)]
+    pub(crate) secure: bool,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct LoginLimitConfig {
+    /// Login and registration attempts one IP or email can make at once.
+    #[env("LOGIN_RATE_LIMIT_BURST")]
+    #[default(10)]
+    pub(crate) burst: u32,
+    /// Attempts regained per minute once the burst is used up.
+    #[env("LOGIN_RATE_LIMIT_PER_MINUTE")]
+    #[default(10)]
+    pub(crate) per_minute: u32,
+    /// Failed logins in a row that lock an IP or email out.
+    #[env("LOGIN_LOCKOUT_THRESHOLD")]
+    #[default(5)]
+    pub(crate) lockout_threshold: u32,
+    /// Length of the first lockout; each further one doubles it.
+    #[env("LOGIN_LOCKOUT_BASE_SECS")]
+    #[default(30)]
+    pub(crate) lockout_base_secs: u64,
+    #[env("LOGIN_LOCKOUT_MAX_SECS")]
+    #[default(3600)]
+    pub(crate) lockout_max_secs: u64,
+}
+
+impl LoginLimitConfig {
+    pub(crate) fn bucket(&self) -> TokenBucket {
+        TokenBucket {
+            capacity: self.burst,
+            refill_per_minute: self.per_minute,
+        }
+    }
+
+    pub(crate) fn lockout_policy(&self) -> LockoutPolicy {
+        LockoutPolicy {
+            threshold: self.lockout_threshold,
+            base: Duration::from_secs(self.lockout_base_secs),
+            max: Duration::from_secs(self.lockout_max_secs),
+        }
+    }
+}
+
+/// Limits on the rest of the API, per signed-in user or, for anonymous
+/// clients, per IP. Reads are `GET`, `HEAD` and `OPTIONS`; everything else is
+/// a write.
+#[derive(Debug, Config, Clone)]
+pub struct ApiLimitConfig {
+    #[env("API_RATE_LIMIT_ENABLED")]
+    #[default(true)]
+    pub(crate) enabled: bool,
+    #[env("API_ANONYMOUS_READ_BURST")]
+    #[default(60)]
+    pub(crate) anonymous_read_burst: u32,
+    #[env("API_ANONYMOUS_READS_PER_MINUTE")]
+    #[default(60)]
+    pub(crate) anonymous_reads_per_minute: u32,
+    #[env("API_ANONYMOUS_WRITE_BURST")]
+    #[default(10)]
+    pub(crate) anonymous_write_burst: u32,
+    #[env("API_ANONYMOUS_WRITES_PER_MINUTE")]
+    #[default(10)]
+    pub(crate) anonymous_writes_per_minute: u32,
+    #[env("API_USER_READ_BURST")]
+    #[default(120)]
+    pub(crate) user_read_burst: u32,
+    #[env("API_USER_READS_PER_MINUTE")]
+    #[default(120)]
+    pub(crate) user_reads_per_minute: u32,
+    #[env("API_USER_WRITE_BURST")]
+    #[default(30)]
+    pub(crate) user_write_burst: u32,
+    #[env("API_USER_WRITES_PER_MINUTE")]
+    #[default(30)]
+    pub(crate) user_writes_per_minute: u32,
+}
+
+impl ApiLimitConfig {
+    pub(crate) fn anonymous_read_bucket(&self) -> TokenBucket {
+        TokenBucket {
+            capacity: self.anonymous_read_burst,
+            refill_per_minute: self.anonymous_reads_per_minute,
+        }
+    }
+
+    pub(crate) fn anonymous_write_bucket(&self) -> TokenBucket {
+        TokenBucket {
+            capacity: self.anonymous_write_burst,
+            refill_per_minute: self.anonymous_writes_per_minute,
+        }
+    }
+
+    pub(crate) fn user_read_bucket(&self) -> TokenBucket {
+        TokenBucket {
+            capacity: self.user_read_burst,
+            refill_per_minute: self.user_reads_per_minute,
+        }
+    }
+
+    pub(crate) fn user_write_bucket(&self) -> TokenBucket {
+        TokenBucket {
+            capacity: self.user_write_burst,
+            refill_per_minute: self.user_writes_per_minute,
+        }
+    }
+}
+
+#[derive(Debug, Clone, ConfigValueDecoder)]
+pub enum MailTransport {
+    /// Writes messages to the log.
+    Log,
+    /// Saves messages as files in `MAIL_FILE_DIR`.
+    File,
+    Smtp,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct MailConfig {
+    #[env("MAIL_TRANSPORT")]
+    #[default(MailTransport::Log)]
+    pub(crate) transport: MailTransport,
+    #[env("MAIL_FROM")]
+    #[default("Conduit <noreply@localhost>")]
+    pub(crate) from: String,
+    /// Frontend address that links in emails point to.
+    #[env("MAIL_LINK_BASE_URL")]
+    #[default("http://localhost:3000")]
+    pub(crate) link_base_url: String,
+    #[env("MAIL_FILE_DIR")]
+    #[default("mail")]
+    pub(crate) file_dir: String,
+    #[env("SMTP_HOST")]
+    #[default("localhost")]
+    pub(crate) smtp_host: String,
+    #[env("SMTP_PORT")]
+    #[default(587)]
+    pub(crate) smtp_port: u16,
+    #[env("SMTP_STARTTLS")]
+    #[default(true)]
+    pub(crate) smtp_starttls: bool,
+    #[env("SMTP_USERNAME")]
+    pub(crate) smtp_username: Option<String>,
+    #[env("SMTP_PASSWORD")]
+    pub(crate) smtp_password: Option<Secret<String>>,
+}
+
+/// An OpenID Connect provider users can sign in with.
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OidcProviderConfig {
+    /// Short name that identifies the provider in URLs, e.g. `google`.
+    pub(crate) name: String,
+    /// The provider's `.well-known/openid-configuration` document.
+    pub(crate) discovery_url: String,
+    pub(crate) client_id: String,
+    pub(crate) client_secret: String,
+    /// Frontend page the provider sends users back to. It has to be
+    /// registered with the provider, and posts the code it receives to the
+    /// callback endpoint.
+    pub(crate) redirect_uri: String,
+}
+
+impl Debug for OidcProviderConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcProviderConfig")
+            .field("name", &self.name)
+            .field("discovery_url", &self.discovery_url)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"***")
+            .field("redirect_uri", &self.redirect_uri)
+            .finish()
+    }
+}
+
+/// Providers as a JSON array of objects with `name`, `discoveryUrl`,
+/// `clientId`, `clientSecret` and `redirectUri`.
+#[derive(Debug, Clone, Default)]
+pub struct OidcProviders(pub(crate) Vec<OidcProviderConfig>);
+
+impl ConfigValueDecoder for OidcProviders {
+    fn decode(raw: String) -> Result<Self, String> {
+        serde_json::from_str(&raw)
+            .map(OidcProviders)
+            .map_err(|e| format!("Invalid provider list: {e}"))
+    }
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct OidcConfig {
+    #[env("OIDC_PROVIDERS")]
+    #[default(OidcProviders::default())]
+    pub(crate) providers: OidcProviders,
+    /// Time a user has to sign in at the provider and come back.
+    #[env("OIDC_LOGIN_TTL_SECS")]
+    #[default(600)]
+    pub(crate) login_ttl_secs: i64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct HealthConfig {
+    #[env("HEALTH_CHECK_TIMEOUT_MS")]
+    #[default(2000)]
+    pub(crate) check_timeout_ms: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct PublishingConfig {
+    /// How often scheduled articles are checked and published when due.
+    #[env("PUBLISH_SCHEDULER_INTERVAL_SECS")]
+    #[default(30)]
+    pub(crate) scheduler_interval_secs: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct SlugConfig {
+    /// Upper bound for slugs generated from titles, at most 255.
+    #[env("SLUG_MAX_LENGTH")]
+    #[default(100)]
+    pub(crate) max_length: usize,
+    /// Slug for titles with no letters or digits: `RandomId` or `Untitled`.
+    #[env("SLUG_FALLBACK")]
+    #[default(SlugFallback::RandomId)]
+    pub(crate) fallback: SlugFallback,
+}
+
+impl SlugConfig {
+    pub(crate) fn options(&self) -> SlugOptions {
+        SlugOptions {
+            max_length: self.max_length,
+            fallback: self.fallback,
+        }
+    }
 }
 
 #[derive(Debug, Clone, ConfigValueDecoder)]
@@ -79,6 +392,26 @@ pub struct AppConfig {
     pub secrets: SecretsConfig,
     #[config]
     pub tracing: TracingConfig,
+    #[config]
+    pub jwt: JwtConfig,
+    #[config]
+    pub auth: AuthConfig,
+    #[config]
+    pub session_cookies: SessionCookieConfig,
+    #[config]
+    pub login_limits: LoginLimitConfig,
+    #[config]
+    pub api_limits: ApiLimitConfig,
+    #[config]
+    pub mail: MailConfig,
+    #[config]
+    pub oidc: OidcConfig,
+    #[config]
+    pub health: HealthConfig,
+    #[config]
+    pub slugs: SlugConfig,
+    #[config]
+    pub publishing: PublishingConfig,
 }
 
 pub fn load_config() -> AppConfig {
@@ -1,7 +1,10 @@
 use crate::http::dto::error::ErrorResponse;
+use crate::model::scope::Scope;
 use axum::Json;
 use axum::http::StatusCode;
+use axum::http::header::RETRY_AFTER;
 use axum::response::{IntoResponse, Response};
+use std::time::Duration;
 use thiserror::Error;
 use tracing::error;
 
@@ -13,10 +16,28 @@ pub enum AppError {
     Unauthorized,
     #[error("Forbidden")]
     Forbidden,
+    #[error("Account is suspended")]
+    AccountSuspended,
+    #[error("Password reset required")]
+    PasswordResetRequired,
+    #[error("Email not verified")]
+    EmailNotVerified,
+    /// A personal access token was used without the scope the action needs.
+    #[error("Missing scope: {0:?}")]
+    MissingScope(Scope),
+    /// Only a signed-in session may do this, not a personal access token.
+    #[error("Session required")]
+    SessionRequired,
+    /// A cookie-authenticated write without the matching CSRF header.
+    #[error("CSRF token mismatch")]
+    CsrfTokenMismatch,
     #[error("Bad request: {0}")]
     BadData(String),
     #[error("Conflict: {0}")]
     DataConflict(String),
+    /// Carries how long the client should wait before trying again.
+    #[error("Too many requests")]
+    TooManyRequests(Duration),
     #[error("Database error")]
     Db(#[from] sqlx::Error),
     #[error("Internal error: {0}")]
@@ -44,6 +65,51 @@ impl IntoResponse for AppError {
             )
                 .into_response(),
 
+            AppError::AccountSuspended => (
+                StatusCode::FORBIDDEN,
+                Json::from(ErrorResponse::new("Account is suspended".into())),
+            )
+                .into_response(),
+
+            AppError::PasswordResetRequired => (
+                StatusCode::FORBIDDEN,
+                Json::from(ErrorResponse::new(
+                    "Password reset required before signing in".into(),
+                )),
+            )
+                .into_response(),
+
+            AppError::EmailNotVerified => (
+                StatusCode::FORBIDDEN,
+                Json::from(ErrorResponse::new(
+                    "Verify your email address first".into(),
+                )),
+            )
+                .into_response(),
+
+            AppError::MissingScope(scope) => (
+                StatusCode::FORBIDDEN,
+                Json::from(ErrorResponse::new(format!(
+                    "Token is missing the {} scope",
+                    scope.as_str()
+                ))),
+            )
+                .into_response(),
+
+            AppError::SessionRequired => (
+                StatusCode::FORBIDDEN,
+                Json::from(ErrorResponse::new(
+                    "Personal access tokens cannot be used here".into(),
+                )),
+            )
+                .into_response(),
+
+            AppError::CsrfTokenMismatch => (
+                StatusCode::FORBIDDEN,
+                Json::from(ErrorResponse::new("Missing or invalid CSRF token".into())),
+            )
+                .into_response(),
+
             AppError::BadData(msg) => (
                 StatusCode::UNPROCESSABLE_ENTITY,
                 Json::from(ErrorResponse::new(msg)),
@@ -63,6 +129,18 @@ impl IntoResponse for AppError {
                 (StatusCode::CONFLICT, Json::from(ErrorResponse::new(msg))).into_response()
             }
 
+            AppError::TooManyRequests(retry_after) => {
+                let retry_after_secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(RETRY_AFTER, retry_after_secs.to_string())],
+                    Json::from(ErrorResponse::new(
+                        "Too many attempts, try again later".into(),
+                    )),
+                )
+                    .into_response()
+            }
+
             AppError::Other(err) => {
                 error!("Internal: {err:?}");
                 (
@@ -1,20 +1,50 @@
-use crate::app_config::load_config;
-use crate::database::connect_db;
+use crate::app_config::{MailConfig, MailTransport, load_config};
+use crate::database::{connect_db, run_migrations};
+use crate::persistence::access_token_repository::AccessTokenRepository;
 use crate::persistence::article_repository::ArticleRepository;
+use crate::persistence::article_revision_repository::ArticleRevisionRepository;
 use crate::persistence::comment_repository::CommentRepository;
+use crate::persistence::health_repository::HealthRepository;
+use crate::persistence::identity_repository::IdentityRepository;
+use crate::persistence::oidc_login_repository::OidcLoginRepository;
+use crate::persistence::password_reset_repository::PasswordResetRepository;
 use crate::persistence::profile_repository::ProfileRepository;
+use crate::persistence::report_repository::ReportRepository;
+use crate::persistence::session_repository::SessionRepository;
 use crate::persistence::tag_repository::TagRepository;
+use crate::persistence::totp_repository::TotpRepository;
 use crate::persistence::user_repository::UserRepository;
 use crate::server::init_server;
 use crate::tracing::init_tracing;
 use crate::utils::hasher::Hasher;
+use crate::utils::jwt::jwt_keys::JwtKeys;
+use crate::utils::mailer::Mailer;
+use crate::utils::mailer::file::FileMailer;
+use crate::utils::mailer::log::LogMailer;
+use crate::utils::mailer::smtp::SmtpMailer;
+use crate::utils::oidc::OidcClient;
+use crate::utils::rate_limit::in_memory::InMemoryBackend;
+use crate::utils::secret_cipher::SecretCipher;
 use crate::{domain, http};
+use domain::access_token_service::AccessTokenService;
+use domain::api_rate_limiter::ApiRateLimiter;
 use domain::article_service::ArticleService;
+use domain::auth_service::AuthService;
 use domain::comment_service::CommentService;
+use domain::email_verification_service::EmailVerificationService;
+use domain::health_service::HealthService;
+use domain::login_limiter::LoginLimiter;
+use domain::oidc_service::OidcService;
+use domain::password_reset_service::PasswordResetService;
 use domain::profile_service::ProfileService;
+use domain::report_service::ReportService;
+use domain::publish_scheduler::spawn_publish_scheduler;
 use domain::tag_service::TagService;
+use domain::two_factor_service::TwoFactorService;
 use domain::user_service::UserService;
 use http::AppState;
+use std::sync::Arc;
+use std::time::Duration;
 use tracing::info;
 
 pub async fn start_app() {
@@ -34,28 +64,150 @@ pub async fn create_app_state(config: &crate::app_config::AppConfig) -> AppState
         .await
         .expect("Failed to connect to database");
 
-    let jwt = config.secrets.jwt.0.clone();
+    if config.database.run_migrations {
+        run_migrations(&db)
+            .await
+            .expect("Failed to apply database migrations");
+    }
+
+    let jwt_keys = match &config.jwt.keys_dir {
+        Some(dir) => JwtKeys::from_dir(dir, config.jwt.active_key_id.as_deref().unwrap_or_default())
+            .expect("Failed to load JWT signing keys"),
+        None => JwtKeys::from_secret(&config.secrets.jwt.0),
+    };
     let hasher = Hasher::new(config.secrets.pepper.0.clone());
 
     let user_repo = UserRepository::new(db.clone());
     let article_repo = ArticleRepository::new(db.clone());
+    let article_revision_repo = ArticleRevisionRepository::new(db.clone());
     let tag_repo = TagRepository::new(db.clone());
     let comment_repo = CommentRepository::new(db.clone());
     let profile_repo = ProfileRepository::new(db.clone());
+    let health_repo = HealthRepository::new(db.clone());
+    let session_repo = SessionRepository::new(db.clone());
+    let report_repo = ReportRepository::new(db.clone());
+    let password_reset_repo = PasswordResetRepository::new(db.clone());
+    let totp_repo = TotpRepository::new(db.clone());
+    let access_token_repo = AccessTokenRepository::new(db.clone());
+    let oidc_login_repo = OidcLoginRepository::new(db.clone());
+    let identity_repo = IdentityRepository::new(db.clone());
+    let mailer = create_mailer(&config.mail);
 
-    let user_service = UserService::new(user_repo, hasher);
-    let article_service = ArticleService::new(article_repo, tag_repo.clone());
+    let user_service = UserService::new(user_repo.clone(), hasher.clone());
+    let password_reset_service = PasswordResetService::new(
+        password_reset_repo,
+        user_repo.clone(),
+        hasher,
+        mailer.clone(),
+        chrono::Duration::minutes(config.auth.password_reset_ttl_mins),
+        chrono::Duration::seconds(config.auth.password_reset_interval_secs),
+        config.mail.link_base_url.clone(),
+    );
+    let email_verification_service = EmailVerificationService::new(
+        user_repo.clone(),
+        jwt_keys.clone(),
+        mailer,
+        chrono::Duration::hours(config.auth.email_verification_ttl_hours),
+        config.mail.link_base_url.clone(),
+    );
+    let auth_service = AuthService::new(
+        session_repo,
+        user_repo.clone(),
+        jwt_keys.clone(),
+        chrono::Duration::seconds(config.auth.access_token_ttl_secs),
+        chrono::Duration::days(config.auth.refresh_token_ttl_days),
+    );
+    let access_token_service = AccessTokenService::new(access_token_repo, user_repo.clone());
+    let oidc_service = OidcService::new(
+        config.oidc.providers.0.clone(),
+        OidcClient::new(Duration::from_secs(10)).expect("Failed to create OpenID Connect client"),
+        oidc_login_repo,
+        identity_repo,
+        user_repo.clone(),
+        chrono::Duration::seconds(config.oidc.login_ttl_secs),
+    );
+    let report_service = ReportService::new(report_repo, article_repo.clone(), comment_repo.clone());
+    let article_service = ArticleService::new(
+        article_repo,
+        article_revision_repo,
+        tag_repo.clone(),
+        user_repo.clone(),
+        config.slugs.options(),
+        config.auth.email_verification_required,
+    );
+    spawn_publish_scheduler(
+        article_service.clone(),
+        Duration::from_secs(config.publishing.scheduler_interval_secs),
+    );
     let comment_service = CommentService::new(comment_repo);
     let tag_service = TagService::new(tag_repo);
     let profile_service = ProfileService::new(profile_repo);
+    let health_service = HealthService::new(
+        health_repo,
+        Duration::from_millis(config.health.check_timeout_ms),
+    );
+
+    let rate_limit_backend = Arc::new(InMemoryBackend::new());
+    let login_limiter = LoginLimiter::new(
+        rate_limit_backend.clone(),
+        config.login_limits.bucket(),
+        config.login_limits.lockout_policy(),
+    );
+    let api_rate_limiter = ApiRateLimiter::new(rate_limit_backend, config.api_limits.clone());
+    let two_factor_service = TwoFactorService::new(
+        totp_repo,
+        user_repo,
+        login_limiter.clone(),
+        SecretCipher::new(&config.secrets.totp_encryption_key.0),
+        jwt_keys,
+        config.auth.totp_issuer.clone(),
+        chrono::Duration::seconds(config.auth.login_challenge_ttl_secs),
+    );
 
     AppState {
         user_service,
+        auth_service,
+        access_token_service,
+        email_verification_service,
+        password_reset_service,
+        two_factor_service,
+        oidc_service,
         article_service,
         comment_service,
         tag_service,
         profile_service,
+        report_service,
+        health_service,
+        login_limiter,
+        api_rate_limiter,
         config: config.clone(),
-        jwt,
+    }
+}
+
+fn create_mailer(config: &MailConfig) -> Arc<dyn Mailer> {
+    match config.transport {
+        MailTransport::Log => Arc::new(LogMailer),
+        MailTransport::File => Arc::new(FileMailer::new(&config.file_dir)),
+        MailTransport::Smtp => {
+            let credentials = config.smtp_username.clone().map(|username| {
+                let password = config
+                    .smtp_password
+                    .as_ref()
+                    .map(|password| password.0.clone())
+                    .unwrap_or_default();
+                (username, password)
+            });
+
+            Arc::new(
+                SmtpMailer::new(
+                    &config.smtp_host,
+                    config.smtp_port,
+                    config.smtp_starttls,
+                    credentials,
+                    &config.from,
+                )
+                .expect("Failed to configure SMTP mailer"),
+            )
+        }
     }
 }
@@ -1,8 +1,11 @@
 use crate::app_config::DatabaseConfig;
+use sqlx::migrate::{MigrateError, Migrator};
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::{Error, Pool, Postgres};
 use tracing::info;
 
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
 #[derive(Clone)]
 pub struct Database(Pool<Postgres>);
 
@@ -41,3 +44,17 @@ pub async fn connect_db(config: &DatabaseConfig) -> Result<Database, Error> {
     Ok(Database(db))
 }
 
+/// Applies every embedded migration that is not yet recorded in `_sqlx_migrations`.
+///
+/// Fails if an already applied migration no longer matches its recorded checksum,
+/// if the database knows a version that is missing from the binary, or if a previous
+/// run left a migration half-applied.
+pub async fn run_migrations(db: &Database) -> Result<(), MigrateError> {
+    info!("Applying database migrations ({} known)", MIGRATOR.iter().count());
+
+    MIGRATOR.run(db.pool()).await?;
+
+    info!("Database migrations are up to date");
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use crate::app_error::AppError;
+use crate::domain::commands::create_access_token_command::CreateAccessTokenCommand;
+use crate::model::indexed_user_field::IndexedUserField;
+use crate::model::persistence::personal_access_token::PersonalAccessToken;
+use crate::model::role::Role;
+use crate::model::values::access_token_id::AccessTokenId;
+use crate::model::values::user_id::UserId;
+use crate::persistence::access_token_repository::AccessTokenRepository;
+use crate::persistence::params::insert_access_token_params::InsertAccessTokenParams;
+use crate::persistence::user_repository::UserRepository;
+use crate::utils::opaque_token::{hash_token, random_token};
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use tracing::info;
+
+/// Sets personal access tokens apart from JWTs, which never start with it.
+const TOKEN_PREFIX: &str = "pat_";
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_EXPIRY_DAYS: i64 = 365;
+
+/// Whether `token` looks like a personal access token rather than a JWT.
+pub fn is_access_token(token: &str) -> bool {
+    token.starts_with(TOKEN_PREFIX)
+}
+
+/// Personal access tokens: long-lived credentials users create for scripts,
+/// limited to the scopes they were created with.
+#[derive(Clone)]
+pub struct AccessTokenService {
+    access_token_repo: AccessTokenRepository,
+    user_repo: UserRepository,
+}
+
+impl AccessTokenService {
+    pub fn new(access_token_repo: AccessTokenRepository, user_repo: UserRepository) -> Self {
+        AccessTokenService {
+            access_token_repo,
+            user_repo,
+        }
+    }
+
+    /// Returns the token with its secret, which is only stored hashed and
+    /// cannot be shown again.
+    pub async fn create_token(
+        &self,
+        command: CreateAccessTokenCommand,
+    ) -> Result<(PersonalAccessToken, String), AppError> {
+        if command.name.is_empty() || command.name.chars().count() > MAX_NAME_LENGTH {
+            return Err(AppError::BadData(format!(
+                "Token name must be between 1 and {MAX_NAME_LENGTH} characters"
+            )));
+        }
+        let expires_at = match command.expires_in_days {
+            Some(days) if !(1..=MAX_EXPIRY_DAYS).contains(&days) => {
+                return Err(AppError::BadData(format!(
+                    "expiresInDays must be between 1 and {MAX_EXPIRY_DAYS}"
+                )));
+            }
+            Some(days) => Some(Utc::now() + Duration::days(days)),
+            None => None,
+        };
+
+        let secret = format!("{TOKEN_PREFIX}{}", random_token());
+        let token = self
+            .access_token_repo
+            .insert_token(InsertAccessTokenParams {
+                user_id: command.user_id,
+                name: command.name,
+                token_hash: hash_token(&secret),
+                scopes: command.scopes,
+                expires_at,
+            })
+            .await?;
+
+        info!(
+            "Created personal access token {} for user {}",
+            token.id, token.user_id
+        );
+
+        Ok((token, secret))
+    }
+
+    pub async fn list_tokens(&self, user_id: UserId) -> Result<Vec<PersonalAccessToken>, AppError> {
+        self.access_token_repo.list_tokens(user_id).await
+    }
+
+    pub async fn revoke_token(&self, user_id: UserId, id: AccessTokenId) -> Result<(), AppError> {
+        if !self.access_token_repo.revoke_token(user_id, id).await? {
+            return Err(AppError::NotFound);
+        }
+
+        info!("Revoked personal access token {} of user {}", id, user_id);
+
+        Ok(())
+    }
+
+    /// Owner of an active token, for telling clients apart; use
+    /// `authenticate` to let one in.
+    pub async fn token_user_id(&self, token: &str) -> Result<Option<UserId>, AppError> {
+        self.access_token_repo
+            .find_user_id(&hash_token(token))
+            .await
+    }
+
+    /// The token and the current role of its user. Like sessions, tokens of
+    /// suspended users stop working.
+    pub async fn authenticate(&self, token: &str) -> Result<(PersonalAccessToken, Role), AppError> {
+        let token = self
+            .access_token_repo
+            .use_token(&hash_token(token))
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+
+        let user = self
+            .user_repo
+            .get_user_by(IndexedUserField::Id, token.user_id)
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+        if user.suspended_at.is_some() {
+            return Err(AppError::AccountSuspended);
+        }
+
+        Ok((token, user.role))
+    }
+}
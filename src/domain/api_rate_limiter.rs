@@ -0,0 +1,76 @@
+use crate::app_config::ApiLimitConfig;
+use crate::model::values::user_id::UserId;
+use crate::utils::rate_limit::{Quota, RateLimitBackend, TokenBucket};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Which limits apply to a route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoutePolicy {
+    Read,
+    Write,
+}
+
+impl RoutePolicy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RoutePolicy::Read => "read",
+            RoutePolicy::Write => "write",
+        }
+    }
+}
+
+/// Who a request is counted against.
+#[derive(Debug, Clone, Copy)]
+pub enum Client {
+    User(UserId),
+    Ip(IpAddr),
+}
+
+/// Outcome of a rate limited request, with the limit it was measured against.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitDecision {
+    pub limit: u32,
+    pub result: Result<Quota, Duration>,
+}
+
+/// Throttles the API per client, with separate budgets for reads and writes
+/// and more room for signed-in users than for anonymous ones.
+#[derive(Clone)]
+pub struct ApiRateLimiter {
+    backend: Arc<dyn RateLimitBackend>,
+    config: ApiLimitConfig,
+}
+
+impl ApiRateLimiter {
+    pub fn new(backend: Arc<dyn RateLimitBackend>, config: ApiLimitConfig) -> Self {
+        ApiRateLimiter { backend, config }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn bucket(&self, policy: RoutePolicy, client: Client) -> TokenBucket {
+        match (client, policy) {
+            (Client::User(_), RoutePolicy::Read) => self.config.user_read_bucket(),
+            (Client::User(_), RoutePolicy::Write) => self.config.user_write_bucket(),
+            (Client::Ip(_), RoutePolicy::Read) => self.config.anonymous_read_bucket(),
+            (Client::Ip(_), RoutePolicy::Write) => self.config.anonymous_write_bucket(),
+        }
+    }
+
+    pub async fn acquire(&self, policy: RoutePolicy, client: Client) -> RateLimitDecision {
+        let bucket = self.bucket(policy, client);
+        let key = match client {
+            Client::User(user_id) => format!("api:{}:user:{user_id}", policy.as_str()),
+            Client::Ip(ip) => format!("api:{}:ip:{ip}", policy.as_str()),
+        };
+
+        RateLimitDecision {
+            limit: bucket.capacity,
+            result: self.backend.acquire(&key, bucket).await,
+        }
+    }
+}
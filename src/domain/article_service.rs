@@ -1,45 +1,138 @@
 use crate::app_error::AppError;
 use crate::domain::commands::create_article_command::CreateArticleCommand;
+use crate::domain::commands::get_drafts_query::GetDraftsQuery;
 use crate::domain::commands::get_feed_query::GetFeedQuery;
 use crate::domain::commands::list_articles_query::ListArticlesQuery;
+use crate::domain::commands::search_articles_query::SearchArticlesQuery;
 use crate::domain::commands::update_article_command::UpdateArticleCommand;
+use crate::domain::policy::{Action, Actor, authorize};
+use crate::model::article_field::ArticleField;
+use crate::model::article_page::ArticlePage;
+use crate::model::article_status::{ArticleStatus, Publication};
 use crate::model::indexed_article_field::IndexedArticleField;
-use crate::model::persistence::article_view::{ArticleListView, ArticleView};
-use crate::model::values::slug::Slug;
+use crate::model::indexed_user_field::IndexedUserField;
+use crate::model::line_diff::{DiffLine, diff_lines};
+use crate::model::persistence::article::Article;
+use crate::model::persistence::article_revision::ArticleRevision;
+use crate::model::persistence::article_view::{ArticleSearchView, ArticleView};
+use crate::model::values::slug::{Slug, SlugOptions};
 use crate::model::values::tag_name::TagName;
 use crate::model::values::user_id::UserId;
 use crate::persistence::article_repository::ArticleRepository;
+use crate::persistence::article_revision_repository::ArticleRevisionRepository;
 use crate::persistence::params::list_articles_params::ListArticlesParams;
 use crate::persistence::tag_repository::TagRepository;
+use crate::persistence::user_repository::UserRepository;
 use anyhow::Result;
+use chrono::Utc;
+use rand::Rng;
+
+/// Slugs tried for one article before giving up: the title-derived one, then
+/// variants with a random suffix.
+const MAX_SLUG_ATTEMPTS: usize = 5;
+
+fn slug_candidate(base: &Slug, attempt: usize, options: &SlugOptions) -> Slug {
+    if attempt == 0 {
+        base.clone()
+    } else {
+        let suffix = format!("{:04x}", rand::rng().random::<u16>());
+        base.with_suffix(&suffix, options.max_length)
+    }
+}
+
+fn no_free_slug(base: &Slug) -> AppError {
+    AppError::DataConflict(format!("Could not find a free slug for '{}'", base))
+}
 
 #[derive(Clone)]
 pub struct ArticleService {
     article_repo: ArticleRepository,
+    revision_repo: ArticleRevisionRepository,
     tag_repo: TagRepository,
+    user_repo: UserRepository,
+    slug_options: SlugOptions,
+    /// Whether authors must have verified their email to publish.
+    verification_required: bool,
 }
 
 impl ArticleService {
-    pub fn new(article_repo: ArticleRepository, tag_repo: TagRepository) -> Self {
+    pub fn new(
+        article_repo: ArticleRepository,
+        revision_repo: ArticleRevisionRepository,
+        tag_repo: TagRepository,
+        user_repo: UserRepository,
+        slug_options: SlugOptions,
+        verification_required: bool,
+    ) -> Self {
         ArticleService {
             article_repo,
+            revision_repo,
             tag_repo,
+            user_repo,
+            slug_options,
+            verification_required,
         }
     }
 
-    async fn verify_slug(&self, slug: &Slug) -> Result<(), AppError> {
-        if self
+    /// Unverified authors may keep drafts, but not publish or schedule them.
+    async fn check_can_publish(&self, author_id: UserId, publication: Publication) -> Result<(), AppError> {
+        if !self.verification_required
+            || !matches!(publication.status, ArticleStatus::Published | ArticleStatus::Scheduled)
+        {
+            return Ok(());
+        }
+
+        let author = self
+            .user_repo
+            .get_user_by(IndexedUserField::Id, author_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if !author.is_verified() {
+            return Err(AppError::EmailNotVerified);
+        }
+
+        Ok(())
+    }
+
+    /// The article's current slug, when `slug` is one it had before a title
+    /// change.
+    pub async fn get_moved_slug(&self, slug: &Slug) -> Result<Option<Slug>, AppError> {
+        self.article_repo.get_current_slug(slug).await
+    }
+
+    /// Looks an article up by its live slug, falling back to the slug history.
+    async fn get_article_model(&self, slug: &Slug) -> Result<Article, AppError> {
+        if let Some(article) = self
             .article_repo
             .get_article_by(IndexedArticleField::Slug, slug)
             .await?
-            .is_some()
         {
-            Err(AppError::DataConflict(format!(
-                "Article with slug '{}' already exists",
-                slug
-            )))
+            return Ok(article);
+        }
+
+        let current_slug = self.get_moved_slug(slug).await?.ok_or(AppError::NotFound)?;
+
+        self.article_repo
+            .get_article_by(IndexedArticleField::Slug, &current_slug)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// Like `get_article_model`, but hides unpublished articles from anyone
+    /// but their author, and moderated ones from everyone.
+    async fn get_visible_article(
+        &self,
+        slug: &Slug,
+        user_id: Option<UserId>,
+    ) -> Result<Article, AppError> {
+        let article = self.get_article_model(slug).await?;
+
+        if article.hidden_at.is_none()
+            && (article.status == ArticleStatus::Published || user_id == Some(article.author_id))
+        {
+            Ok(article)
         } else {
-            Ok(())
+            Err(AppError::NotFound)
         }
     }
 
@@ -47,12 +140,26 @@ impl ArticleService {
         &self,
         command: CreateArticleCommand,
     ) -> Result<ArticleView, AppError> {
-        let slug = Slug::from_title(command.title.value());
+        let publication = Publication::from_request(command.status, command.publish_at, Utc::now())
+            .map_err(AppError::BadData)?
+            .unwrap_or(Publication::PUBLISHED);
+        self.check_can_publish(command.author_id, publication).await?;
+        let base_slug = Slug::from_title(command.title.value(), &self.slug_options);
 
-        self.verify_slug(&slug).await?;
-
-        let params = command.to_insert_params(slug);
-        let article = self.article_repo.insert_article(params).await?;
+        // The unique index decides who gets a slug, so concurrent inserts of
+        // the same title cannot both take it.
+        let mut inserted = None;
+        for attempt in 0..MAX_SLUG_ATTEMPTS {
+            let params = command.to_insert_params(
+                slug_candidate(&base_slug, attempt, &self.slug_options),
+                publication,
+            );
+            inserted = self.article_repo.insert_article(params).await?;
+            if inserted.is_some() {
+                break;
+            }
+        }
+        let article = inserted.ok_or_else(|| no_free_slug(&base_slug))?;
 
         let tag_ids = self.get_or_create_tags(&command.tag_list).await?;
         self.article_repo
@@ -71,61 +178,139 @@ impl ArticleService {
         slug: &Slug,
         user_id: Option<UserId>,
     ) -> Result<Option<ArticleView>, AppError> {
-        self.article_repo
+        let article = self
+            .article_repo
             .get_article_view_by(IndexedArticleField::Slug, slug, user_id)
-            .await
+            .await?;
+
+        if article.is_some() {
+            return Ok(article);
+        }
+
+        match self.get_moved_slug(slug).await? {
+            Some(current_slug) => {
+                self.article_repo
+                    .get_article_view_by(IndexedArticleField::Slug, &current_slug, user_id)
+                    .await
+            }
+            None => Ok(None),
+        }
     }
 
     pub async fn update_article(
         &self,
         command: UpdateArticleCommand,
-        user_id: UserId,
+        actor: &Actor,
     ) -> Result<ArticleView, AppError> {
-        let article = self
-            .article_repo
-            .get_article_by(IndexedArticleField::Slug, &command.old_slug)
-            .await?
-            .ok_or(AppError::NotFound)?;
+        let article = self.get_article_model(&command.old_slug).await?;
+        if article.hidden_at.is_some() {
+            return Err(AppError::NotFound);
+        }
 
-        let params = command.to_params(article.id);
+        authorize(actor, Action::UpdateArticle, article.author_id)?;
+        let user_id = actor.user_id;
 
-        if article.author_id != user_id {
-            Err(AppError::Forbidden)
-        } else {
-            if let Some(ref slug) = params.slug {
-                self.verify_slug(slug).await?;
-            }
-
-            let article = self.article_repo.update_article(params).await?;
-            Ok(self
-                .article_repo
-                .get_article_by_id(article.id, Some(user_id))
-                .await?)
+        let publication = Publication::from_request(command.status, command.publish_at, Utc::now())
+            .map_err(AppError::BadData)?;
+        if let Some(publication) = publication {
+            self.check_can_publish(user_id, publication).await?;
         }
-    }
+        let mut params = command.to_params(&article, user_id, &self.slug_options, publication);
+        let base_slug = params.slug.clone();
 
-    pub async fn delete_article(&self, slug: Slug, user_id: UserId) -> Result<(), AppError> {
-        let article = self
-            .article_repo
-            .get_article_by(IndexedArticleField::Slug, &slug)
-            .await?;
+        for attempt in 0..MAX_SLUG_ATTEMPTS {
+            params.slug = base_slug
+                .as_ref()
+                .map(|base| slug_candidate(base, attempt, &self.slug_options));
 
-        if let Some(article) = article {
-            if article.author_id != user_id {
-                Err(AppError::Forbidden)
-            } else {
-                self.article_repo.delete_article(article.id).await
+            if let Some(article) = self.article_repo.update_article(&params).await? {
+                return self
+                    .article_repo
+                    .get_article_by_id(article.id, Some(user_id))
+                    .await;
             }
-        } else {
-            Err(AppError::NotFound)
         }
+
+        Err(no_free_slug(base_slug.as_ref().unwrap_or(&article.slug)))
+    }
+
+    pub async fn list_revisions(
+        &self,
+        slug: &Slug,
+        user_id: Option<UserId>,
+    ) -> Result<Vec<ArticleRevision>, AppError> {
+        let article = self.get_visible_article(slug, user_id).await?;
+
+        self.revision_repo.list_revisions(article.id).await
+    }
+
+    pub async fn get_revision(
+        &self,
+        slug: &Slug,
+        number: i32,
+        user_id: Option<UserId>,
+    ) -> Result<ArticleRevision, AppError> {
+        let article = self.get_visible_article(slug, user_id).await?;
+
+        self.revision_repo
+            .get_revision(article.id, number)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// Line diffs of every content field, going from revision `from` to `to`.
+    pub async fn diff_revisions(
+        &self,
+        slug: &Slug,
+        from: i32,
+        to: i32,
+        user_id: Option<UserId>,
+    ) -> Result<Vec<(ArticleField, Vec<DiffLine>)>, AppError> {
+        let old = self.get_revision(slug, from, user_id).await?;
+        let new = self.get_revision(slug, to, user_id).await?;
+
+        Ok(ArticleField::ALL
+            .into_iter()
+            .map(|field| {
+                let lines = match field {
+                    ArticleField::Title => diff_lines(old.title.value(), new.title.value()),
+                    ArticleField::Description => {
+                        diff_lines(old.description.value(), new.description.value())
+                    }
+                    ArticleField::Body => diff_lines(old.body.value(), new.body.value()),
+                };
+                (field, lines)
+            })
+            .collect())
+    }
+
+    /// Makes the content of revision `number` current again. The restore is
+    /// itself recorded as a new revision.
+    pub async fn restore_revision(
+        &self,
+        slug: Slug,
+        number: i32,
+        actor: &Actor,
+    ) -> Result<ArticleView, AppError> {
+        let revision = self.get_revision(&slug, number, Some(actor.user_id)).await?;
+
+        self.update_article(UpdateArticleCommand::from_revision(revision, slug), actor)
+            .await
+    }
+
+    pub async fn delete_article(&self, slug: Slug, actor: &Actor) -> Result<(), AppError> {
+        let article = self.get_article_model(&slug).await?;
+
+        authorize(actor, Action::DeleteArticle, article.author_id)?;
+
+        self.article_repo.delete_article(article.id).await
     }
 
     pub async fn list_articles(
         &self,
         query: ListArticlesQuery,
         user_id: Option<UserId>,
-    ) -> Result<Vec<ArticleListView>, AppError> {
+    ) -> Result<ArticlePage, AppError> {
         self.article_repo
             .list_articles(ListArticlesParams::from_query(query, user_id))
             .await
@@ -141,22 +326,54 @@ impl ArticleService {
             .await
     }
 
+    pub async fn search_articles(
+        &self,
+        query: SearchArticlesQuery,
+        user_id: Option<UserId>,
+    ) -> Result<Vec<ArticleSearchView>, AppError> {
+        self.article_repo
+            .search_articles(ListArticlesParams::from_search_query(query, user_id))
+            .await
+    }
+
+    pub async fn count_search_results(
+        &self,
+        query: SearchArticlesQuery,
+        user_id: Option<UserId>,
+    ) -> Result<u64, AppError> {
+        self.article_repo
+            .count_articles(ListArticlesParams::from_search_query(query, user_id))
+            .await
+    }
+
     pub(crate) async fn count_feed_articles(&self, user_id: UserId) -> Result<u64, AppError> {
         self.article_repo.count_feed_articles(user_id).await
     }
 
-    pub async fn get_feed(&self, query: GetFeedQuery) -> Result<Vec<ArticleListView>, AppError> {
+    pub async fn get_feed(&self, query: GetFeedQuery) -> Result<ArticlePage, AppError> {
+        self.article_repo
+            .get_feed_articles(query.user_id, query.limit, query.offset, query.cursor)
+            .await
+    }
+
+    /// Unpublished articles of the user, for them to finish and publish.
+    pub async fn get_drafts(&self, query: GetDraftsQuery) -> Result<ArticlePage, AppError> {
         self.article_repo
-            .get_feed_articles(query.user_id, query.limit, query.offset)
+            .get_draft_articles(query.user_id, query.limit, query.offset, query.cursor)
             .await
     }
 
+    pub(crate) async fn count_drafts(&self, user_id: UserId) -> Result<u64, AppError> {
+        self.article_repo.count_draft_articles(user_id).await
+    }
+
+    /// Publishes the scheduled articles that are due.
+    pub async fn publish_scheduled_articles(&self) -> Result<u64, AppError> {
+        self.article_repo.publish_due_articles().await
+    }
+
     pub async fn favorite_article(&self, user_id: UserId, slug: &Slug) -> Result<(), AppError> {
-        let article = self
-            .article_repo
-            .get_article_by(IndexedArticleField::Slug, slug)
-            .await?
-            .ok_or(AppError::NotFound)?;
+        let article = self.get_visible_article(slug, Some(user_id)).await?;
 
         self.article_repo
             .favorite_article(user_id, article.id)
@@ -164,11 +381,7 @@ impl ArticleService {
     }
 
     pub async fn unfavorite_article(&self, user_id: UserId, slug: &Slug) -> Result<(), AppError> {
-        let article = self
-            .article_repo
-            .get_article_by(IndexedArticleField::Slug, slug)
-            .await?
-            .ok_or(AppError::NotFound)?;
+        let article = self.get_visible_article(slug, Some(user_id)).await?;
 
         self.article_repo
             .unfavorite_article(user_id, article.id)
@@ -0,0 +1,160 @@
+use crate::app_error::AppError;
+use crate::model::indexed_user_field::IndexedUserField;
+use crate::model::role::Role;
+use crate::model::token_pair::TokenPair;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_session_params::InsertSessionParams;
+use crate::persistence::session_repository::SessionRepository;
+use crate::persistence::user_repository::UserRepository;
+use crate::utils::jwt::jwt_keys::JwtKeys;
+use crate::utils::jwt::{Claims, generate_token, verify_token};
+use crate::utils::opaque_token::{hash_token, random_token};
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::jwk::JwkSet;
+use tracing::warn;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct AuthService {
+    session_repo: SessionRepository,
+    user_repo: UserRepository,
+    keys: JwtKeys,
+    access_token_ttl: Duration,
+    refresh_token_ttl: Duration,
+}
+
+impl AuthService {
+    pub fn new(
+        session_repo: SessionRepository,
+        user_repo: UserRepository,
+        keys: JwtKeys,
+        access_token_ttl: Duration,
+        refresh_token_ttl: Duration,
+    ) -> Self {
+        AuthService {
+            session_repo,
+            user_repo,
+            keys,
+            access_token_ttl,
+            refresh_token_ttl,
+        }
+    }
+
+    pub async fn create_session(&self, user_id: UserId, role: Role) -> Result<TokenPair, AppError> {
+        let refresh_token = random_token();
+
+        let session = self
+            .session_repo
+            .insert_session(InsertSessionParams {
+                user_id,
+                refresh_token_hash: hash_token(&refresh_token),
+                expires_at: Utc::now() + self.refresh_token_ttl,
+            })
+            .await?;
+
+        let access_token =
+            generate_token(&self.keys, user_id, session.id, role, self.access_token_ttl)?;
+
+        Ok(TokenPair {
+            access_token,
+            refresh_token,
+        })
+    }
+
+    /// Exchanges a refresh token for a new pair. Presenting a token that was
+    /// already rotated means it leaked, so the whole session is revoked.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<TokenPair, AppError> {
+        let current_hash = hash_token(refresh_token);
+        let next_refresh_token = random_token();
+
+        let session = self
+            .session_repo
+            .rotate_refresh_token(&current_hash, &hash_token(&next_refresh_token))
+            .await?;
+
+        let Some(session) = session else {
+            if self
+                .session_repo
+                .revoke_session_by_previous_hash(&current_hash)
+                .await?
+            {
+                warn!("Rotated refresh token was reused, session revoked");
+            }
+            return Err(AppError::Unauthorized);
+        };
+
+        // The role is read again so that role changes reach the new token.
+        let user = self
+            .user_repo
+            .get_user_by(IndexedUserField::Id, session.user_id)
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+
+        let access_token = generate_token(
+            &self.keys,
+            session.user_id,
+            session.id,
+            user.role,
+            self.access_token_ttl,
+        )?;
+
+        Ok(TokenPair {
+            access_token,
+            refresh_token: next_refresh_token,
+        })
+    }
+
+    pub async fn authenticate(&self, token: &str) -> Result<Claims, AppError> {
+        let claims = verify_token(&self.keys, token)?;
+
+        // Checked before revocation: suspending a user revokes their sessions
+        // too, but a clear error is more useful to the client than a 401.
+        let user_id: Uuid = claims.sub.parse().map_err(|_| AppError::Unauthorized)?;
+        let user = self
+            .user_repo
+            .get_user_by(IndexedUserField::Id, UserId::from(user_id))
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+        if user.suspended_at.is_some() {
+            return Err(AppError::AccountSuspended);
+        }
+
+        if self
+            .session_repo
+            .is_token_revoked(claims.jti, claims.sid)
+            .await?
+        {
+            return Err(AppError::Unauthorized);
+        }
+
+        Ok(claims)
+    }
+
+    /// User a token was issued to, judging by its signature alone. Good enough
+    /// to tell clients apart, never to let one in: use `authenticate` for that.
+    pub fn token_user_id(&self, token: &str) -> Option<UserId> {
+        let claims = verify_token(&self.keys, token).ok()?;
+        claims.sub.parse::<Uuid>().ok().map(UserId::from)
+    }
+
+    pub fn jwks(&self) -> &JwkSet {
+        self.keys.jwks()
+    }
+
+    pub async fn logout(
+        &self,
+        user_id: UserId,
+        claims: &Claims,
+        all_sessions: bool,
+    ) -> Result<(), AppError> {
+        let expires_at = DateTime::from_timestamp(claims.exp, 0).unwrap_or_else(Utc::now);
+        self.session_repo.revoke_token(claims.jti, expires_at).await?;
+
+        if all_sessions {
+            self.session_repo.revoke_all_sessions(user_id).await
+        } else {
+            self.session_repo.revoke_session(claims.sid).await
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::http::dto::comment::CreateCommentRequest;
 use crate::model::values::article_id::ArticleId;
 use crate::model::values::comment_body::CommentBody;
+use crate::model::values::comment_id::CommentId;
 use crate::model::values::user_id::UserId;
 use crate::persistence::params::insert_comment_params::InsertCommentParams;
 
@@ -9,6 +10,7 @@ pub struct AddCommentCommand {
     pub body: CommentBody,
     pub article_id: ArticleId,
     pub author_id: UserId,
+    pub parent_id: Option<CommentId>,
 }
 
 impl AddCommentCommand {
@@ -21,14 +23,17 @@ impl AddCommentCommand {
             body: request.comment.body,
             article_id,
             author_id,
+            parent_id: request.comment.parent_id,
         }
     }
 
-    pub fn to_insert_params(&self) -> InsertCommentParams {
+    pub fn to_insert_params(&self, depth: i16) -> InsertCommentParams {
         InsertCommentParams {
             body: self.body.clone(),
             article_id: self.article_id,
             author_id: self.author_id,
+            parent_id: self.parent_id,
+            depth,
         }
     }
 }
@@ -0,0 +1,26 @@
+use crate::http::dto::access_token::CreateAccessTokenRequest;
+use crate::model::scope::Scope;
+use crate::model::values::user_id::UserId;
+
+#[derive(Debug, Clone)]
+pub struct CreateAccessTokenCommand {
+    pub user_id: UserId,
+    pub name: String,
+    pub scopes: Vec<Scope>,
+    pub expires_in_days: Option<i64>,
+}
+
+impl CreateAccessTokenCommand {
+    pub fn from_request(request: CreateAccessTokenRequest, user_id: UserId) -> Self {
+        let mut scopes = request.access_token.scopes;
+        scopes.sort_by_key(Scope::as_str);
+        scopes.dedup();
+
+        CreateAccessTokenCommand {
+            user_id,
+            name: request.access_token.name.trim().to_string(),
+            scopes,
+            expires_in_days: request.access_token.expires_in_days,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::http::dto::article::CreateArticleRequest;
+use crate::model::article_status::{ArticleStatus, Publication};
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_title::ArticleTitle;
@@ -6,6 +7,7 @@ use crate::model::values::slug::Slug;
 use crate::model::values::tag_name::TagName;
 use crate::model::values::user_id::UserId;
 use crate::persistence::params::insert_article_params::InsertArticleParams;
+use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
 pub struct CreateArticleCommand {
@@ -14,6 +16,8 @@ pub struct CreateArticleCommand {
     pub body: ArticleBody,
     pub tag_list: Vec<TagName>,
     pub author_id: UserId,
+    pub status: Option<ArticleStatus>,
+    pub publish_at: Option<DateTime<Utc>>,
 }
 
 impl CreateArticleCommand {
@@ -24,16 +28,19 @@ impl CreateArticleCommand {
             body: dto.article.body,
             tag_list: dto.article.tag_list.unwrap_or_default(),
             author_id,
+            status: dto.article.status,
+            publish_at: dto.article.publish_at,
         }
     }
 
-    pub fn to_insert_params(&self, slug: Slug) -> InsertArticleParams {
+    pub fn to_insert_params(&self, slug: Slug, publication: Publication) -> InsertArticleParams {
         InsertArticleParams {
             slug,
             title: self.title.clone(),
             description: self.description.clone(),
             body: self.body.clone(),
             author_id: self.author_id,
+            publication,
         }
     }
 }
@@ -0,0 +1,24 @@
+use crate::http::dto::article::ArticleFeedListQuery;
+use crate::model::cursor::Cursor;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::values::user_id::UserId;
+
+#[derive(Debug, Clone)]
+pub struct GetDraftsQuery {
+    pub user_id: UserId,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+    pub cursor: Option<Cursor>,
+}
+
+impl GetDraftsQuery {
+    pub fn from_request(dto: ArticleFeedListQuery, user_id: UserId) -> Self {
+        GetDraftsQuery {
+            user_id,
+            limit: dto.limit,
+            offset: dto.offset,
+            cursor: dto.cursor,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::http::dto::article::ArticleFeedListQuery;
+use crate::model::cursor::Cursor;
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
 use crate::model::values::user_id::UserId;
@@ -8,6 +9,7 @@ pub struct GetFeedQuery {
     pub user_id: UserId,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
+    pub cursor: Option<Cursor>,
 }
 
 impl GetFeedQuery {
@@ -16,6 +18,7 @@ impl GetFeedQuery {
             user_id,
             limit: dto.limit,
             offset: dto.offset,
+            cursor: dto.cursor,
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::http::dto::article::ArticleListQuery as ArticleListQueryDto;
+use crate::model::cursor::Cursor;
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
 use crate::model::values::tag_name::TagName;
@@ -11,6 +12,7 @@ pub struct ListArticlesQuery {
     pub favorited_by: Option<Username>,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
+    pub cursor: Option<Cursor>,
 }
 
 impl ListArticlesQuery {
@@ -21,6 +23,7 @@ impl ListArticlesQuery {
             favorited_by: dto.favorited,
             limit: dto.limit,
             offset: dto.offset,
+            cursor: dto.cursor,
         }
     }
 }
@@ -0,0 +1,22 @@
+use crate::http::dto::admin::AdminUserListQuery;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+
+#[derive(Debug, Clone)]
+pub struct ListUsersQuery {
+    pub search: Option<String>,
+    pub suspended: Option<bool>,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl ListUsersQuery {
+    pub fn from_request(dto: AdminUserListQuery) -> Self {
+        ListUsersQuery {
+            search: dto.q.filter(|q| !q.trim().is_empty()),
+            suspended: dto.suspended,
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
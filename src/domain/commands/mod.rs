@@ -1,8 +1,14 @@
 pub mod add_comment_command;
+pub mod create_access_token_command;
 pub mod create_article_command;
+pub mod get_drafts_query;
 pub mod get_feed_query;
 pub mod list_articles_query;
+pub mod list_users_query;
 pub mod login_command;
 pub mod register_command;
+pub mod report_content_command;
+pub mod search_articles_query;
 pub mod update_article_command;
+pub mod update_comment_command;
 pub mod update_user_command;
@@ -0,0 +1,38 @@
+use crate::http::dto::report::CreateReportRequest;
+use crate::model::report::ReportReason;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::report_details::ReportDetails;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_report_params::InsertReportParams;
+
+#[derive(Debug, Clone)]
+pub struct ReportContentCommand {
+    pub reason: ReportReason,
+    pub details: Option<ReportDetails>,
+    pub reporter_id: UserId,
+}
+
+impl ReportContentCommand {
+    pub fn from_request(request: CreateReportRequest, reporter_id: UserId) -> Self {
+        ReportContentCommand {
+            reason: request.report.reason,
+            details: request.report.details.filter(|details| !details.is_empty()),
+            reporter_id,
+        }
+    }
+
+    pub fn to_insert_params(
+        &self,
+        article_id: ArticleId,
+        comment_id: Option<CommentId>,
+    ) -> InsertReportParams {
+        InsertReportParams {
+            article_id,
+            comment_id,
+            reporter_id: self.reporter_id,
+            reason: self.reason,
+            details: self.details.clone(),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::http::dto::article::ArticleSearchQuery as ArticleSearchQueryDto;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::values::search_terms::SearchTerms;
+use crate::model::values::tag_name::TagName;
+use crate::model::values::username::Username;
+
+#[derive(Debug, Clone)]
+pub struct SearchArticlesQuery {
+    pub terms: SearchTerms,
+    pub tag: Option<TagName>,
+    pub author: Option<Username>,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl SearchArticlesQuery {
+    pub fn from_request(dto: ArticleSearchQueryDto) -> Self {
+        SearchArticlesQuery {
+            terms: dto.q,
+            tag: dto.tag,
+            author: dto.author,
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
@@ -1,44 +1,90 @@
 use crate::http::dto::article::UpdateArticleRequest;
+use crate::model::article_field::ArticleField;
+use crate::model::article_status::{ArticleStatus, Publication};
+use crate::model::persistence::article::Article;
+use crate::model::persistence::article_revision::ArticleRevision;
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
-use crate::model::values::article_id::ArticleId;
 use crate::model::values::article_title::ArticleTitle;
-use crate::model::values::slug::Slug;
+use crate::model::values::slug::{Slug, SlugOptions};
+use crate::model::values::user_id::UserId;
 use crate::persistence::params::update_article_params::UpdateArticleParams;
+use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
 pub struct UpdateArticleCommand {
     pub old_slug: Slug,
-    pub new_slug: Option<Slug>,
     pub title: Option<ArticleTitle>,
     pub description: Option<ArticleDescription>,
     pub body: Option<ArticleBody>,
+    pub status: Option<ArticleStatus>,
+    pub publish_at: Option<DateTime<Utc>>,
 }
 
 impl UpdateArticleCommand {
     pub fn from_request(dto: UpdateArticleRequest, slug: Slug) -> Self {
-        let new_slug = dto
-            .article
-            .title
-            .as_ref()
-            .map(|t| Slug::from_title(t.value()));
-
         UpdateArticleCommand {
             old_slug: slug,
-            new_slug,
             title: dto.article.title,
             description: dto.article.description,
             body: dto.article.body,
+            status: dto.article.status,
+            publish_at: dto.article.publish_at,
+        }
+    }
+
+    /// Puts the content of an earlier revision back, as a regular edit.
+    pub fn from_revision(revision: ArticleRevision, slug: Slug) -> Self {
+        UpdateArticleCommand {
+            old_slug: slug,
+            title: Some(revision.title),
+            description: Some(revision.description),
+            body: Some(revision.body),
+            status: None,
+            publish_at: None,
         }
     }
 
-    pub fn to_params(&self, article_id: ArticleId) -> UpdateArticleParams {
+    pub fn to_params(
+        &self,
+        article: &Article,
+        editor_id: UserId,
+        slug_options: &SlugOptions,
+        publication: Option<Publication>,
+    ) -> UpdateArticleParams {
+        let mut changed_fields = vec![];
+        let title_changed = self.title.as_ref().is_some_and(|title| *title != article.title);
+        if title_changed {
+            changed_fields.push(ArticleField::Title);
+        }
+        if self
+            .description
+            .as_ref()
+            .is_some_and(|description| *description != article.description)
+        {
+            changed_fields.push(ArticleField::Description);
+        }
+        if self.body.as_ref().is_some_and(|body| *body != article.body) {
+            changed_fields.push(ArticleField::Body);
+        }
+
         UpdateArticleParams {
-            article_id,
-            slug: self.new_slug.clone(),
+            article_id: article.id,
+            previous_slug: article.slug.clone(),
+            // An unchanged title keeps the slug, including any suffix it got
+            // to stay unique.
+            slug: self
+                .title
+                .as_ref()
+                .filter(|_| title_changed)
+                .map(|title| Slug::from_title(title.value(), slug_options))
+                .filter(|slug| *slug != article.slug),
             title: self.title.clone(),
             description: self.description.clone(),
             body: self.body.clone(),
+            publication,
+            editor_id,
+            changed_fields,
         }
     }
 }
@@ -0,0 +1,33 @@
+use crate::http::dto::comment::UpdateCommentRequest;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::comment_body::CommentBody;
+use crate::model::values::comment_id::CommentId;
+use crate::persistence::params::update_comment_params::UpdateCommentParams;
+
+#[derive(Debug, Clone)]
+pub struct UpdateCommentCommand {
+    pub comment_id: CommentId,
+    pub article_id: ArticleId,
+    pub body: CommentBody,
+}
+
+impl UpdateCommentCommand {
+    pub fn from_request(
+        request: UpdateCommentRequest,
+        article_id: ArticleId,
+        comment_id: CommentId,
+    ) -> Self {
+        UpdateCommentCommand {
+            comment_id,
+            article_id,
+            body: request.comment.body,
+        }
+    }
+
+    pub fn to_params(&self) -> UpdateCommentParams {
+        UpdateCommentParams {
+            comment_id: self.comment_id,
+            body: self.body.clone(),
+        }
+    }
+}
@@ -1,5 +1,10 @@
 use crate::app_error::AppError;
 use crate::domain::commands::add_comment_command::AddCommentCommand;
+use crate::domain::commands::update_comment_command::UpdateCommentCommand;
+use crate::domain::policy::{Action, Actor, authorize};
+use crate::model::comment_thread::CommentThread;
+use crate::model::persistence::comment::Comment;
+use crate::model::persistence::comment_revision::CommentRevision;
 use crate::model::persistence::comment_view::CommentView;
 use crate::model::values::article_id::ArticleId;
 use crate::model::values::comment_id::CommentId;
@@ -7,6 +12,9 @@ use crate::model::values::user_id::UserId;
 use crate::persistence::comment_repository::CommentRepository;
 use anyhow::Result;
 
+/// Replies nested deeper than this are rejected to keep threads readable.
+const MAX_COMMENT_DEPTH: i16 = 8;
+
 #[derive(Clone)]
 pub struct CommentService {
     comment_repo: CommentRepository,
@@ -17,20 +25,36 @@ impl CommentService {
         CommentService { comment_repo }
     }
 
-    pub async fn delete_comment(
-        &self,
-        comment_id: CommentId,
-        user_id: UserId,
-    ) -> Result<(), AppError> {
-        if !self
+    pub async fn delete_comment(&self, comment_id: CommentId, actor: &Actor) -> Result<(), AppError> {
+        let comment = self
             .comment_repo
-            .is_comment_author(comment_id, user_id)
+            .get_comment_by_id(comment_id)
             .await?
-        {
-            return Err(AppError::Forbidden);
+            .filter(|comment| comment.deleted_at.is_none())
+            .ok_or(AppError::NotFound)?;
+
+        authorize(actor, Action::DeleteComment, comment.author_id)?;
+
+        if self.comment_repo.has_replies(comment_id).await? {
+            return self.comment_repo.soft_delete_comment(comment_id).await;
+        }
+
+        self.comment_repo.delete_comment(comment_id).await?;
+
+        // Placeholders only exist to hold replies; drop the ones left empty.
+        let mut parent_id = comment.parent_id;
+        while let Some(id) = parent_id {
+            let Some(parent) = self.comment_repo.get_comment_by_id(id).await? else {
+                break;
+            };
+            if parent.deleted_at.is_none() || self.comment_repo.has_replies(id).await? {
+                break;
+            }
+            self.comment_repo.delete_comment(id).await?;
+            parent_id = parent.parent_id;
         }
 
-        self.comment_repo.delete_comment(comment_id).await
+        Ok(())
     }
 
     pub async fn add_comment(
@@ -38,7 +62,36 @@ impl CommentService {
         command: AddCommentCommand,
         user_id: UserId,
     ) -> Result<CommentView, AppError> {
-        let params = command.to_insert_params();
+        let depth = match command.parent_id {
+            Some(parent_id) => {
+                let parent = self
+                    .comment_repo
+                    .get_comment_by_id(parent_id)
+                    .await?
+                    .filter(|parent| {
+                        parent.article_id == command.article_id && parent.hidden_at.is_none()
+                    })
+                    .ok_or_else(|| {
+                        AppError::BadData("Parent comment not found on this article".to_string())
+                    })?;
+
+                if parent.deleted_at.is_some() {
+                    return Err(AppError::BadData(
+                        "Cannot reply to a deleted comment".to_string(),
+                    ));
+                }
+                if parent.depth >= MAX_COMMENT_DEPTH {
+                    return Err(AppError::BadData(format!(
+                        "Replies cannot be nested more than {MAX_COMMENT_DEPTH} levels deep"
+                    )));
+                }
+
+                parent.depth + 1
+            }
+            None => 0,
+        };
+
+        let params = command.to_insert_params(depth);
         let comment = self.comment_repo.insert_comment(params).await?;
 
         let comment = self
@@ -49,11 +102,62 @@ impl CommentService {
         Ok(comment)
     }
 
+    /// Finds a live comment, treating one posted on another article or hidden
+    /// by a moderator as missing.
+    async fn get_article_comment(
+        &self,
+        article_id: ArticleId,
+        comment_id: CommentId,
+    ) -> Result<Comment, AppError> {
+        self.comment_repo
+            .get_comment_by_id(comment_id)
+            .await?
+            .filter(|comment| {
+                comment.article_id == article_id
+                    && comment.deleted_at.is_none()
+                    && comment.hidden_at.is_none()
+            })
+            .ok_or(AppError::NotFound)
+    }
+
+    pub async fn update_comment(
+        &self,
+        command: UpdateCommentCommand,
+        actor: &Actor,
+    ) -> Result<CommentView, AppError> {
+        let comment = self
+            .get_article_comment(command.article_id, command.comment_id)
+            .await?;
+
+        authorize(actor, Action::UpdateComment, comment.author_id)?;
+
+        self.comment_repo.update_comment(command.to_params()).await?;
+
+        self.comment_repo
+            .get_comment(command.comment_id, Some(actor.user_id))
+            .await
+    }
+
+    pub async fn get_comment_revisions(
+        &self,
+        article_id: ArticleId,
+        comment_id: CommentId,
+        actor: &Actor,
+    ) -> Result<Vec<CommentRevision>, AppError> {
+        let comment = self.get_article_comment(article_id, comment_id).await?;
+
+        authorize(actor, Action::ViewCommentRevisions, comment.author_id)?;
+
+        self.comment_repo.get_comment_revisions(comment_id).await
+    }
+
     pub async fn get_comments(
         &self,
         article_id: ArticleId,
         user_id: Option<UserId>,
-    ) -> Result<Vec<CommentView>, AppError> {
-        self.comment_repo.get_comments(article_id, user_id).await
+    ) -> Result<Vec<CommentThread>, AppError> {
+        let comments = self.comment_repo.get_comments(article_id, user_id).await?;
+
+        Ok(CommentThread::build(comments))
     }
 }
@@ -0,0 +1,108 @@
+use crate::app_error::AppError;
+use crate::model::indexed_user_field::IndexedUserField;
+use crate::model::persistence::user::User;
+use crate::model::values::email::Email;
+use crate::model::values::user_id::UserId;
+use crate::persistence::user_repository::UserRepository;
+use crate::utils::jwt::jwt_keys::JwtKeys;
+use crate::utils::jwt::{generate_email_verification_token, verify_email_verification_token};
+use crate::utils::mailer::{MailMessage, Mailer};
+use anyhow::Result;
+use chrono::Duration;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+fn invalid_token() -> AppError {
+    AppError::BadData("Invalid or expired verification token".to_string())
+}
+
+/// Confirms that users own the address they signed up with. Tokens are signed
+/// rather than stored, and are tied to the address they were issued for.
+#[derive(Clone)]
+pub struct EmailVerificationService {
+    user_repo: UserRepository,
+    keys: JwtKeys,
+    mailer: Arc<dyn Mailer>,
+    token_ttl: Duration,
+    link_base_url: String,
+}
+
+impl EmailVerificationService {
+    pub fn new(
+        user_repo: UserRepository,
+        keys: JwtKeys,
+        mailer: Arc<dyn Mailer>,
+        token_ttl: Duration,
+        link_base_url: String,
+    ) -> Self {
+        EmailVerificationService {
+            user_repo,
+            keys,
+            mailer,
+            token_ttl,
+            link_base_url,
+        }
+    }
+
+    /// Mails `user` a link for verifying their current address.
+    pub async fn send_verification(&self, user: &User) -> Result<(), AppError> {
+        let token = generate_email_verification_token(&self.keys, user.id, &user.email, self.token_ttl)?;
+        let link = format!(
+            "{}/verify-email?token={}",
+            self.link_base_url.trim_end_matches('/'),
+            token
+        );
+
+        let message = MailMessage {
+            to: user.email.clone(),
+            subject: "Verify your email address".to_string(),
+            body: format!(
+                "Hi {},\n\nPlease confirm your email address by opening this link:\n\n{}\n\n\
+                 The link expires in {} hours.\n",
+                user.username,
+                link,
+                self.token_ttl.num_hours()
+            ),
+        };
+        self.mailer.send(&message).await?;
+
+        info!("Sent email verification to user {}", user.id);
+
+        Ok(())
+    }
+
+    pub async fn verify(&self, token: &str) -> Result<(), AppError> {
+        let claims = verify_email_verification_token(&self.keys, token).map_err(|_| invalid_token())?;
+        let user_id: Uuid = claims.sub.parse().map_err(|_| invalid_token())?;
+        let email = Email::try_from(claims.email).map_err(|_| invalid_token())?;
+
+        if !self
+            .user_repo
+            .verify_email(UserId::from(user_id), &email)
+            .await?
+        {
+            return Err(invalid_token());
+        }
+
+        info!("Verified email of user {}", user_id);
+
+        Ok(())
+    }
+
+    pub async fn resend_verification(&self, user_id: UserId) -> Result<(), AppError> {
+        let user = self
+            .user_repo
+            .get_user_by(IndexedUserField::Id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if user.is_verified() {
+            return Err(AppError::DataConflict(
+                "Email is already verified".to_string(),
+            ));
+        }
+
+        self.send_verification(&user).await
+    }
+}
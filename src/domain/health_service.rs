@@ -0,0 +1,60 @@
+use crate::app_error::AppError;
+use crate::model::health::{DependencyCheck, ReadinessReport};
+use crate::persistence::health_repository::HealthRepository;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+#[derive(Clone)]
+pub struct HealthService {
+    health_repo: HealthRepository,
+    timeout: Duration,
+}
+
+impl HealthService {
+    pub fn new(health_repo: HealthRepository, timeout: Duration) -> Self {
+        HealthService {
+            health_repo,
+            timeout,
+        }
+    }
+
+    pub async fn check_readiness(&self) -> ReadinessReport {
+        let (database, migrations) = tokio::join!(
+            self.check("database", self.health_repo.ping()),
+            self.check("migrations", self.health_repo.get_migration_status()),
+        );
+
+        ReadinessReport {
+            database,
+            migrations,
+        }
+    }
+
+    /// Runs a single dependency check, bounded by the configured timeout so that an
+    /// exhausted connection pool reports as down instead of hanging the probe.
+    async fn check<T>(
+        &self,
+        name: &str,
+        check: impl Future<Output = Result<T, AppError>>,
+    ) -> DependencyCheck<T> {
+        let started = Instant::now();
+
+        let outcome = match tokio::time::timeout(self.timeout, check).await {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(err)) => {
+                warn!(dependency = name, error = ?err, "Health check failed");
+                Err(err.to_string())
+            }
+            Err(_) => {
+                warn!(dependency = name, "Health check timed out");
+                Err(format!("Timed out after {}ms", self.timeout.as_millis()))
+            }
+        };
+
+        DependencyCheck {
+            latency: started.elapsed(),
+            outcome,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::app_error::AppError;
+use crate::utils::rate_limit::{LockoutPolicy, RateLimitBackend, TokenBucket};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Throttles login and registration per client IP and per email, and locks
+/// either out after repeated failed logins.
+#[derive(Clone)]
+pub struct LoginLimiter {
+    backend: Arc<dyn RateLimitBackend>,
+    bucket: TokenBucket,
+    lockout: LockoutPolicy,
+}
+
+/// Keys an attempt counts against. The IP is unknown when the server is not
+/// given peer addresses, and then only the email is limited.
+fn keys(ip: Option<IpAddr>, email: Option<&str>) -> Vec<String> {
+    ip.map(|ip| format!("login:ip:{ip}"))
+        .into_iter()
+        .chain(email.map(|email| format!("login:email:{}", email.trim().to_lowercase())))
+        .collect()
+}
+
+impl LoginLimiter {
+    pub fn new(backend: Arc<dyn RateLimitBackend>, bucket: TokenBucket, lockout: LockoutPolicy) -> Self {
+        LoginLimiter {
+            backend,
+            bucket,
+            lockout,
+        }
+    }
+
+    /// Fails with `TooManyRequests` while the IP or email is locked out or
+    /// has used up its attempts.
+    pub async fn check(&self, ip: Option<IpAddr>, email: Option<&str>) -> Result<(), AppError> {
+        let keys = keys(ip, email);
+
+        for key in &keys {
+            if let Some(remaining) = self.backend.locked_for(key).await {
+                return Err(AppError::TooManyRequests(remaining));
+            }
+        }
+
+        for key in &keys {
+            self.backend
+                .acquire(key, self.bucket)
+                .await
+                .map_err(AppError::TooManyRequests)?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn record_failure(&self, ip: Option<IpAddr>, email: Option<&str>) {
+        for key in keys(ip, email) {
+            if let Some(lockout) = self.backend.record_failure(&key, self.lockout).await {
+                warn!("Too many failed logins for {key}, locked out for {lockout:?}");
+            }
+        }
+    }
+
+    /// Clears the email's failures. The IP's are kept, so that signing in to
+    /// one account does not reset guessing at others.
+    pub async fn record_success(&self, email: Option<&str>) {
+        for key in keys(None, email) {
+            self.backend.reset_failures(&key).await;
+        }
+    }
+}
@@ -1,6 +1,18 @@
+pub mod access_token_service;
+pub mod api_rate_limiter;
 pub mod article_service;
+pub mod auth_service;
 pub mod commands;
 pub mod comment_service;
+pub mod email_verification_service;
+pub mod health_service;
+pub mod login_limiter;
+pub mod oidc_service;
+pub mod password_reset_service;
+pub mod policy;
 pub mod profile_service;
+pub mod publish_scheduler;
+pub mod report_service;
 pub mod tag_service;
+pub mod two_factor_service;
 pub mod user_service;
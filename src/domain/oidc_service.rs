@@ -0,0 +1,356 @@
+use crate::app_config::OidcProviderConfig;
+use crate::app_error::AppError;
+use crate::model::indexed_user_field::IndexedUserField;
+use crate::model::persistence::user::User;
+use crate::model::persistence::user_identity::UserIdentity;
+use crate::model::values::email::Email;
+use crate::model::values::identity_id::IdentityId;
+use crate::model::values::user_id::UserId;
+use crate::model::values::username::Username;
+use crate::persistence::identity_repository::IdentityRepository;
+use crate::persistence::oidc_login_repository::OidcLoginRepository;
+use crate::persistence::params::insert_identity_params::InsertIdentityParams;
+use crate::persistence::params::insert_oidc_login_params::InsertOidcLoginParams;
+use crate::persistence::user_repository::UserRepository;
+use crate::utils::oidc::oidc_error::OidcError;
+use crate::utils::oidc::{
+    ClientCredentials, IdTokenClaims, OidcClient, ProviderMetadata, authorization_url,
+};
+use crate::utils::opaque_token::{hash_token, random_token};
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use rand::Rng;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Generated usernames leave room for a numeric suffix.
+const MAX_BASE_USERNAME_LENGTH: usize = 40;
+const USERNAME_ATTEMPTS: usize = 10;
+
+/// Letters, digits and `_-.` from the provider's name for the user, or
+/// `None` if too little is left.
+fn username_base(claims: &IdTokenClaims) -> Option<String> {
+    let email_name = claims
+        .email
+        .as_deref()
+        .and_then(|email| email.split('@').next());
+
+    [claims.preferred_username.as_deref(), email_name, claims.name.as_deref()]
+        .into_iter()
+        .flatten()
+        .map(|name| {
+            name.chars()
+                .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+                .take(MAX_BASE_USERNAME_LENGTH)
+                .collect::<String>()
+        })
+        .find(|name| name.len() >= 2)
+}
+
+impl From<OidcError> for AppError {
+    fn from(error: OidcError) -> Self {
+        match error {
+            OidcError::CodeRejected(_) | OidcError::InvalidIdToken(_) => {
+                warn!("OpenID Connect sign-in failed: {error}");
+                AppError::Unauthorized
+            }
+            OidcError::Request(_) | OidcError::InvalidMetadata(_) => AppError::Other(error.into()),
+        }
+    }
+}
+
+/// How a completed provider login ended.
+pub enum OidcOutcome {
+    /// The user the identity belongs to, who gets a new session.
+    SignedIn(User),
+    /// The identity was added to the signed-in user, who keeps their session.
+    Linked(User),
+}
+
+/// Sign-in with OpenID Connect providers, using the authorization code flow
+/// with PKCE.
+#[derive(Clone)]
+pub struct OidcService {
+    providers: Arc<Vec<OidcProviderConfig>>,
+    client: OidcClient,
+    login_repo: OidcLoginRepository,
+    identity_repo: IdentityRepository,
+    user_repo: UserRepository,
+    login_ttl: Duration,
+}
+
+impl OidcService {
+    pub fn new(
+        providers: Vec<OidcProviderConfig>,
+        client: OidcClient,
+        login_repo: OidcLoginRepository,
+        identity_repo: IdentityRepository,
+        user_repo: UserRepository,
+        login_ttl: Duration,
+    ) -> Self {
+        OidcService {
+            providers: Arc::new(providers),
+            client,
+            login_repo,
+            identity_repo,
+            user_repo,
+            login_ttl,
+        }
+    }
+
+    fn provider(&self, name: &str) -> Result<&OidcProviderConfig, AppError> {
+        self.providers
+            .iter()
+            .find(|provider| provider.name == name)
+            .ok_or(AppError::NotFound)
+    }
+
+    /// Discovery is fetched on every sign-in; sign-ins are rare enough not to
+    /// bother caching it.
+    async fn metadata(&self, provider: &OidcProviderConfig) -> Result<ProviderMetadata, AppError> {
+        Ok(self.client.discover(&provider.discovery_url).await?)
+    }
+
+    /// Starts a sign-in and returns the provider URL to send the user to.
+    /// With `link_to`, the identity is added to that user instead.
+    pub async fn begin_login(&self, provider_name: &str, link_to: Option<UserId>) -> Result<String, AppError> {
+        let provider = self.provider(provider_name)?;
+        let metadata = self.metadata(provider).await?;
+
+        let state = random_token();
+        let nonce = random_token();
+        let code_verifier = random_token();
+
+        let url = authorization_url(
+            &metadata,
+            &credentials(provider),
+            &state,
+            &nonce,
+            &code_verifier,
+        )?;
+
+        self.login_repo
+            .insert_login(InsertOidcLoginParams {
+                state_hash: hash_token(&state),
+                provider: provider.name.clone(),
+                code_verifier,
+                nonce,
+                user_id: link_to,
+                expires_at: Utc::now() + self.login_ttl,
+            })
+            .await?;
+
+        Ok(url)
+    }
+
+    /// Finishes a sign-in with the code and state the provider sent back.
+    /// Signs in the user the identity is linked to, or else the user with the
+    /// same verified email, or else a new user.
+    ///
+    /// A login started to link an identity has to be finished by the same
+    /// signed-in user, `signed_in`: otherwise anyone could send their link
+    /// URL to a victim and have the victim's identity added to their account.
+    pub async fn complete_login(
+        &self,
+        provider_name: &str,
+        code: &str,
+        state: &str,
+        signed_in: Option<UserId>,
+    ) -> Result<OidcOutcome, AppError> {
+        let provider = self.provider(provider_name)?;
+        let login = self
+            .login_repo
+            .take_login(&hash_token(state), &provider.name)
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+        if login.user_id.is_some() && login.user_id != signed_in {
+            warn!("OpenID Connect link finished by another user than the one who started it");
+            return Err(AppError::Unauthorized);
+        }
+
+        let metadata = self.metadata(provider).await?;
+        let client = credentials(provider);
+        let id_token = self
+            .client
+            .exchange_code(&metadata, &client, code, &login.code_verifier)
+            .await?;
+        let claims = self
+            .client
+            .verify_id_token(&metadata, client.client_id, &id_token, &login.nonce)
+            .await?;
+
+        let outcome = match login.user_id {
+            Some(user_id) => OidcOutcome::Linked(self.link_to_user(user_id, &provider.name, claims).await?),
+            None => OidcOutcome::SignedIn(self.sign_in(&provider.name, claims).await?),
+        };
+        let (OidcOutcome::SignedIn(user) | OidcOutcome::Linked(user)) = &outcome;
+        if user.suspended_at.is_some() {
+            return Err(AppError::AccountSuspended);
+        }
+
+        Ok(outcome)
+    }
+
+    async fn sign_in(&self, provider: &str, claims: IdTokenClaims) -> Result<User, AppError> {
+        if let Some(user_id) = self.identity_repo.find_user_id(provider, &claims.sub).await? {
+            return self.load_user(user_id).await;
+        }
+
+        let email = verified_email(&claims)?;
+        let identity = identity_params(provider, &claims);
+
+        match self
+            .user_repo
+            .get_user_by(IndexedUserField::Email, email.clone())
+            .await?
+        {
+            Some(user) if user.is_verified() => {
+                if !self.identity_repo.link_identity(user.id, identity).await? {
+                    self.check_linked_to(user.id, provider, &claims.sub).await?;
+                }
+                info!("Linked {} identity to user {} by email", provider, user.id);
+                Ok(user)
+            }
+            Some(user) => {
+                if !self
+                    .identity_repo
+                    .claim_unverified_account(user.id, identity)
+                    .await?
+                {
+                    self.check_linked_to(user.id, provider, &claims.sub).await?;
+                }
+                info!("User {} claimed by a verified {} identity", user.id, provider);
+                self.load_user(user.id).await
+            }
+            None => {
+                let username = self.unique_username(&claims).await?;
+                let user = self
+                    .identity_repo
+                    .create_user_with_identity(username, email, identity)
+                    .await?;
+                info!("Created user {} from {} identity", user.id, provider);
+                Ok(user)
+            }
+        }
+    }
+
+    async fn link_to_user(&self, user_id: UserId, provider: &str, claims: IdTokenClaims) -> Result<User, AppError> {
+        match self.identity_repo.find_user_id(provider, &claims.sub).await? {
+            Some(linked_id) if linked_id == user_id => {}
+            Some(_) => {
+                return Err(AppError::DataConflict(
+                    "This identity is linked to another account".to_string(),
+                ));
+            }
+            None => {
+                if !self
+                    .identity_repo
+                    .link_identity(user_id, identity_params(provider, &claims))
+                    .await?
+                {
+                    self.check_linked_to(user_id, provider, &claims.sub).await?;
+                }
+                info!("Linked {} identity to user {}", provider, user_id);
+            }
+        }
+
+        self.load_user(user_id).await
+    }
+
+    /// After a link lost the race to a concurrent sign-in with the same
+    /// identity: fine if that one linked it to the same user.
+    async fn check_linked_to(&self, user_id: UserId, provider: &str, subject: &str) -> Result<(), AppError> {
+        match self.identity_repo.find_user_id(provider, subject).await? {
+            Some(linked_id) if linked_id == user_id => Ok(()),
+            _ => Err(AppError::DataConflict(
+                "This identity is linked to another account".to_string(),
+            )),
+        }
+    }
+
+    async fn load_user(&self, user_id: UserId) -> Result<User, AppError> {
+        self.user_repo
+            .get_user_by(IndexedUserField::Id, user_id)
+            .await?
+            .ok_or(AppError::Unauthorized)
+    }
+
+    async fn unique_username(&self, claims: &IdTokenClaims) -> Result<Username, AppError> {
+        let base = username_base(claims).unwrap_or_else(|| "user".to_string());
+
+        for attempt in 0..USERNAME_ATTEMPTS {
+            let candidate = if attempt == 0 {
+                base.clone()
+            } else {
+                format!("{base}{}", rand::rng().random_range(1000..10000))
+            };
+            let username = Username::try_from(candidate).map_err(AppError::BadData)?;
+
+            if self
+                .user_repo
+                .get_user_by(IndexedUserField::Username, username.clone())
+                .await?
+                .is_none()
+            {
+                return Ok(username);
+            }
+        }
+
+        Err(AppError::DataConflict(
+            "Couldn't find a free username".to_string(),
+        ))
+    }
+
+    pub async fn list_identities(&self, user_id: UserId) -> Result<Vec<UserIdentity>, AppError> {
+        self.identity_repo.list_identities(user_id).await
+    }
+
+    /// Refuses to remove the last way to sign in to an account without a
+    /// password.
+    pub async fn unlink_identity(&self, user_id: UserId, id: IdentityId) -> Result<(), AppError> {
+        let user = self.load_user(user_id).await?;
+        let identities = self.identity_repo.list_identities(user_id).await?;
+
+        if !identities.iter().any(|identity| identity.id == id) {
+            return Err(AppError::NotFound);
+        }
+        if user.password_hash.is_none() && identities.len() == 1 {
+            return Err(AppError::DataConflict(
+                "Set a password before removing the last linked identity".to_string(),
+            ));
+        }
+
+        self.identity_repo.delete_identity(user_id, id).await?;
+        info!("Unlinked identity {} from user {}", id, user_id);
+
+        Ok(())
+    }
+}
+
+fn credentials(provider: &OidcProviderConfig) -> ClientCredentials<'_> {
+    ClientCredentials {
+        client_id: &provider.client_id,
+        client_secret: &provider.client_secret,
+        redirect_uri: &provider.redirect_uri,
+    }
+}
+
+fn verified_email(claims: &IdTokenClaims) -> Result<Email, AppError> {
+    let email = claims
+        .email
+        .clone()
+        .filter(|_| claims.email_verified)
+        .ok_or_else(|| {
+            AppError::BadData("The identity provider did not share a verified email address".to_string())
+        })?;
+
+    Email::try_from(email).map_err(AppError::BadData)
+}
+
+fn identity_params(provider: &str, claims: &IdTokenClaims) -> InsertIdentityParams {
+    InsertIdentityParams {
+        provider: provider.to_string(),
+        subject: claims.sub.clone(),
+        email: claims.email.clone().unwrap_or_default(),
+    }
+}
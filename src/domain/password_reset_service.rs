@@ -0,0 +1,149 @@
+use crate::app_error::AppError;
+use crate::model::indexed_user_field::IndexedUserField;
+use crate::model::persistence::user::User;
+use crate::model::values::email::Email;
+use crate::model::values::password::Password;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_password_reset_params::InsertPasswordResetParams;
+use crate::persistence::password_reset_repository::PasswordResetRepository;
+use crate::persistence::user_repository::UserRepository;
+use crate::utils::hasher::Hasher;
+use crate::utils::mailer::{MailMessage, Mailer};
+use crate::utils::opaque_token::{hash_token, random_token};
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[derive(Clone)]
+pub struct PasswordResetService {
+    reset_repo: PasswordResetRepository,
+    user_repo: UserRepository,
+    hasher: Hasher,
+    mailer: Arc<dyn Mailer>,
+    token_ttl: Duration,
+    resend_interval: Duration,
+    link_base_url: String,
+}
+
+impl PasswordResetService {
+    pub fn new(
+        reset_repo: PasswordResetRepository,
+        user_repo: UserRepository,
+        hasher: Hasher,
+        mailer: Arc<dyn Mailer>,
+        token_ttl: Duration,
+        resend_interval: Duration,
+        link_base_url: String,
+    ) -> Self {
+        PasswordResetService {
+            reset_repo,
+            user_repo,
+            hasher,
+            mailer,
+            token_ttl,
+            resend_interval,
+            link_base_url,
+        }
+    }
+
+    /// Mails a reset link if `email` belongs to a user. Succeeds either way, so
+    /// callers cannot tell which addresses are registered; and since the link
+    /// is made and mailed in the background, neither can they by how long it
+    /// takes.
+    pub async fn request_reset(&self, email: Email) -> Result<(), AppError> {
+        let Some(user) = self
+            .user_repo
+            .get_user_by(IndexedUserField::Email, email)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let service = self.clone();
+        tokio::spawn(async move {
+            if let Err(err) = service.send_unless_recent(&user).await {
+                error!("Failed to send password reset email to user {}: {err}", user.id);
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Mails a reset link for a reset an admin required, however recently the
+    /// last one went out. Unlike `request_reset`, a failed send is reported,
+    /// so the admin knows to try again.
+    pub async fn send_required_reset(&self, user_id: UserId) -> Result<(), AppError> {
+        let user = self
+            .user_repo
+            .get_user_by(IndexedUserField::Id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        self.send_reset_link(&user).await
+    }
+
+    async fn send_unless_recent(&self, user: &User) -> Result<(), AppError> {
+        if self
+            .reset_repo
+            .has_token_since(user.id, Utc::now() - self.resend_interval)
+            .await?
+        {
+            info!("Skipped password reset email to user {}: one was just sent", user.id);
+            return Ok(());
+        }
+
+        self.send_reset_link(user).await
+    }
+
+    async fn send_reset_link(&self, user: &User) -> Result<(), AppError> {
+        let token = random_token();
+        self.reset_repo
+            .insert_token(InsertPasswordResetParams {
+                user_id: user.id,
+                token_hash: hash_token(&token),
+                expires_at: Utc::now() + self.token_ttl,
+            })
+            .await?;
+
+        let link = format!(
+            "{}/reset-password?token={}",
+            self.link_base_url.trim_end_matches('/'),
+            token
+        );
+        let message = MailMessage {
+            to: user.email.clone(),
+            subject: "Reset your password".to_string(),
+            body: format!(
+                "Hi {},\n\nSomeone asked to reset the password of your account. If it was \
+                 you, open this link to choose a new one:\n\n{}\n\n\
+                 The link expires in {} minutes. If you did not ask for it, you can \
+                 ignore this email.\n",
+                user.username,
+                link,
+                self.token_ttl.num_minutes()
+            ),
+        };
+        self.mailer.send(&message).await?;
+
+        info!("Sent password reset email to user {}", user.id);
+
+        Ok(())
+    }
+
+    /// Sets a new password with a mailed token and signs the user out
+    /// everywhere.
+    pub async fn reset_password(&self, token: &str, password: &Password) -> Result<(), AppError> {
+        let password_hash = self.hasher.hash_password(password)?;
+
+        let user_id = self
+            .reset_repo
+            .reset_password(&hash_token(token), password_hash)
+            .await?
+            .ok_or_else(|| AppError::BadData("Invalid or expired reset token".to_string()))?;
+
+        info!("Reset password of user {}", user_id);
+
+        Ok(())
+    }
+}
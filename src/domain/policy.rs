@@ -0,0 +1,88 @@
+use crate::app_error::AppError;
+use crate::model::role::Role;
+use crate::model::values::user_id::UserId;
+
+/// The authenticated user a request acts for.
+#[derive(Debug, Clone, Copy)]
+pub struct Actor {
+    pub user_id: UserId,
+    pub role: Role,
+}
+
+/// Operations on content owned by someone, checked by [`authorize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    UpdateArticle,
+    DeleteArticle,
+    UpdateComment,
+    DeleteComment,
+    ViewCommentRevisions,
+}
+
+/// Operations that are not about anyone's content, checked by [`require`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    ManageUsers,
+    /// Work the report queue and see hidden content.
+    ModerateContent,
+}
+
+impl Actor {
+    fn owns(&self, owner_id: UserId) -> bool {
+        self.user_id == owner_id
+    }
+
+    fn is_at_least(&self, role: Role) -> bool {
+        self.role >= role
+    }
+}
+
+/// Whether `actor` may perform `action` on content owned by `owner_id`.
+/// Only authors edit their content; moderators may also remove it and see
+/// how it was edited.
+pub fn is_allowed(actor: &Actor, action: Action, owner_id: UserId) -> bool {
+    match action {
+        Action::UpdateArticle | Action::UpdateComment => actor.owns(owner_id),
+        Action::DeleteArticle | Action::DeleteComment | Action::ViewCommentRevisions => {
+            actor.owns(owner_id) || actor.is_at_least(Role::Moderator)
+        }
+    }
+}
+
+fn role_has_permission(role: Role, permission: Permission) -> bool {
+    match permission {
+        Permission::ManageUsers => role >= Role::Admin,
+        Permission::ModerateContent => role >= Role::Moderator,
+    }
+}
+
+/// Whether `actor` holds `permission`.
+pub fn has_permission(actor: &Actor, permission: Permission) -> bool {
+    role_has_permission(actor.role, permission)
+}
+
+/// Roles that hold `permission`, for checks that have to run inside a query.
+pub fn roles_with(permission: Permission) -> Vec<Role> {
+    Role::ALL
+        .into_iter()
+        .filter(|role| role_has_permission(*role, permission))
+        .collect()
+}
+
+/// [`has_permission`], failing with `Forbidden` when it does not.
+pub fn require(actor: &Actor, permission: Permission) -> Result<(), AppError> {
+    if has_permission(actor, permission) {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden)
+    }
+}
+
+/// [`is_allowed`], failing with `Forbidden` when the action is not.
+pub fn authorize(actor: &Actor, action: Action, owner_id: UserId) -> Result<(), AppError> {
+    if is_allowed(actor, action, owner_id) {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden)
+    }
+}
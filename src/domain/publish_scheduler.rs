@@ -0,0 +1,24 @@
+use crate::domain::article_service::ArticleService;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info};
+
+/// Publishes scheduled articles once their `publish_at` has passed, checking
+/// every `interval`. The task runs until the runtime shuts down.
+pub fn spawn_publish_scheduler(article_service: ArticleService, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match article_service.publish_scheduled_articles().await {
+                Ok(0) => {}
+                Ok(count) => info!(count = count, "Published scheduled articles"),
+                Err(e) => error!(error = ?e, "Failed to publish scheduled articles"),
+            }
+        }
+    })
+}
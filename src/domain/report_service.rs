@@ -0,0 +1,155 @@
+use crate::app_error::AppError;
+use crate::domain::commands::report_content_command::ReportContentCommand;
+use crate::domain::policy::{Actor, Permission, require};
+use crate::model::indexed_article_field::IndexedArticleField;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::report::{Report, ReportedContent};
+use crate::model::report::ReportAction;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+use crate::persistence::article_repository::ArticleRepository;
+use crate::persistence::comment_repository::CommentRepository;
+use crate::persistence::report_repository::ReportRepository;
+use anyhow::Result;
+use tracing::info;
+
+#[derive(Clone)]
+pub struct ReportService {
+    report_repo: ReportRepository,
+    article_repo: ArticleRepository,
+    comment_repo: CommentRepository,
+}
+
+impl ReportService {
+    pub fn new(
+        report_repo: ReportRepository,
+        article_repo: ArticleRepository,
+        comment_repo: CommentRepository,
+    ) -> Self {
+        ReportService {
+            report_repo,
+            article_repo,
+            comment_repo,
+        }
+    }
+
+    /// Reports an article the reporter can see; `author_id` is its author.
+    pub async fn report_article(
+        &self,
+        article_id: ArticleId,
+        author_id: UserId,
+        command: ReportContentCommand,
+    ) -> Result<Report, AppError> {
+        self.insert_report(command, article_id, None, author_id).await
+    }
+
+    pub async fn report_comment(
+        &self,
+        article_id: ArticleId,
+        comment_id: CommentId,
+        command: ReportContentCommand,
+    ) -> Result<Report, AppError> {
+        let comment = self
+            .comment_repo
+            .get_comment_by_id(comment_id)
+            .await?
+            .filter(|comment| {
+                comment.article_id == article_id
+                    && comment.deleted_at.is_none()
+                    && comment.hidden_at.is_none()
+            })
+            .ok_or(AppError::NotFound)?;
+
+        self.insert_report(command, article_id, Some(comment_id), comment.author_id)
+            .await
+    }
+
+    async fn insert_report(
+        &self,
+        command: ReportContentCommand,
+        article_id: ArticleId,
+        comment_id: Option<CommentId>,
+        author_id: UserId,
+    ) -> Result<Report, AppError> {
+        if command.reporter_id == author_id {
+            return Err(AppError::BadData(
+                "You cannot report your own content".to_string(),
+            ));
+        }
+
+        self.report_repo
+            .insert_report(command.to_insert_params(article_id, comment_id))
+            .await?
+            .ok_or_else(|| AppError::DataConflict("You have already reported this".to_string()))
+    }
+
+    pub async fn get_reported_content(
+        &self,
+        actor: &Actor,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<(Vec<ReportedContent>, u64), AppError> {
+        require(actor, Permission::ModerateContent)?;
+
+        let content = self.report_repo.get_reported_content(limit, offset).await?;
+        let count = self.report_repo.count_reported_content().await?;
+
+        Ok((content, count))
+    }
+
+    /// Settles the open reports on an article, or on one of its comments when
+    /// `comment_id` is given. Hidden content can still be resolved, so that
+    /// its author can be suspended after the fact.
+    pub async fn resolve_reports(
+        &self,
+        actor: &Actor,
+        slug: &Slug,
+        comment_id: Option<CommentId>,
+        action: ReportAction,
+    ) -> Result<(), AppError> {
+        require(actor, Permission::ModerateContent)?;
+        if action == ReportAction::SuspendAuthor {
+            require(actor, Permission::ManageUsers)?;
+        }
+
+        let article = self
+            .article_repo
+            .get_article_by(IndexedArticleField::Slug, slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let author_id = match comment_id {
+            Some(comment_id) => {
+                self.comment_repo
+                    .get_comment_by_id(comment_id)
+                    .await?
+                    .filter(|comment| comment.article_id == article.id)
+                    .ok_or(AppError::NotFound)?
+                    .author_id
+            }
+            None => article.author_id,
+        };
+
+        if action == ReportAction::SuspendAuthor && author_id == actor.user_id {
+            return Err(AppError::BadData("You cannot suspend yourself".to_string()));
+        }
+
+        if !self
+            .report_repo
+            .resolve_reports(article.id, comment_id, author_id, actor.user_id, action)
+            .await?
+        {
+            return Err(AppError::NotFound);
+        }
+
+        info!(
+            "Resolved reports on article {} (comment {:?}) with {:?}",
+            article.id, comment_id, action
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+use crate::app_error::AppError;
+use crate::domain::login_limiter::LoginLimiter;
+use crate::model::indexed_user_field::IndexedUserField;
+use crate::model::persistence::totp_credential::TotpCredential;
+use crate::model::persistence::user::User;
+use crate::model::values::user_id::UserId;
+use crate::persistence::totp_repository::TotpRepository;
+use crate::persistence::user_repository::UserRepository;
+use crate::utils::jwt::jwt_keys::JwtKeys;
+use crate::utils::jwt::{generate_login_challenge_token, verify_login_challenge_token};
+use crate::utils::opaque_token::hash_token;
+use crate::utils::secret_cipher::SecretCipher;
+use crate::utils::totp;
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use rand::Rng;
+use tracing::info;
+use uuid::Uuid;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+/// Groups of four characters in a recovery code; four give 80 bits.
+const RECOVERY_CODE_GROUPS: usize = 4;
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+fn generate_recovery_code() -> String {
+    let mut rng = rand::rng();
+    (0..RECOVERY_CODE_GROUPS)
+        .map(|_| {
+            (0..4)
+                .map(|_| {
+                    RECOVERY_CODE_ALPHABET[rng.random_range(0..RECOVERY_CODE_ALPHABET.len())]
+                        as char
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Recovery codes may be typed with any case, spacing or dashes.
+fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    hash_token(&normalized)
+}
+
+fn is_totp_code(code: &str) -> bool {
+    code.len() == 6 && code.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn invalid_code() -> AppError {
+    AppError::BadData("Invalid code".to_string())
+}
+
+/// What an authenticator app needs to start producing codes.
+pub struct TotpEnrollment {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+/// Hands out the token for the second step of a login with 2FA.
+pub struct LoginChallenge {
+    pub token: String,
+    pub expires_in: Duration,
+}
+
+/// TOTP two-factor authentication: enrollment, and the second login step.
+#[derive(Clone)]
+pub struct TwoFactorService {
+    totp_repo: TotpRepository,
+    user_repo: UserRepository,
+    login_limiter: LoginLimiter,
+    cipher: SecretCipher,
+    keys: JwtKeys,
+    issuer: String,
+    challenge_ttl: Duration,
+}
+
+impl TwoFactorService {
+    pub fn new(
+        totp_repo: TotpRepository,
+        user_repo: UserRepository,
+        login_limiter: LoginLimiter,
+        cipher: SecretCipher,
+        keys: JwtKeys,
+        issuer: String,
+        challenge_ttl: Duration,
+    ) -> Self {
+        TwoFactorService {
+            totp_repo,
+            user_repo,
+            login_limiter,
+            cipher,
+            keys,
+            issuer,
+            challenge_ttl,
+        }
+    }
+
+    pub async fn is_enabled(&self, user_id: UserId) -> Result<bool, AppError> {
+        Ok(self
+            .totp_repo
+            .get_credential(user_id)
+            .await?
+            .is_some_and(|credential| credential.is_confirmed()))
+    }
+
+    /// Starts enrollment with a fresh secret; 2FA stays off until a first
+    /// code confirms it.
+    pub async fn begin_enrollment(&self, user_id: UserId) -> Result<TotpEnrollment, AppError> {
+        let user = self
+            .user_repo
+            .get_user_by(IndexedUserField::Id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let secret = totp::generate_secret();
+        let secret_encrypted = self
+            .cipher
+            .encrypt(&secret, user_id.to_string().as_bytes())?;
+        if !self
+            .totp_repo
+            .save_pending_secret(user_id, secret_encrypted)
+            .await?
+        {
+            return Err(AppError::DataConflict(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+
+        Ok(TotpEnrollment {
+            secret: totp::base32_encode(&secret),
+            provisioning_uri: totp::provisioning_uri(&self.issuer, user.username.value(), &secret),
+        })
+    }
+
+    /// Turns 2FA on and returns the recovery codes, which are not stored in
+    /// a readable form and cannot be shown again.
+    pub async fn confirm_enrollment(
+        &self,
+        user_id: UserId,
+        code: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let credential = self
+            .totp_repo
+            .get_credential(user_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::BadData("Two-factor enrollment has not been started".to_string())
+            })?;
+        if credential.is_confirmed() {
+            return Err(AppError::DataConflict(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+
+        let step = self
+            .matching_step(user_id, &credential, code)?
+            .ok_or_else(invalid_code)?;
+
+        let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| generate_recovery_code())
+            .collect();
+        let hashes: Vec<String> = recovery_codes
+            .iter()
+            .map(|code| hash_recovery_code(code))
+            .collect();
+        if !self.totp_repo.confirm(user_id, step, &hashes).await? {
+            return Err(invalid_code());
+        }
+
+        info!("Enabled two-factor authentication for user {}", user_id);
+
+        Ok(recovery_codes)
+    }
+
+    /// Turns 2FA off; takes a current code or a recovery code.
+    pub async fn disable(&self, user_id: UserId, code: &str) -> Result<(), AppError> {
+        let credential = self
+            .totp_repo
+            .get_credential(user_id)
+            .await?
+            .filter(TotpCredential::is_confirmed)
+            .ok_or_else(|| {
+                AppError::BadData("Two-factor authentication is not enabled".to_string())
+            })?;
+
+        if !self.use_code(user_id, &credential, code).await? {
+            return Err(invalid_code());
+        }
+
+        self.totp_repo.delete(user_id).await?;
+
+        info!("Disabled two-factor authentication for user {}", user_id);
+
+        Ok(())
+    }
+
+    pub fn create_challenge(&self, user_id: UserId) -> Result<LoginChallenge, AppError> {
+        Ok(LoginChallenge {
+            token: generate_login_challenge_token(&self.keys, user_id, self.challenge_ttl)?,
+            expires_in: self.challenge_ttl,
+        })
+    }
+
+    /// Second login step: checks a code, or a recovery code, against the
+    /// user the challenge was issued to. Wrong codes count towards the same
+    /// lockout as wrong passwords.
+    pub async fn complete_login(
+        &self,
+        challenge_token: &str,
+        code: &str,
+    ) -> Result<User, AppError> {
+        let claims = verify_login_challenge_token(&self.keys, challenge_token)?;
+        let user_id: Uuid = claims.sub.parse().map_err(|_| AppError::Unauthorized)?;
+        let user_id = UserId::from(user_id);
+
+        let user = self
+            .user_repo
+            .get_user_by(IndexedUserField::Id, user_id)
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+        let email = user.email.value().to_string();
+
+        self.login_limiter.check(None, Some(&email)).await?;
+
+        let credential = self
+            .totp_repo
+            .get_credential(user_id)
+            .await?
+            .filter(TotpCredential::is_confirmed)
+            .ok_or(AppError::Unauthorized)?;
+
+        if !self.use_code(user_id, &credential, code).await? {
+            self.login_limiter.record_failure(None, Some(&email)).await;
+            return Err(AppError::Unauthorized);
+        }
+        self.login_limiter.record_success(Some(&email)).await;
+
+        // The account may have changed since the password was checked.
+        if user.suspended_at.is_some() {
+            return Err(AppError::AccountSuspended);
+        }
+        if user.password_reset_required {
+            return Err(AppError::PasswordResetRequired);
+        }
+
+        Ok(user)
+    }
+
+    fn matching_step(
+        &self,
+        user_id: UserId,
+        credential: &TotpCredential,
+        code: &str,
+    ) -> Result<Option<i64>, AppError> {
+        if !is_totp_code(code) {
+            return Ok(None);
+        }
+
+        let secret = self
+            .cipher
+            .decrypt(&credential.secret_encrypted, user_id.to_string().as_bytes())?;
+
+        Ok(totp::matching_step(
+            &secret,
+            code,
+            totp::step_at(Utc::now().timestamp()),
+        ))
+    }
+
+    /// Uses up a TOTP code, or failing that a recovery code.
+    async fn use_code(
+        &self,
+        user_id: UserId,
+        credential: &TotpCredential,
+        code: &str,
+    ) -> Result<bool, AppError> {
+        let code = code.trim();
+
+        if let Some(step) = self.matching_step(user_id, credential, code)? {
+            return self.totp_repo.use_step(user_id, step).await;
+        }
+        if is_totp_code(code) {
+            return Ok(false);
+        }
+
+        let used = self
+            .totp_repo
+            .use_recovery_code(user_id, &hash_recovery_code(code))
+            .await?;
+        if used {
+            info!("User {} signed in with a recovery code", user_id);
+        }
+
+        Ok(used)
+    }
+}
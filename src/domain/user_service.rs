@@ -1,11 +1,15 @@
 use crate::app_error::AppError;
+use crate::domain::commands::list_users_query::ListUsersQuery;
 use crate::domain::commands::login_command::LoginCommand;
 use crate::domain::commands::register_command::RegisterCommand;
 use crate::domain::commands::update_user_command::UpdateUserCommand;
+use crate::domain::policy::{Actor, Permission, require};
 use crate::model::indexed_user_field::IndexedUserField;
 use crate::model::persistence::user::User;
+use crate::model::persistence::user_account::UserAccount;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
+use crate::persistence::params::list_users_params::ListUsersParams;
 use crate::persistence::user_repository::UserRepository;
 use crate::utils::hasher::Hasher;
 use anyhow::Result;
@@ -59,16 +63,28 @@ impl UserService {
             .get_user_by(IndexedUserField::Email, command.email.clone())
             .await?
             .ok_or_else(|| AppError::Unauthorized)?;
+        let Some(password_hash) = &user.password_hash else {
+            return Err(AppError::Unauthorized);
+        };
 
-        if self
+        if !self
             .hasher
-            .verify_password(&command.password, &user.password_hash)
+            .verify_password(&command.password, password_hash)
             .map_err(|_| AppError::Unauthorized)?
         {
-            Ok(user)
-        } else {
-            Err(AppError::Unauthorized)
+            return Err(AppError::Unauthorized);
+        }
+
+        // Checked only after the password so the account state is not
+        // revealed to someone who merely knows the email.
+        if user.suspended_at.is_some() {
+            return Err(AppError::AccountSuspended);
         }
+        if user.password_reset_required {
+            return Err(AppError::PasswordResetRequired);
+        }
+
+        Ok(user)
     }
 
     pub async fn get_user_by_id(&self, user_id: UserId) -> Result<Option<User>, AppError> {
@@ -111,4 +127,100 @@ impl UserService {
 
         Ok(user)
     }
+
+    pub async fn list_users(
+        &self,
+        actor: &Actor,
+        query: ListUsersQuery,
+    ) -> Result<(Vec<UserAccount>, u64), AppError> {
+        require(actor, Permission::ManageUsers)?;
+
+        let users = self
+            .user_repo
+            .list_user_accounts(ListUsersParams::from_query(query.clone()))
+            .await?;
+        let count = self
+            .user_repo
+            .count_users(ListUsersParams::from_query(query))
+            .await?;
+
+        Ok((users, count))
+    }
+
+    pub async fn get_user_account(
+        &self,
+        actor: &Actor,
+        username: &Username,
+    ) -> Result<UserAccount, AppError> {
+        require(actor, Permission::ManageUsers)?;
+
+        self.user_repo
+            .get_user_account(username)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    pub async fn suspend_user(
+        &self,
+        actor: &Actor,
+        username: &Username,
+        reason: Option<String>,
+    ) -> Result<UserAccount, AppError> {
+        let user = self.managed_user(actor, username).await?;
+        if user.id == actor.user_id {
+            return Err(AppError::BadData("You cannot suspend yourself".to_string()));
+        }
+
+        self.user_repo.suspend_user(user.id, reason).await?;
+        info!("Suspended user with id: {}", user.id);
+
+        self.get_user_account(actor, username).await
+    }
+
+    pub async fn unsuspend_user(
+        &self,
+        actor: &Actor,
+        username: &Username,
+    ) -> Result<UserAccount, AppError> {
+        let user = self.managed_user(actor, username).await?;
+
+        self.user_repo.unsuspend_user(user.id).await?;
+        info!("Unsuspended user with id: {}", user.id);
+
+        self.get_user_account(actor, username).await
+    }
+
+    pub async fn require_password_reset(
+        &self,
+        actor: &Actor,
+        username: &Username,
+    ) -> Result<UserAccount, AppError> {
+        let user = self.managed_user(actor, username).await?;
+
+        self.user_repo.require_password_reset(user.id).await?;
+        info!("Forced password reset for user with id: {}", user.id);
+
+        self.get_user_account(actor, username).await
+    }
+
+    pub async fn delete_user(&self, actor: &Actor, username: &Username) -> Result<(), AppError> {
+        let user = self.managed_user(actor, username).await?;
+        if user.id == actor.user_id {
+            return Err(AppError::BadData("You cannot delete yourself".to_string()));
+        }
+
+        self.user_repo.delete_user(user.id).await?;
+        info!("Deleted user with id: {}", user.id);
+
+        Ok(())
+    }
+
+    async fn managed_user(&self, actor: &Actor, username: &Username) -> Result<User, AppError> {
+        require(actor, Permission::ManageUsers)?;
+
+        self.user_repo
+            .get_user_by(IndexedUserField::Username, username.clone())
+            .await?
+            .ok_or(AppError::NotFound)
+    }
 }
@@ -0,0 +1,73 @@
+use crate::model::persistence::personal_access_token::PersonalAccessToken;
+use crate::model::scope::Scope;
+use crate::model::values::access_token_id::AccessTokenId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateAccessTokenRequest {
+    #[serde(rename = "accessToken")]
+    pub access_token: NewAccessToken,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NewAccessToken {
+    /// What the token is for, e.g. the name of the bot using it.
+    pub name: String,
+    /// What the token may change; without scopes it can only read.
+    #[serde(default)]
+    pub scopes: Vec<Scope>,
+    /// Days until the token expires; it never does if left out.
+    #[serde(rename = "expiresInDays")]
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AccessTokenResponse {
+    #[serde(rename = "accessToken")]
+    pub access_token: AccessTokenItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AccessTokensResponse {
+    #[serde(rename = "accessTokens")]
+    pub access_tokens: Vec<AccessTokenItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AccessTokenItem {
+    pub id: AccessTokenId,
+    pub name: String,
+    pub scopes: Vec<Scope>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(rename = "lastUsedAt")]
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// The token itself, only returned when it is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl AccessTokenItem {
+    pub(crate) fn from_access_token(token: PersonalAccessToken) -> AccessTokenItem {
+        AccessTokenItem {
+            id: token.id,
+            name: token.name,
+            scopes: token.scopes,
+            created_at: token.created_at,
+            expires_at: token.expires_at,
+            last_used_at: token.last_used_at,
+            token: None,
+        }
+    }
+
+    pub(crate) fn with_secret(token: PersonalAccessToken, secret: String) -> AccessTokenItem {
+        AccessTokenItem {
+            token: Some(secret),
+            ..AccessTokenItem::from_access_token(token)
+        }
+    }
+}
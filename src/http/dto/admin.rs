@@ -0,0 +1,84 @@
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::user_account::UserAccount;
+use crate::model::role::Role;
+use crate::model::values::bio::Bio;
+use crate::model::values::email::Email;
+use crate::model::values::image::Image;
+use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AdminUserResponse {
+    pub user: AdminUserItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AdminUsersResponse {
+    pub users: Vec<AdminUserItem>,
+    #[serde(rename = "usersCount")]
+    pub users_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AdminUserItem {
+    pub username: Username,
+    pub email: Email,
+    pub bio: Option<Bio>,
+    pub image: Option<Image>,
+    pub role: Role,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "suspendedAt")]
+    pub suspended_at: Option<DateTime<Utc>>,
+    #[serde(rename = "suspensionReason")]
+    pub suspension_reason: Option<String>,
+    /// Set by a forced password reset; the user cannot sign in until they
+    /// choose a new password.
+    #[serde(rename = "passwordResetRequired")]
+    pub password_reset_required: bool,
+    #[serde(rename = "articlesCount")]
+    pub articles_count: i64,
+    #[serde(rename = "commentsCount")]
+    pub comments_count: i64,
+    #[serde(rename = "followersCount")]
+    pub followers_count: i64,
+}
+
+impl AdminUserItem {
+    pub(crate) fn from_user_account(account: UserAccount) -> AdminUserItem {
+        AdminUserItem {
+            username: account.username,
+            email: account.email,
+            bio: account.bio,
+            image: account.image,
+            role: account.role,
+            created_at: account.created_at,
+            suspended_at: account.suspended_at,
+            suspension_reason: account.suspension_reason,
+            password_reset_required: account.password_reset_required,
+            articles_count: account.articles_count,
+            comments_count: account.comments_count,
+            followers_count: account.followers_count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SuspendUserRequest {
+    /// Shown to admins only.
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AdminUserListQuery {
+    /// Part of a username or email, case-insensitive.
+    pub q: Option<String>,
+    /// Only suspended users when `true`, only active ones when `false`.
+    pub suspended: Option<bool>,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
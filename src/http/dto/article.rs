@@ -1,29 +1,53 @@
 use crate::http::dto::profile::Profile;
+use crate::model::article_page::ArticlePage;
+use crate::model::article_status::ArticleStatus;
+use crate::model::cursor::Cursor;
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
-use crate::model::persistence::article_view::{ArticleListView, ArticleView};
+use crate::model::persistence::article_view::{ArticleListView, ArticleSearchView, ArticleView};
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::search_terms::SearchTerms;
 use crate::model::values::slug::Slug;
 use crate::model::values::tag_name::TagName;
 use crate::model::values::username::Username;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ArticleResponse {
     pub article: ArticleItem,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ArticlesResponse {
     pub articles: Vec<ArticleListItem>,
     #[serde(rename = "articlesCount")]
     pub articles_count: u64,
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<Cursor>,
+    #[serde(rename = "prevCursor")]
+    pub prev_cursor: Option<Cursor>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl ArticlesResponse {
+    pub(crate) fn from_article_page(page: &ArticlePage, articles_count: u64) -> ArticlesResponse {
+        ArticlesResponse {
+            articles: page
+                .articles
+                .iter()
+                .map(ArticleListItem::from_article_view)
+                .collect(),
+            articles_count,
+            next_cursor: page.next_cursor,
+            prev_cursor: page.prev_cursor,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ArticleItem {
     pub slug: Slug,
     pub title: ArticleTitle,
@@ -39,6 +63,10 @@ pub struct ArticleItem {
     #[serde(rename = "favoritesCount")]
     pub favorites_count: i64,
     pub author: Profile,
+    pub status: ArticleStatus,
+    /// When a scheduled article goes live.
+    #[serde(rename = "publishAt")]
+    pub publish_at: Option<DateTime<Utc>>,
 }
 
 impl ArticleItem {
@@ -59,11 +87,13 @@ impl ArticleItem {
                 image: view.author_image.clone(),
                 following: view.following,
             },
+            status: view.status,
+            publish_at: view.publish_at,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ArticleListItem {
     pub slug: Slug,
     pub title: ArticleTitle,
@@ -78,6 +108,10 @@ pub struct ArticleListItem {
     #[serde(rename = "favoritesCount")]
     pub favorites_count: i64,
     pub author: Profile,
+    pub status: ArticleStatus,
+    /// When a scheduled article goes live.
+    #[serde(rename = "publishAt")]
+    pub publish_at: Option<DateTime<Utc>>,
 }
 
 impl ArticleListItem {
@@ -97,30 +131,64 @@ impl ArticleListItem {
                 image: view.author_image.clone(),
                 following: view.following,
             },
+            status: view.status,
+            publish_at: view.publish_at,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleSearchResponse {
+    pub articles: Vec<ArticleSearchItem>,
+    #[serde(rename = "articlesCount")]
+    pub articles_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleSearchItem {
+    #[serde(flatten)]
+    pub article: ArticleListItem,
+    /// Relevance rank; higher is a better match.
+    pub score: f32,
+    /// Excerpt of the body with matches wrapped in `<mark>` tags.
+    pub snippet: String,
+}
+
+impl ArticleSearchItem {
+    pub(crate) fn from_search_view(view: &ArticleSearchView) -> ArticleSearchItem {
+        ArticleSearchItem {
+            article: ArticleListItem::from_article_view(&view.article),
+            score: view.score,
+            snippet: view.snippet.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateArticleRequest {
     pub article: CreateArticle,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateArticle {
     pub title: ArticleTitle,
     pub description: ArticleDescription,
     pub body: ArticleBody,
     #[serde(rename = "tagList")]
     pub tag_list: Option<Vec<TagName>>,
+    /// Defaults to `published`, or `scheduled` when `publishAt` is given.
+    pub status: Option<ArticleStatus>,
+    /// Future time at which a scheduled article is published.
+    #[serde(rename = "publishAt")]
+    pub publish_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateArticleRequest {
     pub article: UpdateArticleQuery,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateArticleQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<ArticleTitle>,
@@ -128,19 +196,41 @@ pub struct UpdateArticleQuery {
     pub description: Option<ArticleDescription>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<ArticleBody>,
+    /// Left unchanged when omitted; `publishAt` alone reschedules the article.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ArticleStatus>,
+    #[serde(rename = "publishAt", skip_serializing_if = "Option::is_none")]
+    pub publish_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ArticleListQuery {
     pub tag: Option<TagName>,
     pub author: Option<Username>,
     pub favorited: Option<Username>,
     pub limit: Option<Limit>,
+    /// Ignored when `cursor` is given.
     pub offset: Option<Offset>,
+    pub cursor: Option<Cursor>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ArticleFeedListQuery {
+    pub limit: Option<Limit>,
+    /// Ignored when `cursor` is given.
+    pub offset: Option<Offset>,
+    pub cursor: Option<Cursor>,
+}
+
+#[derive(Serialize, Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ArticleSearchQuery {
+    /// Search terms; supports quoted phrases, `or` and `-` to exclude words.
+    pub q: SearchTerms,
+    pub tag: Option<TagName>,
+    pub author: Option<Username>,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
 }
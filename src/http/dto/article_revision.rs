@@ -0,0 +1,100 @@
+use crate::model::article_field::ArticleField;
+use crate::model::line_diff::{DiffLine, DiffOp};
+use crate::model::persistence::article_revision::ArticleRevision;
+use crate::model::values::article_body::ArticleBody;
+use crate::model::values::article_description::ArticleDescription;
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleRevisionsResponse {
+    pub revisions: Vec<ArticleRevisionSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleRevisionSummary {
+    pub number: i32,
+    /// Username of whoever made the change, if the account still exists.
+    pub author: Option<Username>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "changedFields")]
+    pub changed_fields: Vec<ArticleField>,
+}
+
+impl ArticleRevisionSummary {
+    pub fn from_article_revision(revision: ArticleRevision) -> ArticleRevisionSummary {
+        ArticleRevisionSummary {
+            number: revision.number,
+            author: revision.author,
+            created_at: revision.created_at,
+            changed_fields: revision.changed_fields,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleRevisionResponse {
+    pub revision: ArticleRevisionItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleRevisionItem {
+    pub number: i32,
+    pub author: Option<Username>,
+    pub title: ArticleTitle,
+    pub description: ArticleDescription,
+    pub body: ArticleBody,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "changedFields")]
+    pub changed_fields: Vec<ArticleField>,
+}
+
+impl ArticleRevisionItem {
+    pub fn from_article_revision(revision: ArticleRevision) -> ArticleRevisionItem {
+        ArticleRevisionItem {
+            number: revision.number,
+            author: revision.author,
+            title: revision.title,
+            description: revision.description,
+            body: revision.body,
+            created_at: revision.created_at,
+            changed_fields: revision.changed_fields,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RevisionDiffQuery {
+    /// Revision to compare against; defaults to the one just before.
+    pub from: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleRevisionDiffResponse {
+    pub from: i32,
+    pub to: i32,
+    pub fields: Vec<ArticleFieldDiff>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleFieldDiff {
+    pub field: ArticleField,
+    pub changed: bool,
+    pub lines: Vec<DiffLine>,
+}
+
+impl ArticleFieldDiff {
+    pub fn new(field: ArticleField, lines: Vec<DiffLine>) -> ArticleFieldDiff {
+        ArticleFieldDiff {
+            field,
+            changed: lines.iter().any(|line| line.op != DiffOp::Equal),
+            lines,
+        }
+    }
+}
@@ -1,54 +1,124 @@
 use crate::http::dto::profile::Profile;
+use crate::model::comment_thread::CommentThread;
+use crate::model::persistence::comment_revision::CommentRevision;
 use crate::model::persistence::comment_view::CommentView;
 use crate::model::values::comment_body::CommentBody;
 use crate::model::values::comment_id::CommentId;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CommentResponse {
     pub comment: CommentItem,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CommentsResponse {
     pub comments: Vec<CommentItem>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CommentItem {
     pub id: CommentId,
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<CommentId>,
+    /// 0 for top-level comments, 1 for their replies, and so on.
+    pub depth: i16,
     #[serde(rename = "createdAt")]
     pub created_at: DateTime<Utc>,
     #[serde(rename = "updatedAt")]
     pub updated_at: DateTime<Utc>,
+    /// `[deleted]` for deleted comments that still have replies.
     pub body: CommentBody,
-    pub author: Profile,
+    /// Absent for deleted comments.
+    pub author: Option<Profile>,
+    pub deleted: bool,
+    /// Whether the body was changed after posting.
+    pub edited: bool,
+    #[schema(no_recursion)]
+    pub replies: Vec<CommentItem>,
 }
 
 impl CommentItem {
     pub fn from_comment_view(view: CommentView) -> CommentItem {
+        let author = (!view.deleted).then_some(Profile {
+            username: view.author,
+            bio: view.author_bio,
+            image: view.author_image,
+            following: view.following,
+        });
+
         CommentItem {
             id: view.id,
+            parent_id: view.parent_id,
+            depth: view.depth,
             created_at: view.created_at,
             updated_at: view.updated_at,
             body: view.body,
-            author: Profile {
-                username: view.author,
-                bio: view.author_bio,
-                image: view.author_image,
-                following: view.following,
-            },
+            author,
+            deleted: view.deleted,
+            edited: view.edited,
+            replies: vec![],
+        }
+    }
+
+    pub fn from_comment_thread(thread: CommentThread) -> CommentItem {
+        CommentItem {
+            replies: thread
+                .replies
+                .into_iter()
+                .map(CommentItem::from_comment_thread)
+                .collect(),
+            ..CommentItem::from_comment_view(thread.comment)
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateCommentRequest {
     pub comment: CreateComment,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateComment {
     pub body: CommentBody,
+    /// Comment on the same article to reply to.
+    #[serde(rename = "parentId", default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<CommentId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateCommentRequest {
+    pub comment: UpdateComment,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateComment {
+    pub body: CommentBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CommentRevisionsResponse {
+    pub revisions: Vec<CommentRevisionItem>,
+}
+
+/// A body the comment had before an edit.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CommentRevisionItem {
+    pub body: CommentBody,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "replacedAt")]
+    pub replaced_at: DateTime<Utc>,
+}
+
+impl CommentRevisionItem {
+    pub fn from_comment_revision(revision: CommentRevision) -> CommentRevisionItem {
+        CommentRevisionItem {
+            body: revision.body,
+            created_at: revision.created_at,
+            replaced_at: revision.replaced_at,
+        }
+    }
 }
@@ -0,0 +1,92 @@
+use crate::model::health::{HealthStatus, ReadinessReport};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HealthResponse {
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checks: Option<HealthChecks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HealthChecks {
+    pub database: DatabaseHealth,
+    pub migrations: MigrationsHealth,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DatabaseHealth {
+    pub status: HealthStatus,
+    #[serde(rename = "latencyMs")]
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connections: Option<u32>,
+    #[serde(rename = "idleConnections", skip_serializing_if = "Option::is_none")]
+    pub idle_connections: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MigrationsHealth {
+    pub status: HealthStatus,
+    #[serde(rename = "latencyMs")]
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub mismatched: Vec<i64>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub dirty: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HealthResponse {
+    pub(crate) fn live() -> HealthResponse {
+        HealthResponse {
+            status: HealthStatus::Up,
+            checks: None,
+        }
+    }
+
+    pub(crate) fn from_readiness_report(report: &ReadinessReport) -> HealthResponse {
+        let database = DatabaseHealth {
+            status: report.database_status(),
+            latency_ms: report.database.latency.as_millis() as u64,
+            connections: report.database.outcome.as_ref().ok().map(|s| s.connections),
+            idle_connections: report
+                .database
+                .outcome
+                .as_ref()
+                .ok()
+                .map(|s| s.idle_connections),
+            error: report.database.outcome.as_ref().err().cloned(),
+        };
+
+        let migration_status = report.migrations.outcome.as_ref().ok();
+
+        let migrations = MigrationsHealth {
+            status: report.migrations_status(),
+            latency_ms: report.migrations.latency.as_millis() as u64,
+            applied: migration_status.map(|s| s.applied),
+            pending: migration_status.map(|s| s.pending),
+            mismatched: migration_status
+                .map(|s| s.mismatched.clone())
+                .unwrap_or_default(),
+            dirty: migration_status.map(|s| s.dirty.clone()).unwrap_or_default(),
+            error: report.migrations.outcome.as_ref().err().cloned(),
+        };
+
+        HealthResponse {
+            status: report.status(),
+            checks: Some(HealthChecks {
+                database,
+                migrations,
+            }),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// RFC 7517 JSON Web Key Set. Empty when tokens are signed with a shared secret.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JwksResponse {
+    #[schema(value_type = Vec<Object>)]
+    pub keys: Vec<Jwk>,
+}
+
+impl JwksResponse {
+    pub(crate) fn from_jwk_set(set: &JwkSet) -> JwksResponse {
+        JwksResponse {
+            keys: set.keys.clone(),
+        }
+    }
+}
@@ -1,8 +1,17 @@
+pub mod access_token;
+pub mod admin;
 pub mod article;
+pub mod article_revision;
 pub mod comment;
 pub mod error;
+pub mod health;
+pub mod jwks;
 pub mod login;
+pub mod oidc;
 pub mod profile;
 pub mod register;
+pub mod report;
 pub mod tag;
+pub mod token;
+pub mod two_factor;
 pub mod user;
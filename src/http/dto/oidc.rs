@@ -0,0 +1,46 @@
+use crate::model::persistence::user_identity::UserIdentity;
+use crate::model::values::identity_id::IdentityId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OidcAuthorizationResponse {
+    /// Provider page to send the user to.
+    #[serde(rename = "authorizationUrl")]
+    pub authorization_url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OidcCallbackRequest {
+    /// `code` query parameter the provider redirected back with.
+    pub code: String,
+    /// `state` query parameter the provider redirected back with.
+    pub state: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IdentitiesResponse {
+    pub identities: Vec<IdentityItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IdentityItem {
+    pub id: IdentityId,
+    pub provider: String,
+    /// Email of the provider account when it was linked.
+    pub email: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl IdentityItem {
+    pub(crate) fn from_identity(identity: UserIdentity) -> IdentityItem {
+        IdentityItem {
+            id: identity.id,
+            provider: identity.provider,
+            email: identity.email,
+            created_at: identity.created_at,
+        }
+    }
+}
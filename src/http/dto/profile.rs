@@ -3,13 +3,14 @@ use crate::model::values::bio::Bio;
 use crate::model::values::image::Image;
 use crate::model::values::username::Username;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ProfileResponse {
     pub profile: Profile,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Profile {
     pub username: Username,
     pub bio: Option<Bio>,
@@ -0,0 +1,107 @@
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::report::{Report, ReportedContent};
+use crate::model::report::{ReportAction, ReportReason};
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::comment_body::CommentBody;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::report_details::ReportDetails;
+use crate::model::values::slug::Slug;
+use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateReportRequest {
+    pub report: CreateReport,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateReport {
+    pub reason: ReportReason,
+    /// Anything the moderators should know; only they can see it.
+    pub details: Option<ReportDetails>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportResponse {
+    pub report: ReportItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportItem {
+    pub reason: ReportReason,
+    pub details: Option<ReportDetails>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReportItem {
+    pub(crate) fn from_report(report: Report) -> ReportItem {
+        ReportItem {
+            reason: report.reason,
+            details: report.details,
+            created_at: report.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportedContentResponse {
+    pub reports: Vec<ReportedContentItem>,
+    /// Number of reported articles and comments, not of single reports.
+    #[serde(rename = "reportsCount")]
+    pub reports_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportedContentItem {
+    /// Article the reports are about, or that holds the reported comment.
+    pub slug: Slug,
+    pub title: ArticleTitle,
+    /// Present when the reports are about a comment.
+    #[serde(rename = "commentId")]
+    pub comment_id: Option<CommentId>,
+    #[serde(rename = "commentBody")]
+    pub comment_body: Option<CommentBody>,
+    /// Author of the reported content.
+    pub author: Username,
+    #[serde(rename = "reportsCount")]
+    pub reports_count: i64,
+    pub reasons: Vec<ReportReason>,
+    #[serde(rename = "firstReportedAt")]
+    pub first_reported_at: DateTime<Utc>,
+    #[serde(rename = "lastReportedAt")]
+    pub last_reported_at: DateTime<Utc>,
+    pub hidden: bool,
+}
+
+impl ReportedContentItem {
+    pub(crate) fn from_reported_content(content: ReportedContent) -> ReportedContentItem {
+        ReportedContentItem {
+            slug: content.slug,
+            title: content.title,
+            comment_id: content.comment_id,
+            comment_body: content.comment_body,
+            author: content.author,
+            reports_count: content.reports_count,
+            reasons: content.reasons,
+            first_reported_at: content.first_reported_at,
+            last_reported_at: content.last_reported_at,
+            hidden: content.hidden,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ResolveReportsRequest {
+    pub action: ReportAction,
+}
+
+#[derive(Serialize, Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReportQueueQuery {
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
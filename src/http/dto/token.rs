@@ -0,0 +1,32 @@
+use crate::model::token_pair::TokenPair;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RefreshTokenRequest {
+    #[serde(rename = "refreshToken")]
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TokenResponse {
+    pub token: String,
+    #[serde(rename = "refreshToken")]
+    pub refresh_token: String,
+}
+
+impl TokenResponse {
+    pub(crate) fn from_token_pair(tokens: TokenPair) -> TokenResponse {
+        TokenResponse {
+            token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LogoutQuery {
+    /// Revoke every session of the user instead of only the current one.
+    pub all: Option<bool>,
+}
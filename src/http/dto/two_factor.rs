@@ -0,0 +1,60 @@
+use crate::domain::two_factor_service::{LoginChallenge, TotpEnrollment};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorEnrollmentResponse {
+    /// Base32 secret for authenticator apps that cannot scan the URI.
+    pub secret: String,
+    /// `otpauth://` URI, usually shown as a QR code.
+    #[serde(rename = "provisioningUri")]
+    pub provisioning_uri: String,
+}
+
+impl From<TotpEnrollment> for TwoFactorEnrollmentResponse {
+    fn from(enrollment: TotpEnrollment) -> Self {
+        TwoFactorEnrollmentResponse {
+            secret: enrollment.secret,
+            provisioning_uri: enrollment.provisioning_uri,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorCodeRequest {
+    /// Six digit code from the authenticator app, or a recovery code.
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RecoveryCodesResponse {
+    /// Single-use codes that stand in for the authenticator; shown only once.
+    #[serde(rename = "recoveryCodes")]
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LoginChallengeResponse {
+    #[serde(rename = "challengeToken")]
+    pub challenge_token: String,
+    /// Seconds left to complete the login with a code.
+    #[serde(rename = "expiresIn")]
+    pub expires_in: i64,
+}
+
+impl From<LoginChallenge> for LoginChallengeResponse {
+    fn from(challenge: LoginChallenge) -> Self {
+        LoginChallengeResponse {
+            challenge_token: challenge.token,
+            expires_in: challenge.expires_in.num_seconds(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LoginTwoFactorRequest {
+    #[serde(rename = "challengeToken")]
+    pub challenge_token: String,
+    /// Six digit code from the authenticator app, or a recovery code.
+    pub code: String,
+}
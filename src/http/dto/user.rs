@@ -1,22 +1,31 @@
 use crate::model::persistence::user::User;
+use crate::model::role::Role;
+use crate::model::token_pair::TokenPair;
 use crate::model::values::bio::Bio;
 use crate::model::values::email::Email;
 use crate::model::values::image::Image;
+use crate::model::values::password::Password;
 use crate::model::values::username::Username;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserResponse {
     pub user: UserData,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserData {
     pub email: Email,
     pub token: String,
+    #[serde(rename = "refreshToken", skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
     pub username: Username,
     pub bio: Option<Bio>,
     pub image: Option<Image>,
+    pub role: Role,
+    /// Whether the user has confirmed their email address.
+    pub verified: bool,
 }
 
 impl UserData {
@@ -24,28 +33,56 @@ impl UserData {
         UserData {
             email: user.email,
             token,
+            refresh_token: None,
             username: user.username,
             bio: user.bio,
             image: user.image,
+            role: user.role,
+            verified: user.email_verified_at.is_some(),
+        }
+    }
+
+    pub(crate) fn with_tokens(user: User, tokens: TokenPair) -> Self {
+        UserData {
+            refresh_token: Some(tokens.refresh_token),
+            ..UserData::new(user, tokens.access_token)
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateUserRequest {
     pub user: UpdateUser,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateUser {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<Email>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<Username>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub password: Option<crate::model::values::password::Password>,
+    pub password: Option<Password>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bio: Option<Bio>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<Image>,
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VerifyEmailRequest {
+    /// Token from the verification email.
+    pub token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ForgotPasswordRequest {
+    pub email: Email,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ResetPasswordRequest {
+    /// Token from the password reset email.
+    pub token: String,
+    pub password: Password,
+}
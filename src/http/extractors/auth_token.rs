@@ -1,64 +1,135 @@
-use crate::{http::AppState, utils::jwt::verify_token};
+use crate::app_error::AppError;
+use crate::domain::access_token_service::is_access_token;
+use crate::domain::policy::Actor;
+use crate::http::AppState;
+use crate::http::session_cookies;
+use crate::model::role::Role;
+use crate::model::scope::Scope;
 use crate::model::values::user_id::UserId;
-use axum::{
-    extract::FromRequestParts,
-    http::{StatusCode, request::Parts},
-};
+use crate::utils::jwt::Claims;
+use axum::extract::FromRequestParts;
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+use axum_extra::extract::cookie::CookieJar;
 use uuid::Uuid;
 
+/// What a request was authenticated with.
+pub(crate) enum Credential {
+    /// An access token from a login, good for anything its user may do.
+    Session(Claims),
+    /// A personal access token: it reads like a session, but only changes
+    /// what its scopes allow.
+    AccessToken(Vec<Scope>),
+}
+
 pub struct AuthToken {
     pub(crate) user_id: UserId,
+    pub(crate) role: Role,
     pub(crate) raw_token: String,
+    pub(crate) credential: Credential,
 }
 
+impl AuthToken {
+    pub(crate) fn actor(&self) -> Actor {
+        Actor {
+            user_id: self.user_id,
+            role: self.role,
+        }
+    }
+
+    /// For handlers that change content; sessions hold every scope.
+    pub(crate) fn require_scope(&self, scope: Scope) -> Result<(), AppError> {
+        match &self.credential {
+            Credential::Session(_) => Ok(()),
+            Credential::AccessToken(scopes) if scopes.contains(&scope) => Ok(()),
+            Credential::AccessToken(_) => Err(AppError::MissingScope(scope)),
+        }
+    }
+
+    /// For handlers no scope covers, such as account settings, moderation
+    /// and managing tokens themselves.
+    pub(crate) fn require_session(&self) -> Result<&Claims, AppError> {
+        match &self.credential {
+            Credential::Session(claims) => Ok(claims),
+            Credential::AccessToken(_) => Err(AppError::SessionRequired),
+        }
+    }
+}
+
+/// The token of an `Authorization` header in the `Token` or `Bearer` scheme.
+pub(crate) fn authorization_token(header: &str) -> Option<&str> {
+    let (scheme, token) = header.split_once(' ')?;
+    let token = token.trim();
+    let known_scheme = scheme.eq_ignore_ascii_case("Token") || scheme.eq_ignore_ascii_case("Bearer");
+
+    (known_scheme && !token.is_empty()).then_some(token)
+}
+
+/// Reads the `Authorization` header or, failing that, the session cookie.
+/// Cookies come with the browser's every request, so writes authenticated by
+/// one must also pass the CSRF check.
 impl FromRequestParts<AppState> for Option<AuthToken> {
-    type Rejection = (StatusCode, &'static str);
+    type Rejection = AppError;
 
     async fn from_request_parts(
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
-        let jwt = &state.jwt;
-
-        let maybe_raw_header = parts
-            .headers
-            .get(axum::http::header::AUTHORIZATION)
-            .and_then(|h| h.to_str().ok());
-
-        if let Some(raw_header) = maybe_raw_header {
-            let token = raw_header
-                .strip_prefix("Token ")
-                .ok_or((StatusCode::UNAUTHORIZED, "Invalid Token format"))?;
-
-            let parsed_token = verify_token(jwt, token)
-                .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid or expired token"))?;
-
-            let uuid: Uuid = parsed_token.sub.parse().map_err(|_| {
-                (
-                    StatusCode::UNAUTHORIZED,
-                    "Couldn't extract user id from token",
-                )
-            })?;
-            let user_id = UserId::from(uuid);
-
-            Ok(Some(AuthToken {
-                user_id,
+        let jar = CookieJar::from_headers(&parts.headers);
+
+        let token = match parts.headers.get(AUTHORIZATION) {
+            Some(header) => header
+                .to_str()
+                .ok()
+                .and_then(authorization_token)
+                .ok_or(AppError::Unauthorized)?,
+            None => match session_cookies::session_token(&state.config, &jar) {
+                Some(token) => {
+                    if !parts.method.is_safe() {
+                        session_cookies::verify_csrf(&parts.headers, &jar)?;
+                    }
+                    token
+                }
+                None => return Ok(None),
+            },
+        };
+
+        if is_access_token(token) {
+            let (access_token, role) = state.access_token_service.authenticate(token).await?;
+
+            return Ok(Some(AuthToken {
+                user_id: access_token.user_id,
+                role,
                 raw_token: token.to_string(),
-            }))
-        } else {
-            Ok(None)
+                credential: Credential::AccessToken(access_token.scopes),
+            }));
         }
+
+        let parsed_token = state.auth_service.authenticate(token).await?;
+
+        let uuid: Uuid = parsed_token
+            .sub
+            .parse()
+            .map_err(|_| AppError::Unauthorized)?;
+        let user_id = UserId::from(uuid);
+
+        Ok(Some(AuthToken {
+            user_id,
+            role: parsed_token.role,
+            raw_token: token.to_string(),
+            credential: Credential::Session(parsed_token),
+        }))
     }
 }
 
 impl FromRequestParts<AppState> for AuthToken {
-    type Rejection = (StatusCode, &'static str);
+    type Rejection = AppError;
 
     async fn from_request_parts(
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
         let maybe_token = Option::<AuthToken>::from_request_parts(parts, state).await?;
-        maybe_token.ok_or((StatusCode::UNAUTHORIZED, "Authorization token required"))
+        maybe_token.ok_or(AppError::Unauthorized)
     }
 }
@@ -0,0 +1,87 @@
+use crate::http::AppState;
+use crate::model::values::slug::Slug;
+use axum::extract::{FromRequestParts, OriginalUri, Path};
+use axum::http::header::LOCATION;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
+use std::collections::HashMap;
+
+/// Characters escaped when a slug is written back into a path segment.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// Guard for read endpoints under `/articles/{slug}`. When `slug` is one the
+/// article had before a title change, the request is rejected with a 301 to
+/// the same URL with the current slug.
+///
+/// Writes do not use it: `ArticleService` resolves old slugs for them, since
+/// clients do not reliably resend a request body after a redirect.
+pub struct CanonicalSlug;
+
+impl FromRequestParts<AppState> for CanonicalSlug {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let Ok(Path(params)) =
+            Path::<HashMap<String, String>>::from_request_parts(parts, state).await
+        else {
+            return Ok(CanonicalSlug);
+        };
+        let Some(slug) = params.get("slug").and_then(|s| Slug::try_from(s.as_str()).ok()) else {
+            return Ok(CanonicalSlug);
+        };
+
+        let Some(current_slug) = state
+            .article_service
+            .get_moved_slug(&slug)
+            .await
+            .map_err(IntoResponse::into_response)?
+        else {
+            return Ok(CanonicalSlug);
+        };
+
+        let uri = parts
+            .extensions
+            .get::<OriginalUri>()
+            .map(|original| &original.0)
+            .unwrap_or(&parts.uri);
+
+        let mut after_articles = false;
+        let path = uri
+            .path()
+            .split('/')
+            .map(|segment| {
+                let replaced = if after_articles {
+                    utf8_percent_encode(current_slug.value(), PATH_SEGMENT).to_string()
+                } else {
+                    segment.to_string()
+                };
+                after_articles = segment == "articles";
+                replaced
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let location = match uri.query() {
+            Some(query) => format!("{path}?{query}"),
+            None => path,
+        };
+
+        Err((StatusCode::MOVED_PERMANENTLY, [(LOCATION, location)]).into_response())
+    }
+}
@@ -1 +1,2 @@
 pub mod auth_token;
+pub mod canonical_slug;
\ No newline at end of file
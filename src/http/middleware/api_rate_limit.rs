@@ -0,0 +1,116 @@
+use crate::app_error::AppError;
+use crate::domain::access_token_service::is_access_token;
+use crate::domain::api_rate_limiter::{Client, RoutePolicy};
+use crate::http::AppState;
+use crate::http::extractors::auth_token::authorization_token;
+use crate::http::session_cookies;
+use axum::extract::{ConnectInfo, OriginalUri, Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::cookie::CookieJar;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tracing::warn;
+
+const RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+const RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+const RATELIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+/// Limits for a request, or `None` for routes that are not limited here:
+/// health probes, and login and registration, which have their own limiter.
+fn route_policy(method: &Method, path: &str) -> Option<RoutePolicy> {
+    if path.starts_with("/api/health")
+        || path == "/api/users/login"
+        || path == "/api/users/login/2fa"
+        || (method == Method::POST && path == "/api/users")
+    {
+        return None;
+    }
+
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        Some(RoutePolicy::Read)
+    } else {
+        Some(RoutePolicy::Write)
+    }
+}
+
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs_f64().ceil() as u64
+}
+
+fn set_headers(headers: &mut HeaderMap, limit: u32, remaining: u32, reset_after: Duration) {
+    headers.insert(RATELIMIT_LIMIT, HeaderValue::from(limit));
+    headers.insert(RATELIMIT_REMAINING, HeaderValue::from(remaining));
+    headers.insert(RATELIMIT_RESET, HeaderValue::from(ceil_secs(reset_after)));
+}
+
+/// Rate limits the API per signed-in user, whether by session or personal
+/// access token, or per IP for everyone else, and reports the quota in
+/// `RateLimit-*` headers. Requests from an unknown IP without a token are let
+/// through.
+pub(crate) async fn api_rate_limit(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let limiter = &state.api_rate_limiter;
+    if !limiter.is_enabled() {
+        return next.run(request).await;
+    }
+
+    // Nested routers only see the path with their prefix stripped.
+    let path = request
+        .extensions()
+        .get::<OriginalUri>()
+        .map(|uri| uri.path())
+        .unwrap_or_else(|| request.uri().path());
+    let Some(policy) = route_policy(request.method(), path) else {
+        return next.run(request).await;
+    };
+
+    let jar = CookieJar::from_headers(request.headers());
+    let token = match request.headers().get(AUTHORIZATION) {
+        Some(header) => header.to_str().ok().and_then(authorization_token),
+        None => session_cookies::session_token(&state.config, &jar),
+    };
+    let user_id = match token {
+        Some(token) if is_access_token(token) => state
+            .access_token_service
+            .token_user_id(token)
+            .await
+            .unwrap_or_else(|err| {
+                warn!("Couldn't look up personal access token for rate limiting: {err}");
+                None
+            }),
+        Some(token) => state.auth_service.token_user_id(token),
+        None => None,
+    };
+    let ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let Some(client) = user_id.map(Client::User).or(ip.map(Client::Ip)) else {
+        return next.run(request).await;
+    };
+
+    let decision = limiter.acquire(policy, client).await;
+    match decision.result {
+        Ok(quota) => {
+            let mut response = next.run(request).await;
+            set_headers(
+                response.headers_mut(),
+                decision.limit,
+                quota.remaining,
+                quota.reset_after,
+            );
+            response
+        }
+        Err(retry_after) => {
+            let mut response = AppError::TooManyRequests(retry_after).into_response();
+            set_headers(response.headers_mut(), decision.limit, 0, retry_after);
+            response
+        }
+    }
+}
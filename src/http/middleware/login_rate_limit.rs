@@ -0,0 +1,50 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use axum::body::{Body, to_bytes};
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::net::SocketAddr;
+
+/// Credentials are small; anything bigger is not worth buffering.
+const MAX_CREDENTIALS_BODY_BYTES: usize = 64 * 1024;
+
+/// The `user.email` of a login or registration body, if it has one.
+fn submitted_email(body: &[u8]) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_slice(body).ok()?;
+    value["user"]["email"].as_str().map(str::to_string)
+}
+
+/// Rate limits the routes that take a password, a second factor or an
+/// authorization code. A 401 can only come from a failed login and counts
+/// towards a lockout; any success clears the email's failures, except a 2FA
+/// challenge, which only means the password was right.
+pub(crate) async fn login_rate_limit(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    let (parts, body) = request.into_parts();
+    let body = to_bytes(body, MAX_CREDENTIALS_BODY_BYTES)
+        .await
+        .map_err(|_| AppError::BadData("Request body is too large".to_string()))?;
+    let email = submitted_email(&body);
+
+    state.login_limiter.check(ip, email.as_deref()).await?;
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        state.login_limiter.record_failure(ip, email.as_deref()).await;
+    } else if response.status().is_success() && response.status() != StatusCode::ACCEPTED {
+        state.login_limiter.record_success(email.as_deref()).await;
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,2 @@
+pub(crate) mod api_rate_limit;
+pub(crate) mod login_rate_limit;
@@ -1,22 +1,46 @@
 pub(crate) mod dto;
 pub(crate) mod extractors;
+pub(crate) mod middleware;
+pub(crate) mod openapi;
 pub(crate) mod routes;
+pub(crate) mod session_cookies;
 
-use axum::extract::Request;
+use axum::extract::{OriginalUri, Request};
+use routes::admin::admin_routes;
 use routes::users::user_routes;
 use routes::comments::comment_routes;
+use routes::health::health_routes;
+use routes::oidc::oidc_routes;
 use routes::profiles::profile_routes;
 use routes::articles::article_routes;
+use routes::revisions::revision_routes;
 use routes::tags::tag_routes;
+use routes::well_known::well_known_routes;
 use crate::{app_config::AppConfig};
+use crate::domain::access_token_service::AccessTokenService;
+use crate::domain::api_rate_limiter::ApiRateLimiter;
 use crate::domain::article_service::ArticleService;
+use crate::domain::auth_service::AuthService;
 use crate::domain::comment_service::CommentService;
+use crate::domain::email_verification_service::EmailVerificationService;
+use crate::domain::health_service::HealthService;
+use crate::domain::login_limiter::LoginLimiter;
+use crate::domain::oidc_service::OidcService;
+use crate::domain::password_reset_service::PasswordResetService;
 use crate::domain::profile_service::ProfileService;
+use crate::domain::report_service::ReportService;
 use crate::domain::tag_service::TagService;
+use crate::domain::two_factor_service::TwoFactorService;
 use crate::domain::user_service::UserService;
+use crate::http::openapi::ApiDoc;
 use axum::Router;
+use axum::middleware::from_fn_with_state;
+use middleware::api_rate_limit::api_rate_limit;
 use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, MakeSpan, TraceLayer};
 use tracing::Span;
+use utoipa::OpenApi;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_swagger_ui::SwaggerUi;
 #[derive(Clone)]
 struct FilteringMakeSpan<'a> {
     inner: DefaultMakeSpan,
@@ -34,7 +58,14 @@ impl<'a> FilteringMakeSpan<'a> {
 
 impl<B> MakeSpan<B> for FilteringMakeSpan<'_> {
     fn make_span(&mut self, request: &Request<B>) -> Span {
-        if self.exceptions.contains(&request.uri().path()) {
+        // Nested routers only see the path with their prefix stripped.
+        let path = request
+            .extensions()
+            .get::<OriginalUri>()
+            .map(|uri| uri.path())
+            .unwrap_or_else(|| request.uri().path());
+
+        if self.exceptions.contains(&path) {
             Span::none()
         } else {
             self.inner.make_span(request)
@@ -43,20 +74,34 @@ impl<B> MakeSpan<B> for FilteringMakeSpan<'_> {
 }
 
 pub fn router(state: AppState) -> Router {
-    let api_routes = Router::new()
-        .merge(user_routes::user_routes())
+    let api_routes = OpenApiRouter::new()
+        .merge(user_routes::user_routes(&state))
+        .merge(oidc_routes::oidc_routes(&state))
         .merge(profile_routes::profile_routes())
         .merge(article_routes::article_routes())
+        .merge(revision_routes::revision_routes())
         .merge(comment_routes::comment_routes())
         .merge(tag_routes::tag_routes())
+        .merge(admin_routes::admin_routes())
+        .merge(health_routes::health_routes())
+        .layer(from_fn_with_state(state.clone(), api_rate_limit))
         .layer(
             TraceLayer::new_for_http()
-                .make_span_with(FilteringMakeSpan::except_routes(vec!["/api/health"]))
+                .make_span_with(FilteringMakeSpan::except_routes(vec![
+                    "/api/health",
+                    "/api/health/live",
+                    "/api/health/ready",
+                ]))
                 .on_response(DefaultOnResponse::new().level(tracing::Level::INFO)),
         );
 
-    Router::new()
+    let (routes, openapi) = OpenApiRouter::with_openapi(ApiDoc::openapi())
         .nest("/api", api_routes)
+        .merge(well_known_routes::well_known_routes())
+        .split_for_parts();
+
+    routes
+        .merge(SwaggerUi::new("/api/docs").url("/api/openapi.json", openapi))
         .with_state(state)
 }
 
@@ -64,9 +109,18 @@ pub fn router(state: AppState) -> Router {
 pub struct AppState {
     pub config: AppConfig,
     pub user_service: UserService,
+    pub auth_service: AuthService,
+    pub access_token_service: AccessTokenService,
+    pub email_verification_service: EmailVerificationService,
+    pub password_reset_service: PasswordResetService,
+    pub two_factor_service: TwoFactorService,
+    pub oidc_service: OidcService,
     pub article_service: ArticleService,
     pub comment_service: CommentService,
     pub tag_service: TagService,
     pub profile_service: ProfileService,
-    pub jwt: String,
+    pub report_service: ReportService,
+    pub health_service: HealthService,
+    pub login_limiter: LoginLimiter,
+    pub api_rate_limiter: ApiRateLimiter,
 }
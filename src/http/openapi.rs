@@ -0,0 +1,41 @@
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Conduit API",
+        description = "RealWorld-compatible blogging platform API.",
+        license(name = "MIT", identifier = "MIT")
+    ),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "users", description = "Registration, authentication and the current user"),
+        (name = "oidc", description = "Sign-in with OpenID Connect providers"),
+        (name = "profiles", description = "Public profiles and follows"),
+        (name = "articles", description = "Articles, feed and favorites"),
+        (name = "revisions", description = "Article edit history"),
+        (name = "comments", description = "Comments on articles"),
+        (name = "tags", description = "Article tags"),
+        (name = "admin", description = "User management and the moderation queue"),
+        (name = "health", description = "Liveness and readiness probes"),
+        (name = "keys", description = "Public keys for verifying access tokens"),
+    )
+)]
+pub(crate) struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+
+        components.add_security_scheme(
+            "token",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+                "Authorization",
+                "Access or personal access token in the `Bearer` or `Token` scheme, e.g. `Bearer eyJhbGciOi...`. With cookie sessions enabled, browsers may send the `session` cookie instead, plus an `X-CSRF-Token` header repeating the `csrf_token` cookie on writes",
+            ))),
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use crate::http::AppState;
+use crate::http::routes::admin::{
+    delete_user::delete_user,
+    force_password_reset::force_password_reset,
+    get_user::get_user,
+    list_reports::list_reports,
+    list_users::list_users,
+    resolve_article_reports::resolve_article_reports,
+    resolve_comment_reports::resolve_comment_reports,
+    suspend_user::suspend_user,
+    unsuspend_user::unsuspend_user
+};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub(crate) fn admin_routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(list_users::list_users))
+        .routes(routes!(get_user::get_user, delete_user::delete_user))
+        .routes(routes!(suspend_user::suspend_user, unsuspend_user::unsuspend_user))
+        .routes(routes!(force_password_reset::force_password_reset))
+        .routes(routes!(list_reports::list_reports))
+        .routes(routes!(resolve_article_reports::resolve_article_reports))
+        .routes(routes!(resolve_comment_reports::resolve_comment_reports))
+}
@@ -0,0 +1,38 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::username::Username;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    delete,
+    path = "/admin/users/{username}",
+    tag = "admin",
+    params(("username" = Username, Path, description = "Username of the user")),
+    security(("token" = [])),
+    responses(
+        (status = 204, description = "User deleted together with their articles and comments"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only admins can manage users; personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 422, description = "Cannot delete yourself", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn delete_user(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<StatusCode, AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, username = %username, "Delete user: {}", username);
+
+    state
+        .user_service
+        .delete_user(&auth.actor(), &username)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
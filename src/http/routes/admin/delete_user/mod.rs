@@ -0,0 +1 @@
+pub(crate) mod delete_user;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::admin::{AdminUserItem, AdminUserResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::username::Username;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/admin/users/{username}/password-reset",
+    tag = "admin",
+    params(("username" = Username, Path, description = "Username of the user")),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "User is signed out and mailed a link to reset their password, which they must do before signing in", body = AdminUserResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only admins can manage users; personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "User is signed out and must reset their password, but the link could not be mailed; retrying sends another", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn force_password_reset(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<Json<AdminUserResponse>, AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, username = %username, "Force password reset: {}", username);
+
+    let account = state
+        .user_service
+        .require_password_reset(&auth.actor(), &username)
+        .await?;
+
+    state
+        .password_reset_service
+        .send_required_reset(account.id)
+        .await?;
+
+    Ok(Json(AdminUserResponse {
+        user: AdminUserItem::from_user_account(account),
+    }))
+}
@@ -0,0 +1 @@
+pub(crate) mod force_password_reset;
\ No newline at end of file
@@ -0,0 +1 @@
+pub(crate) mod get_user;
\ No newline at end of file
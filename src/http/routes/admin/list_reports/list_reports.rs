@@ -0,0 +1,42 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::report::{ReportQueueQuery, ReportedContentItem, ReportedContentResponse};
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::{Query, State};
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/admin/reports",
+    tag = "admin",
+    params(ReportQueueQuery),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Reported articles and comments, most reported first", body = ReportedContentResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only moderators can see reports; personal access tokens cannot be used here", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn list_reports(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Query(params): Query<ReportQueueQuery>,
+) -> Result<Json<ReportedContentResponse>, AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, params = ?params, "List reports");
+
+    let (content, reports_count) = state
+        .report_service
+        .get_reported_content(&auth.actor(), params.limit, params.offset)
+        .await?;
+
+    Ok(Json(ReportedContentResponse {
+        reports: content
+            .into_iter()
+            .map(ReportedContentItem::from_reported_content)
+            .collect(),
+        reports_count,
+    }))
+}
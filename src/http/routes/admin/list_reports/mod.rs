@@ -0,0 +1 @@
+pub(crate) mod list_reports;
\ No newline at end of file
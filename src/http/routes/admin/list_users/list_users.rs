@@ -0,0 +1,39 @@
+use crate::app_error::AppError;
+use crate::domain::commands::list_users_query::ListUsersQuery;
+use crate::http::AppState;
+use crate::http::dto::admin::{AdminUserItem, AdminUserListQuery, AdminUsersResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::{Query, State};
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/admin/users",
+    tag = "admin",
+    params(AdminUserListQuery),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Users with their activity counts, newest first", body = AdminUsersResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only admins can manage users; personal access tokens cannot be used here", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn list_users(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Query(params): Query<AdminUserListQuery>,
+) -> Result<Json<AdminUsersResponse>, AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, params = ?params, "List users");
+
+    let query = ListUsersQuery::from_request(params);
+
+    let (users, users_count) = state.user_service.list_users(&auth.actor(), query).await?;
+
+    Ok(Json(AdminUsersResponse {
+        users: users.into_iter().map(AdminUserItem::from_user_account).collect(),
+        users_count,
+    }))
+}
@@ -0,0 +1 @@
+pub(crate) mod list_users;
\ No newline at end of file
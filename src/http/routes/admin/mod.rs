@@ -0,0 +1,10 @@
+pub(crate) mod admin_routes;
+pub(crate) mod delete_user;
+pub(crate) mod force_password_reset;
+pub(crate) mod get_user;
+pub(crate) mod list_reports;
+pub(crate) mod list_users;
+pub(crate) mod resolve_article_reports;
+pub(crate) mod resolve_comment_reports;
+pub(crate) mod suspend_user;
+pub(crate) mod unsuspend_user;
@@ -0,0 +1 @@
+pub(crate) mod resolve_article_reports;
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::report::ResolveReportsRequest;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/admin/reports/articles/{slug}/resolve",
+    tag = "admin",
+    params(("slug" = Slug, Path, description = "Article slug")),
+    request_body = ResolveReportsRequest,
+    security(("token" = [])),
+    responses(
+        (status = 204, description = "All open reports on the article resolved"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only moderators can resolve reports, and only admins can suspend; personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "Article not found or not reported", body = ErrorResponse),
+        (status = 422, description = "Cannot suspend yourself", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn resolve_article_reports(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(slug): Path<Slug>,
+    Json(payload): Json<ResolveReportsRequest>,
+) -> Result<StatusCode, AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, payload = ?payload, "Resolve reports on article: {}", slug);
+
+    state
+        .report_service
+        .resolve_reports(&auth.actor(), &slug, None, payload.action)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1 @@
+pub(crate) mod resolve_comment_reports;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::report::ResolveReportsRequest;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/admin/reports/articles/{slug}/comments/{id}/resolve",
+    tag = "admin",
+    params(
+        ("slug" = Slug, Path, description = "Article slug"),
+        ("id" = CommentId, Path, description = "Comment id"),
+    ),
+    request_body = ResolveReportsRequest,
+    security(("token" = [])),
+    responses(
+        (status = 204, description = "All open reports on the comment resolved"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only moderators can resolve reports, and only admins can suspend; personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "Comment not found or not reported", body = ErrorResponse),
+        (status = 422, description = "Cannot suspend yourself", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn resolve_comment_reports(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, comment_id)): Path<(Slug, CommentId)>,
+    Json(payload): Json<ResolveReportsRequest>,
+) -> Result<StatusCode, AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, payload = ?payload, "Resolve reports on comment {} of article: {}", comment_id, slug);
+
+    state
+        .report_service
+        .resolve_reports(&auth.actor(), &slug, Some(comment_id), payload.action)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1 @@
+pub(crate) mod suspend_user;
\ No newline at end of file
@@ -0,0 +1,43 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::admin::{AdminUserItem, AdminUserResponse, SuspendUserRequest};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::username::Username;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/admin/users/{username}/suspension",
+    tag = "admin",
+    params(("username" = Username, Path, description = "Username of the user")),
+    request_body = SuspendUserRequest,
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Suspended user; their sessions are revoked", body = AdminUserResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only admins can manage users; personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 422, description = "Cannot suspend yourself", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn suspend_user(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+    Json(payload): Json<SuspendUserRequest>,
+) -> Result<Json<AdminUserResponse>, AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, username = %username, "Suspend user: {}", username);
+
+    let account = state
+        .user_service
+        .suspend_user(&auth.actor(), &username, payload.reason)
+        .await?;
+
+    Ok(Json(AdminUserResponse {
+        user: AdminUserItem::from_user_account(account),
+    }))
+}
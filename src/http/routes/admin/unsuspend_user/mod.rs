@@ -0,0 +1 @@
+pub(crate) mod unsuspend_user;
\ No newline at end of file
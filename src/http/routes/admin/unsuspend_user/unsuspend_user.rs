@@ -0,0 +1,40 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::admin::{AdminUserItem, AdminUserResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::username::Username;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    delete,
+    path = "/admin/users/{username}/suspension",
+    tag = "admin",
+    params(("username" = Username, Path, description = "Username of the user")),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "User is no longer suspended", body = AdminUserResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only admins can manage users; personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn unsuspend_user(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<Json<AdminUserResponse>, AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, username = %username, "Unsuspend user: {}", username);
+
+    let account = state
+        .user_service
+        .unsuspend_user(&auth.actor(), &username)
+        .await?;
+
+    Ok(Json(AdminUserResponse {
+        user: AdminUserItem::from_user_account(account),
+    }))
+}
@@ -1,24 +1,29 @@
 use crate::http::AppState;
-use crate::http::routes::articles::articles_list::articles_list::list_articles;
-use crate::http::routes::articles::articles_feed::articles_feed::feed_articles;
-use crate::http::routes::articles::get_article::get_article::get_article;
-use crate::http::routes::articles::update_article::update_article::update_article;
-use crate::http::routes::articles::delete_article::delete_article::delete_article;
-use crate::http::routes::articles::favorite_article::favorite_article::favorite_article;
-use crate::http::routes::articles::unfavorite_article::unfavorite_article::unfavorite_article;
-use crate::http::routes::articles::create_article::create_article::create_article;
+use crate::http::routes::articles::{
+    articles_feed::articles_feed,
+    articles_list::articles_list,
+    create_article::create_article,
+    delete_article::delete_article,
+    favorite_article::favorite_article,
+    get_article::get_article,
+    report_article::report_article,
+    search_articles::search_articles,
+    unfavorite_article::unfavorite_article,
+    update_article::update_article
+};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-use axum::{ Router};
-use axum::routing::{delete, get, post, put};
-
-pub(crate) fn article_routes() -> Router<AppState> {
-    Router::new()
-        .route("/articles", get(list_articles))
-        .route("/articles", post(create_article))
-        .route("/articles/feed", get(feed_articles))
-        .route("/articles/{slug}", get(get_article))
-        .route("/articles/{slug}", put(update_article))
-        .route("/articles/{slug}", delete(delete_article))
-        .route("/articles/{slug}/favorite", post(favorite_article))
-        .route("/articles/{slug}/favorite", delete(unfavorite_article))
-}
\ No newline at end of file
+pub(crate) fn article_routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(articles_list::list_articles))
+        .routes(routes!(create_article::create_article))
+        .routes(routes!(articles_feed::feed_articles))
+        .routes(routes!(search_articles::search_articles))
+        .routes(routes!(get_article::get_article))
+        .routes(routes!(update_article::update_article))
+        .routes(routes!(delete_article::delete_article))
+        .routes(routes!(favorite_article::favorite_article))
+        .routes(routes!(unfavorite_article::unfavorite_article))
+        .routes(routes!(report_article::report_article))
+}
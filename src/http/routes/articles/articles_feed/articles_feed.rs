@@ -2,15 +2,25 @@ use axum::extract::State;
 use crate::http::AppState;
 use crate::app_error::AppError;
 use crate::domain::commands::get_feed_query::GetFeedQuery;
-use crate::http::dto::article::{
-    ArticleFeedListQuery, ArticleListItem, ArticlesResponse,
-};
+use crate::http::dto::article::{ArticleFeedListQuery, ArticlesResponse};
 use crate::http::extractors::auth_token::AuthToken;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Query};
 use axum::{Json};
 use tracing::info;
 
 
+#[utoipa::path(
+    get,
+    path = "/articles/feed",
+    tag = "articles",
+    params(ArticleFeedListQuery),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Articles by followed authors", body = ArticlesResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn feed_articles(
     State(state): State<AppState>,
     auth: AuthToken,
@@ -20,19 +30,11 @@ pub(crate) async fn feed_articles(
 
     let query = GetFeedQuery::from_request(params, auth.user_id);
 
-    let articles = state.article_service.get_feed(query).await?;
-
-    let views: Vec<_> = articles
-        .iter()
-        .map(ArticleListItem::from_article_view)
-        .collect();
+    let page = state.article_service.get_feed(query).await?;
 
     let articles_count = state
         .article_service
         .count_feed_articles(auth.user_id)
         .await?;
-    Ok(Json(ArticlesResponse {
-        articles: views,
-        articles_count,
-    }))
+    Ok(Json(ArticlesResponse::from_article_page(&page, articles_count)))
 }
\ No newline at end of file
@@ -1,14 +1,24 @@
 use crate::app_error::AppError;
 use crate::domain::commands::list_articles_query::ListArticlesQuery;
 use crate::http::AppState;
-use crate::http::dto::article::{
-    ArticleListItem, ArticleListQuery, ArticlesResponse,
-};
+use crate::http::dto::article::{ArticleListQuery, ArticlesResponse};
 use crate::http::extractors::auth_token::AuthToken;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{ Query, State};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    get,
+    path = "/articles",
+    tag = "articles",
+    params(ArticleListQuery),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Articles ordered by most recent first", body = ArticlesResponse),
+        (status = 401, description = "Invalid token", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn list_articles(
     State(state): State<AppState>,
     auth: Option<AuthToken>,
@@ -19,19 +29,11 @@ pub(crate) async fn list_articles(
     let query = ListArticlesQuery::from_request(params);
     let user_id = auth.as_ref().map(|u| u.user_id);
 
-    let articles = state
+    let page = state
         .article_service
         .list_articles(query.clone(), user_id)
         .await?;
     let articles_count = state.article_service.count_articles(query, user_id).await?;
 
-    let views: Vec<_> = articles
-        .iter()
-        .map(ArticleListItem::from_article_view)
-        .collect();
-
-    Ok(Json(ArticlesResponse {
-        articles: views,
-        articles_count,
-    }))
+    Ok(Json(ArticlesResponse::from_article_page(&page, articles_count)))
 }
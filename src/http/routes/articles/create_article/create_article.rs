@@ -4,17 +4,34 @@ use crate::http::dto::article::{
     ArticleItem, ArticleResponse, CreateArticleRequest,
 };
 use crate::http::extractors::auth_token::AuthToken;
+use crate::model::scope::Scope;
 use axum::extract::{State};
 use axum::http::StatusCode;
 use axum::{Json};
 use tracing::info;
 use crate::app_error::AppError;
+use crate::http::dto::error::ErrorResponse;
 
+#[utoipa::path(
+    post,
+    path = "/articles",
+    tag = "articles",
+    request_body = CreateArticleRequest,
+    security(("token" = [])),
+    responses(
+        (status = 201, description = "Article created", body = ArticleResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Publishing needs a verified email address; personal access tokens need the articles:write scope", body = ErrorResponse),
+        (status = 409, description = "No free slug could be found for the title", body = ErrorResponse),
+        (status = 422, description = "Invalid article data", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn create_article(
     State(state): State<AppState>,
     auth: AuthToken,
     Json(payload): Json<CreateArticleRequest>,
 ) -> Result<(StatusCode, Json<ArticleResponse>), AppError> {
+    auth.require_scope(Scope::ArticlesWrite)?;
     info!(payload = ?payload, "Create article");
 
     let command = CreateArticleCommand::from_request(payload, auth.user_id);
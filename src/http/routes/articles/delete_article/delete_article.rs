@@ -1,21 +1,37 @@
 use crate::app_error::AppError;
 use crate::http::AppState;
 use crate::http::extractors::auth_token::AuthToken;
+use crate::model::scope::Scope;
 use crate::model::values::slug::Slug;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use tracing::info;
 
+#[utoipa::path(
+    delete,
+    path = "/articles/{slug}",
+    tag = "articles",
+    params(("slug" = Slug, Path, description = "Article slug")),
+    security(("token" = [])),
+    responses(
+        (status = 204, description = "Article deleted"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only the author or a moderator can delete the article; personal access tokens need the articles:write scope", body = ErrorResponse),
+        (status = 404, description = "Article not found", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn delete_article(
     State(state): State<AppState>,
     auth: AuthToken,
     Path(slug): Path<Slug>,
 ) -> Result<StatusCode, AppError> {
+    auth.require_scope(Scope::ArticlesWrite)?;
     info!(slug = %slug, "Delete article: {}", slug);
 
     state
         .article_service
-        .delete_article(slug, auth.user_id)
+        .delete_article(slug, &auth.actor())
         .await?;
 
     Ok(StatusCode::NO_CONTENT)
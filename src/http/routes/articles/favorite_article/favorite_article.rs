@@ -3,15 +3,30 @@ use crate::http::AppState;
 use crate::http::dto::article::{ArticleItem, ArticleResponse};
 use crate::http::extractors::auth_token::AuthToken;
 use crate::model::values::slug::Slug;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Path, State};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    post,
+    path = "/articles/{slug}/favorite",
+    tag = "articles",
+    params(("slug" = Slug, Path, description = "Article slug")),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Favorited article", body = ArticleResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "Article not found", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn favorite_article(
     State(state): State<AppState>,
     auth: AuthToken,
     Path(slug): Path<Slug>,
 ) -> Result<Json<ArticleResponse>, AppError> {
+    auth.require_session()?;
     info!(slug = %slug, "Favorite article: {}", slug);
 
     state
@@ -3,13 +3,28 @@ use crate::http::AppState;
 use crate::http::dto::article::{ArticleItem, ArticleResponse};
 use crate::http::extractors::auth_token::AuthToken;
 use crate::model::values::slug::Slug;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::canonical_slug::CanonicalSlug;
 use axum::extract::{Path, State};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    get,
+    path = "/articles/{slug}",
+    tag = "articles",
+    params(("slug" = Slug, Path, description = "Article slug")),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Article", body = ArticleResponse),
+        (status = 301, description = "The article was renamed; `Location` has the URL under its current slug"),
+        (status = 404, description = "Article not found", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn get_article(
     State(state): State<AppState>,
     auth: Option<AuthToken>,
+    _canonical: CanonicalSlug,
     Path(slug): Path<Slug>,
 ) -> Result<Json<ArticleResponse>, AppError> {
     info!(slug = %slug, "Get article: {}", slug);
@@ -6,4 +6,6 @@ pub(crate) mod articles_list;
 pub(crate) mod unfavorite_article;
 pub(crate) mod update_article;
 pub(crate) mod get_article;
+pub(crate) mod report_article;
+pub(crate) mod search_articles;
 pub(crate) mod article_routes;
\ No newline at end of file
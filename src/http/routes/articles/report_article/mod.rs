@@ -0,0 +1 @@
+pub(crate) mod report_article;
\ No newline at end of file
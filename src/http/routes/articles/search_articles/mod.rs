@@ -0,0 +1 @@
+pub(crate) mod search_articles;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use crate::app_error::AppError;
+use crate::domain::commands::search_articles_query::SearchArticlesQuery;
+use crate::http::AppState;
+use crate::http::dto::article::{ArticleSearchItem, ArticleSearchQuery, ArticleSearchResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::extract::{Query, State};
+use axum::Json;
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/articles/search",
+    tag = "articles",
+    params(ArticleSearchQuery),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Matching articles ordered by relevance", body = ArticleSearchResponse),
+        (status = 400, description = "Missing or invalid search query"),
+        (status = 401, description = "Invalid token", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn search_articles(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Query(params): Query<ArticleSearchQuery>,
+) -> Result<Json<ArticleSearchResponse>, AppError> {
+    info!(params = ?params, "Search articles");
+
+    let query = SearchArticlesQuery::from_request(params);
+    let user_id = auth.as_ref().map(|u| u.user_id);
+
+    let results = state
+        .article_service
+        .search_articles(query.clone(), user_id)
+        .await?;
+    let articles_count = state
+        .article_service
+        .count_search_results(query, user_id)
+        .await?;
+
+    Ok(Json(ArticleSearchResponse {
+        articles: results
+            .iter()
+            .map(ArticleSearchItem::from_search_view)
+            .collect(),
+        articles_count,
+    }))
+}
@@ -5,24 +5,42 @@ use crate::http::dto::article::{
     ArticleItem, ArticleResponse, UpdateArticleRequest,
 };
 use crate::http::extractors::auth_token::AuthToken;
+use crate::model::scope::Scope;
 use crate::model::values::slug::Slug;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Path, State};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    put,
+    path = "/articles/{slug}",
+    tag = "articles",
+    params(("slug" = Slug, Path, description = "Article slug")),
+    request_body = UpdateArticleRequest,
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Updated article", body = ArticleResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only the author can update the article, and publishing needs a verified email address; personal access tokens need the articles:write scope", body = ErrorResponse),
+        (status = 404, description = "Article not found", body = ErrorResponse),
+        (status = 409, description = "No free slug could be found for the new title", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn update_article(
     State(state): State<AppState>,
     auth: AuthToken,
     Path(slug): Path<Slug>,
     Json(payload): Json<UpdateArticleRequest>,
 ) -> Result<Json<ArticleResponse>, AppError> {
+    auth.require_scope(Scope::ArticlesWrite)?;
     info!(slug = %slug, payload = ?payload , "Update article: {}", slug);
 
     let command = UpdateArticleCommand::from_request(payload, slug);
 
     let updated_article = state
         .article_service
-        .update_article(command, auth.user_id)
+        .update_article(command, &auth.actor())
         .await?;
 
     let article = ArticleItem::from_article_view(&updated_article);
@@ -1,15 +1,21 @@
 use crate::http::AppState;
-use crate::http::routes::comments:: {
-    create_comment::create_comment::create_comment,
-    delete_comment::delete_comment::delete_comment,
-    get_comments::get_comments::get_comments
+use crate::http::routes::comments::{
+    create_comment::create_comment,
+    delete_comment::delete_comment,
+    get_comment_revisions::get_comment_revisions,
+    get_comments::get_comments,
+    report_comment::report_comment,
+    update_comment::update_comment,
 };
-use axum::routing::{delete, get, post};
-use axum::{Router};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub(crate) fn comment_routes() -> Router<AppState> {
-    Router::new()
-        .route("/articles/{slug}/comments", post(create_comment))
-        .route("/articles/{slug}/comments", get(get_comments))
-        .route("/articles/{slug}/comments/{id}", delete(delete_comment))
-}
\ No newline at end of file
+pub(crate) fn comment_routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_comment::create_comment))
+        .routes(routes!(get_comments::get_comments))
+        .routes(routes!(delete_comment::delete_comment))
+        .routes(routes!(update_comment::update_comment))
+        .routes(routes!(get_comment_revisions::get_comment_revisions))
+        .routes(routes!(report_comment::report_comment))
+}
@@ -5,18 +5,36 @@ use crate::http::dto::comment::{
     CommentItem, CommentResponse, CreateCommentRequest,
 };
 use crate::http::extractors::auth_token::AuthToken;
+use crate::model::scope::Scope;
 use crate::model::values::slug::Slug;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    post,
+    path = "/articles/{slug}/comments",
+    tag = "comments",
+    params(("slug" = Slug, Path, description = "Article slug")),
+    request_body = CreateCommentRequest,
+    security(("token" = [])),
+    responses(
+        (status = 201, description = "Comment created", body = CommentResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens need the comments:write scope", body = ErrorResponse),
+        (status = 404, description = "Article not found", body = ErrorResponse),
+        (status = 422, description = "Invalid comment data or parent comment", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn create_comment(
     State(state): State<AppState>,
     auth: AuthToken,
     Path(slug): Path<Slug>,
     Json(payload): Json<CreateCommentRequest>,
 ) -> Result<(StatusCode, Json<CommentResponse>), AppError> {
+    auth.require_scope(Scope::CommentsWrite)?;
     info!(user_id=%{auth.user_id}, payload=?payload, "Add comment to article: {}", slug);
 
     let article = state
@@ -1,22 +1,41 @@
 use crate::app_error::AppError;
 use crate::http::AppState;
 use crate::http::extractors::auth_token::AuthToken;
+use crate::model::scope::Scope;
 use crate::model::values::comment_id::CommentId;
 use crate::model::values::slug::Slug;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use tracing::info;
 
+#[utoipa::path(
+    delete,
+    path = "/articles/{slug}/comments/{id}",
+    tag = "comments",
+    params(
+        ("slug" = Slug, Path, description = "Article slug"),
+        ("id" = CommentId, Path, description = "Comment id"),
+    ),
+    security(("token" = [])),
+    responses(
+        (status = 204, description = "Comment deleted, or replaced by a `[deleted]` placeholder if it has replies"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only the author or a moderator can delete the comment; personal access tokens need the comments:write scope", body = ErrorResponse),
+        (status = 404, description = "Comment not found", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn delete_comment(
     State(state): State<AppState>,
     auth: AuthToken,
     Path((slug, comment_id)): Path<(Slug, CommentId)>,
 ) -> Result<StatusCode, AppError> {
+    auth.require_scope(Scope::CommentsWrite)?;
     info!(user_id=%{auth.user_id}, slug = %slug, comment_id = %comment_id, "Delete comment {} from article: {}", comment_id, slug);
 
     state
         .comment_service
-        .delete_comment(comment_id, auth.user_id)
+        .delete_comment(comment_id, &auth.actor())
         .await?;
 
     Ok(StatusCode::NO_CONTENT)
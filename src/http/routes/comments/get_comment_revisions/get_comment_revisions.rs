@@ -0,0 +1,53 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::comment::{CommentRevisionItem, CommentRevisionsResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::canonical_slug::CanonicalSlug;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/articles/{slug}/comments/{id}/revisions",
+    tag = "comments",
+    params(
+        ("slug" = Slug, Path, description = "Article slug"),
+        ("id" = CommentId, Path, description = "Comment id"),
+    ),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Previous bodies of the comment, oldest first", body = CommentRevisionsResponse),
+        (status = 301, description = "The article was renamed; `Location` has the URL under its current slug"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only the author or a moderator can see the edit history", body = ErrorResponse),
+        (status = 404, description = "Article or comment not found", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn get_comment_revisions(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    _canonical: CanonicalSlug,
+    Path((slug, comment_id)): Path<(Slug, CommentId)>,
+) -> Result<Json<CommentRevisionsResponse>, AppError> {
+    info!(user_id=%{auth.user_id}, slug = %slug, comment_id = %comment_id, "Get revisions of comment {} on article: {}", comment_id, slug);
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let revisions = state
+        .comment_service
+        .get_comment_revisions(article.id, comment_id, &auth.actor())
+        .await?
+        .into_iter()
+        .map(CommentRevisionItem::from_comment_revision)
+        .collect();
+
+    Ok(Json(CommentRevisionsResponse { revisions }))
+}
@@ -0,0 +1 @@
+pub(crate) mod get_comment_revisions;
\ No newline at end of file
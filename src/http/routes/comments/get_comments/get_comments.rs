@@ -5,13 +5,28 @@ use crate::http::dto::comment::{
 };
 use crate::http::extractors::auth_token::AuthToken;
 use crate::model::values::slug::Slug;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::canonical_slug::CanonicalSlug;
 use axum::extract::{Path, State};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    get,
+    path = "/articles/{slug}/comments",
+    tag = "comments",
+    params(("slug" = Slug, Path, description = "Article slug")),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Top-level comments on the article, newest first, with nested replies", body = CommentsResponse),
+        (status = 301, description = "The article was renamed; `Location` has the URL under its current slug"),
+        (status = 404, description = "Article not found", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn get_comments(
     State(state): State<AppState>,
     auth: Option<AuthToken>,
+    _canonical: CanonicalSlug,
     Path(slug): Path<Slug>,
 ) -> Result<Json<CommentsResponse>, AppError> {
     let maybe_user_id = auth.as_ref().map(|a| a.user_id);
@@ -19,18 +34,18 @@ pub(crate) async fn get_comments(
 
     let article = state
         .article_service
-        .get_article(&slug, None)
+        .get_article(&slug, maybe_user_id)
         .await?
         .ok_or_else(|| AppError::NotFound)?;
 
-    let comment_views = state
+    let threads = state
         .comment_service
         .get_comments(article.id, maybe_user_id)
         .await?;
 
-    let comments = comment_views
+    let comments = threads
         .into_iter()
-        .map(CommentItem::from_comment_view)
+        .map(CommentItem::from_comment_thread)
         .collect();
 
     Ok(Json(CommentsResponse { comments }))
@@ -1,4 +1,7 @@
 pub(crate) mod comment_routes;
 pub(crate) mod create_comment;
 pub(crate) mod delete_comment;
-pub(crate) mod get_comments;
\ No newline at end of file
+pub(crate) mod get_comment_revisions;
+pub(crate) mod get_comments;
+pub(crate) mod report_comment;
+pub(crate) mod update_comment;
\ No newline at end of file
@@ -0,0 +1 @@
+pub(crate) mod report_comment;
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::app_error::AppError;
+use crate::domain::commands::report_content_command::ReportContentCommand;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::report::{CreateReportRequest, ReportItem, ReportResponse};
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/articles/{slug}/comments/{id}/report",
+    tag = "comments",
+    params(
+        ("slug" = Slug, Path, description = "Article slug"),
+        ("id" = CommentId, Path, description = "Comment id"),
+    ),
+    request_body = CreateReportRequest,
+    security(("token" = [])),
+    responses(
+        (status = 201, description = "Report sent to the moderators", body = ReportResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "Article or comment not found", body = ErrorResponse),
+        (status = 409, description = "Comment already reported by this user", body = ErrorResponse),
+        (status = 422, description = "Invalid report, or the comment is your own", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn report_comment(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, comment_id)): Path<(Slug, CommentId)>,
+    Json(payload): Json<CreateReportRequest>,
+) -> Result<(StatusCode, Json<ReportResponse>), AppError> {
+    auth.require_session()?;
+    info!(user_id = %{auth.user_id}, payload = ?payload, "Report comment {} on article: {}", comment_id, slug);
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let command = ReportContentCommand::from_request(payload, auth.user_id);
+
+    let report = state
+        .report_service
+        .report_comment(article.id, comment_id, command)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ReportResponse {
+            report: ReportItem::from_report(report),
+        }),
+    ))
+}
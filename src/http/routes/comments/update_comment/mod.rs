@@ -0,0 +1 @@
+pub(crate) mod update_comment;
\ No newline at end of file
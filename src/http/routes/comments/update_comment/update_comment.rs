@@ -0,0 +1,57 @@
+use crate::app_error::AppError;
+use crate::domain::commands::update_comment_command::UpdateCommentCommand;
+use crate::http::AppState;
+use crate::http::dto::comment::{CommentItem, CommentResponse, UpdateCommentRequest};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::scope::Scope;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    put,
+    path = "/articles/{slug}/comments/{id}",
+    tag = "comments",
+    params(
+        ("slug" = Slug, Path, description = "Article slug"),
+        ("id" = CommentId, Path, description = "Comment id"),
+    ),
+    request_body = UpdateCommentRequest,
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Updated comment; the previous body is kept as a revision", body = CommentResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only the author can edit the comment; personal access tokens need the comments:write scope", body = ErrorResponse),
+        (status = 404, description = "Article or comment not found", body = ErrorResponse),
+        (status = 422, description = "Invalid comment data", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn update_comment(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, comment_id)): Path<(Slug, CommentId)>,
+    Json(payload): Json<UpdateCommentRequest>,
+) -> Result<Json<CommentResponse>, AppError> {
+    auth.require_scope(Scope::CommentsWrite)?;
+    info!(user_id=%{auth.user_id}, slug = %slug, comment_id = %comment_id, "Update comment {} on article: {}", comment_id, slug);
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let command = UpdateCommentCommand::from_request(payload, article.id, comment_id);
+
+    let comment_view = state
+        .comment_service
+        .update_comment(command, &auth.actor())
+        .await?;
+
+    let comment = CommentItem::from_comment_view(comment_view);
+
+    Ok(Json(CommentResponse { comment }))
+}
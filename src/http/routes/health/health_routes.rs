@@ -0,0 +1,15 @@
+use crate::http::AppState;
+use crate::http::routes::health::{
+    liveness::liveness,
+    readiness::readiness
+};
+use axum::routing::get;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub(crate) fn health_routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .route("/health", get(readiness::readiness))
+        .routes(routes!(liveness::liveness))
+        .routes(routes!(readiness::readiness))
+}
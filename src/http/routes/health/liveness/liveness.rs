@@ -0,0 +1,14 @@
+use crate::http::dto::health::HealthResponse;
+use axum::Json;
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses(
+        (status = 200, description = "The process is running", body = HealthResponse),
+    )
+)]
+pub(crate) async fn liveness() -> Json<HealthResponse> {
+    Json(HealthResponse::live())
+}
@@ -0,0 +1 @@
+pub(crate) mod liveness;
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub(crate) mod health_routes;
+pub(crate) mod liveness;
+pub(crate) mod readiness;
@@ -0,0 +1 @@
+pub(crate) mod readiness;
\ No newline at end of file
@@ -0,0 +1,33 @@
+use crate::http::AppState;
+use crate::http::dto::health::HealthResponse;
+use crate::model::health::HealthStatus;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use tracing::debug;
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "All dependencies are available", body = HealthResponse),
+        (status = 503, description = "At least one dependency is unavailable", body = HealthResponse),
+    )
+)]
+pub(crate) async fn readiness(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<HealthResponse>) {
+    let report = state.health_service.check_readiness().await;
+
+    let response = HealthResponse::from_readiness_report(&report);
+
+    debug!(status = ?response.status, "Readiness check");
+
+    let status_code = match response.status {
+        HealthStatus::Up => StatusCode::OK,
+        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    (status_code, Json(response))
+}
@@ -1,6 +1,12 @@
+#![allow(clippy::module_inception)]
 
+pub(crate) mod admin;
 pub(crate) mod comments;
+pub(crate) mod health;
+pub(crate) mod oidc;
 pub(crate) mod profiles;
+pub(crate) mod revisions;
 pub(crate) mod tags;
 pub(crate) mod users;
+pub(crate) mod well_known;
 pub(crate) mod articles;
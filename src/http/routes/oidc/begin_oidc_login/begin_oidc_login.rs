@@ -0,0 +1,40 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::oidc::OidcAuthorizationResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/auth/oidc/{provider}/authorize",
+    tag = "oidc",
+    params(("provider" = String, Path, description = "Provider name")),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Provider URL to send the user to; signed-in users link the identity to their account and must finish the login signed in", body = OidcAuthorizationResponse),
+        (status = 401, description = "Invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "Unknown provider", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn begin_oidc_login(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Path(provider): Path<String>,
+) -> Result<Json<OidcAuthorizationResponse>, AppError> {
+    let link_to = match &auth {
+        Some(auth) => {
+            auth.require_session()?;
+            Some(auth.user_id)
+        }
+        None => None,
+    };
+    info!(provider = %provider, linking = link_to.is_some(), "Begin OpenID Connect sign-in");
+
+    let authorization_url = state.oidc_service.begin_login(&provider, link_to).await?;
+
+    Ok(Json(OidcAuthorizationResponse { authorization_url }))
+}
@@ -0,0 +1 @@
+pub(crate) mod begin_oidc_login;
\ No newline at end of file
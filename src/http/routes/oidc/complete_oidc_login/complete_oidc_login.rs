@@ -0,0 +1,82 @@
+use crate::app_error::AppError;
+use crate::domain::oidc_service::OidcOutcome;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::oidc::OidcCallbackRequest;
+use crate::http::dto::two_factor::LoginChallengeResponse;
+use crate::http::dto::user::{UserData, UserResponse};
+use crate::http::extractors::auth_token::AuthToken;
+use crate::http::session_cookies::session_cookies;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/auth/oidc/{provider}/callback",
+    tag = "oidc",
+    params(("provider" = String, Path, description = "Provider name")),
+    request_body = OidcCallbackRequest,
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Authenticated user with a fresh access and refresh token, also set as cookies when cookie sessions are enabled", body = UserResponse),
+        (status = 202, description = "Complete the login at /users/login/2fa", body = LoginChallengeResponse),
+        (status = 204, description = "Identity linked to the signed-in user, who started the login"),
+        (status = 401, description = "Unknown or expired state, a link finished by another user, or the provider rejected the sign-in", body = ErrorResponse),
+        (status = 403, description = "Account is suspended, or a personal access token was used", body = ErrorResponse),
+        (status = 404, description = "Unknown provider", body = ErrorResponse),
+        (status = 409, description = "The identity is linked to another account", body = ErrorResponse),
+        (status = 422, description = "The provider did not share a verified email address", body = ErrorResponse),
+        (status = 429, description = "Too many attempts; see the Retry-After header", body = ErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds to wait before trying again"))),
+    )
+)]
+pub(crate) async fn complete_oidc_login(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Path(provider): Path<String>,
+    Json(payload): Json<OidcCallbackRequest>,
+) -> Result<Response, AppError> {
+    let signed_in = match &auth {
+        Some(auth) => {
+            auth.require_session()?;
+            Some(auth.user_id)
+        }
+        None => None,
+    };
+
+    let user = match state
+        .oidc_service
+        .complete_login(&provider, &payload.code, &payload.state, signed_in)
+        .await?
+    {
+        OidcOutcome::Linked(user) => {
+            info!(user_id = %user.id, provider = %provider, "Linked OpenID Connect identity");
+            return Ok(StatusCode::NO_CONTENT.into_response());
+        }
+        OidcOutcome::SignedIn(user) => user,
+    };
+
+    info!(user_id = %user.id, provider = %provider, "Completed OpenID Connect sign-in");
+
+    if state.two_factor_service.is_enabled(user.id).await? {
+        let challenge = state.two_factor_service.create_challenge(user.id)?;
+        return Ok((
+            StatusCode::ACCEPTED,
+            Json(LoginChallengeResponse::from(challenge)),
+        )
+            .into_response());
+    }
+
+    let tokens = state
+        .auth_service
+        .create_session(user.id, user.role)
+        .await?;
+
+    let cookies = session_cookies(&state.config, &tokens);
+    let user = UserData::with_tokens(user, tokens);
+
+    Ok((cookies, Json(UserResponse { user })).into_response())
+}
@@ -0,0 +1 @@
+pub(crate) mod complete_oidc_login;
\ No newline at end of file
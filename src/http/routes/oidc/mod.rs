@@ -0,0 +1,3 @@
+pub(crate) mod begin_oidc_login;
+pub(crate) mod complete_oidc_login;
+pub(crate) mod oidc_routes;
\ No newline at end of file
@@ -0,0 +1,19 @@
+use crate::http::AppState;
+use crate::http::middleware::login_rate_limit::login_rate_limit;
+use crate::http::routes::oidc::{
+    begin_oidc_login::begin_oidc_login,
+    complete_oidc_login::complete_oidc_login
+};
+use axum::middleware::from_fn_with_state;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub(crate) fn oidc_routes(state: &AppState) -> OpenApiRouter<AppState> {
+    let callback_routes = OpenApiRouter::new()
+        .routes(routes!(complete_oidc_login::complete_oidc_login))
+        .route_layer(from_fn_with_state(state.clone(), login_rate_limit));
+
+    OpenApiRouter::new()
+        .merge(callback_routes)
+        .routes(routes!(begin_oidc_login::begin_oidc_login))
+}
@@ -3,15 +3,31 @@ use crate::http::AppState;
 use crate::http::dto::profile::{Profile, ProfileResponse};
 use crate::http::extractors::auth_token::AuthToken;
 use crate::model::values::username::Username;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Path, State};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    post,
+    path = "/profiles/{username}/follow",
+    tag = "profiles",
+    params(("username" = Username, Path, description = "Username to follow")),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Followed profile", body = ProfileResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 422, description = "Cannot follow yourself", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn follow_user(
     State(state): State<AppState>,
     auth: AuthToken,
     Path(username): Path<Username>,
 ) -> Result<Json<ProfileResponse>, AppError> {
+    auth.require_session()?;
     info!(user_id = %{auth.user_id}, username = %username, "Follow user: {}", username);
 
     let user = state
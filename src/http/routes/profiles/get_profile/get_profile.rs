@@ -3,10 +3,22 @@ use crate::http::AppState;
 use crate::http::dto::profile::{Profile, ProfileResponse};
 use crate::http::extractors::auth_token::AuthToken;
 use crate::model::values::username::Username;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Path, State};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    get,
+    path = "/profiles/{username}",
+    tag = "profiles",
+    params(("username" = Username, Path, description = "Username of the profile")),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Profile", body = ProfileResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn get_profile(
     State(state): State<AppState>,
     auth: Option<AuthToken>,
@@ -1,15 +1,15 @@
 use crate::http::AppState;
-use crate::http::routes::profiles:: {
-    follow_user::follow_user::follow_user,
-    get_profile::get_profile::get_profile,
-    unfollow_user::unfollow_user::unfollow_user
+use crate::http::routes::profiles::{
+    follow_user::follow_user,
+    get_profile::get_profile,
+    unfollow_user::unfollow_user
 };
-use axum::routing::{delete, get, post};
-use axum::{Router};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub(crate) fn profile_routes() -> Router<AppState> {
-    Router::new()
-        .route("/profiles/{username}", get(get_profile))
-        .route("/profiles/{username}/follow", post(follow_user))
-        .route("/profiles/{username}/follow", delete(unfollow_user))
+pub(crate) fn profile_routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(get_profile::get_profile))
+        .routes(routes!(follow_user::follow_user))
+        .routes(routes!(unfollow_user::unfollow_user))
 }
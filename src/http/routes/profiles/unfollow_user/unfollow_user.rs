@@ -3,15 +3,30 @@ use crate::http::AppState;
 use crate::http::dto::profile::{Profile, ProfileResponse};
 use crate::http::extractors::auth_token::AuthToken;
 use crate::model::values::username::Username;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::{Path, State};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    delete,
+    path = "/profiles/{username}/follow",
+    tag = "profiles",
+    params(("username" = Username, Path, description = "Username to unfollow")),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Unfollowed profile", body = ProfileResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn unfollow_user(
     State(state): State<AppState>,
     auth: AuthToken,
     Path(username): Path<Username>,
 ) -> Result<Json<ProfileResponse>, AppError> {
+    auth.require_session()?;
     info!(user_id = %{auth.user_id}, username = %username, "Unfollow user: {}", username);
 
     let user = state
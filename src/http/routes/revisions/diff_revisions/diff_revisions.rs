@@ -0,0 +1,53 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::article_revision::{
+    ArticleFieldDiff, ArticleRevisionDiffResponse, RevisionDiffQuery,
+};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::http::extractors::canonical_slug::CanonicalSlug;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/articles/{slug}/revisions/{number}/diff",
+    tag = "revisions",
+    params(
+        ("slug" = Slug, Path, description = "Article slug"),
+        ("number" = i32, Path, description = "Revision to compare to"),
+        RevisionDiffQuery,
+    ),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Line diff of each content field", body = ArticleRevisionDiffResponse),
+        (status = 301, description = "The article was renamed; `Location` has the URL under its current slug"),
+        (status = 404, description = "Article or revision not found", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn diff_revisions(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    _canonical: CanonicalSlug,
+    Path((slug, number)): Path<(Slug, i32)>,
+    Query(query): Query<RevisionDiffQuery>,
+) -> Result<Json<ArticleRevisionDiffResponse>, AppError> {
+    let from = query.from.unwrap_or(number - 1);
+    info!(slug = %slug, from = from, to = number, "Diff revisions {} and {} of article: {}", from, number, slug);
+
+    let fields = state
+        .article_service
+        .diff_revisions(&slug, from, number, auth.map(|a| a.user_id))
+        .await?
+        .into_iter()
+        .map(|(field, lines)| ArticleFieldDiff::new(field, lines))
+        .collect();
+
+    Ok(Json(ArticleRevisionDiffResponse {
+        from,
+        to: number,
+        fields,
+    }))
+}
@@ -0,0 +1 @@
+pub(crate) mod diff_revisions;
\ No newline at end of file
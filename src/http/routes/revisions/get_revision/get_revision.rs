@@ -0,0 +1,40 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::article_revision::{ArticleRevisionItem, ArticleRevisionResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::http::extractors::canonical_slug::CanonicalSlug;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/articles/{slug}/revisions/{number}",
+    tag = "revisions",
+    params(
+        ("slug" = Slug, Path, description = "Article slug"),
+        ("number" = i32, Path, description = "Revision number, starting at 1"),
+    ),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Article content as of the revision", body = ArticleRevisionResponse),
+        (status = 301, description = "The article was renamed; `Location` has the URL under its current slug"),
+        (status = 404, description = "Article or revision not found", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn get_revision(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    _canonical: CanonicalSlug,
+    Path((slug, number)): Path<(Slug, i32)>,
+) -> Result<Json<ArticleRevisionResponse>, AppError> {
+    info!(slug = %slug, number = number, "Get revision {} of article: {}", number, slug);
+
+    let revision = state.article_service.get_revision(&slug, number, auth.map(|a| a.user_id)).await?;
+
+    Ok(Json(ArticleRevisionResponse {
+        revision: ArticleRevisionItem::from_article_revision(revision),
+    }))
+}
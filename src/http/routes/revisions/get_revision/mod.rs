@@ -0,0 +1 @@
+pub(crate) mod get_revision;
\ No newline at end of file
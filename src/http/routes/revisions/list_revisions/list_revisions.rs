@@ -0,0 +1,41 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::article_revision::{ArticleRevisionSummary, ArticleRevisionsResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::http::extractors::canonical_slug::CanonicalSlug;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/articles/{slug}/revisions",
+    tag = "revisions",
+    params(("slug" = Slug, Path, description = "Article slug")),
+    security((), ("token" = [])),
+    responses(
+        (status = 200, description = "Revisions of the article, newest first", body = ArticleRevisionsResponse),
+        (status = 301, description = "The article was renamed; `Location` has the URL under its current slug"),
+        (status = 404, description = "Article not found", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn list_revisions(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    _canonical: CanonicalSlug,
+    Path(slug): Path<Slug>,
+) -> Result<Json<ArticleRevisionsResponse>, AppError> {
+    info!(slug = %slug, "List revisions of article: {}", slug);
+
+    let revisions = state
+        .article_service
+        .list_revisions(&slug, auth.map(|a| a.user_id))
+        .await?
+        .into_iter()
+        .map(ArticleRevisionSummary::from_article_revision)
+        .collect();
+
+    Ok(Json(ArticleRevisionsResponse { revisions }))
+}
@@ -0,0 +1 @@
+pub(crate) mod list_revisions;
\ No newline at end of file
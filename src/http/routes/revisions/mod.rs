@@ -0,0 +1,5 @@
+pub(crate) mod diff_revisions;
+pub(crate) mod get_revision;
+pub(crate) mod list_revisions;
+pub(crate) mod restore_revision;
+pub(crate) mod revision_routes;
\ No newline at end of file
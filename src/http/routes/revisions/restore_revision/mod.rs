@@ -0,0 +1 @@
+pub(crate) mod restore_revision;
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::article::{ArticleItem, ArticleResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::scope::Scope;
+use crate::model::values::slug::Slug;
+use axum::Json;
+use axum::extract::{Path, State};
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/articles/{slug}/revisions/{number}/restore",
+    tag = "revisions",
+    params(
+        ("slug" = Slug, Path, description = "Article slug"),
+        ("number" = i32, Path, description = "Revision to restore"),
+    ),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Article with the revision's content, recorded as a new revision", body = ArticleResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Only the author can restore a revision; personal access tokens need the articles:write scope", body = ErrorResponse),
+        (status = 404, description = "Article or revision not found", body = ErrorResponse),
+        (status = 409, description = "No free slug could be found for the restored title", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn restore_revision(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, number)): Path<(Slug, i32)>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    auth.require_scope(Scope::ArticlesWrite)?;
+    info!(user_id=%{auth.user_id}, slug = %slug, number = number, "Restore revision {} of article: {}", number, slug);
+
+    let article = state
+        .article_service
+        .restore_revision(slug, number, &auth.actor())
+        .await?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
@@ -0,0 +1,17 @@
+use crate::http::AppState;
+use crate::http::routes::revisions::{
+    diff_revisions::diff_revisions,
+    get_revision::get_revision,
+    list_revisions::list_revisions,
+    restore_revision::restore_revision,
+};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub(crate) fn revision_routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(list_revisions::list_revisions))
+        .routes(routes!(get_revision::get_revision))
+        .routes(routes!(diff_revisions::diff_revisions))
+        .routes(routes!(restore_revision::restore_revision))
+}
@@ -5,6 +5,14 @@ use axum::extract::State;
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    get,
+    path = "/tags",
+    tag = "tags",
+    responses(
+        (status = 200, description = "All tags", body = TagsResponse),
+    )
+)]
 pub(crate) async fn get_tags(
     State(state): State<AppState>,
 ) -> Result<Json<TagsResponse>, AppError> {
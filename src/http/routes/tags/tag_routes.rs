@@ -1,8 +1,8 @@
 use crate::http::AppState;
-use crate::http::routes::tags::get_tags::get_tags::get_tags;
-use axum::routing::get;
-use axum::{ Router};
+use crate::http::routes::tags::get_tags::get_tags;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub(crate) fn tag_routes() -> Router<AppState> {
-    Router::new().route("/tags", get(get_tags))
+pub(crate) fn tag_routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new().routes(routes!(get_tags::get_tags))
 }
@@ -0,0 +1,35 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::two_factor::TwoFactorEnrollmentResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/user/2fa",
+    tag = "users",
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "New TOTP secret; 2FA is enabled once a code confirms it", body = TwoFactorEnrollmentResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 409, description = "Two-factor authentication is already enabled", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn begin_two_factor(
+    State(app_state): State<AppState>,
+    auth_user: AuthToken,
+) -> Result<Json<TwoFactorEnrollmentResponse>, AppError> {
+    auth_user.require_session()?;
+    info!(user_id = %auth_user.user_id, "Begin two-factor enrollment");
+
+    let enrollment = app_state
+        .two_factor_service
+        .begin_enrollment(auth_user.user_id)
+        .await?;
+
+    Ok(Json(enrollment.into()))
+}
@@ -0,0 +1 @@
+pub(crate) mod begin_two_factor;
\ No newline at end of file
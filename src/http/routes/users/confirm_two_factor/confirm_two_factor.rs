@@ -0,0 +1,38 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::two_factor::{RecoveryCodesResponse, TwoFactorCodeRequest};
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/user/2fa/confirm",
+    tag = "users",
+    security(("token" = [])),
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor authentication enabled", body = RecoveryCodesResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 409, description = "Two-factor authentication is already enabled", body = ErrorResponse),
+        (status = 422, description = "Wrong code, or enrollment not started", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn confirm_two_factor(
+    State(app_state): State<AppState>,
+    auth_user: AuthToken,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> Result<Json<RecoveryCodesResponse>, AppError> {
+    auth_user.require_session()?;
+    info!(user_id = %auth_user.user_id, "Confirm two-factor enrollment");
+
+    let recovery_codes = app_state
+        .two_factor_service
+        .confirm_enrollment(auth_user.user_id, &payload.code)
+        .await?;
+
+    Ok(Json(RecoveryCodesResponse { recovery_codes }))
+}
@@ -0,0 +1 @@
+pub(crate) mod confirm_two_factor;
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::app_error::AppError;
+use crate::domain::commands::create_access_token_command::CreateAccessTokenCommand;
+use crate::http::AppState;
+use crate::http::dto::access_token::{
+    AccessTokenItem, AccessTokenResponse, CreateAccessTokenRequest,
+};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/user/tokens",
+    tag = "users",
+    request_body = CreateAccessTokenRequest,
+    security(("token" = [])),
+    responses(
+        (status = 201, description = "Token created; its value is only shown this once", body = AccessTokenResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot create tokens", body = ErrorResponse),
+        (status = 422, description = "Invalid name, scopes or expiry", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn create_access_token(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Json(payload): Json<CreateAccessTokenRequest>,
+) -> Result<(StatusCode, Json<AccessTokenResponse>), AppError> {
+    auth.require_session()?;
+    info!(user_id = %auth.user_id, name = %payload.access_token.name, "Create personal access token");
+
+    let command = CreateAccessTokenCommand::from_request(payload, auth.user_id);
+
+    let (token, secret) = state.access_token_service.create_token(command).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AccessTokenResponse {
+            access_token: AccessTokenItem::with_secret(token, secret),
+        }),
+    ))
+}
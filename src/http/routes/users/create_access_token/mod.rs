@@ -0,0 +1 @@
+pub(crate) mod create_access_token;
\ No newline at end of file
@@ -0,0 +1,38 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::two_factor::TwoFactorCodeRequest;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/user/2fa/disable",
+    tag = "users",
+    security(("token" = [])),
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 204, description = "Two-factor authentication disabled"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 422, description = "Wrong code, or 2FA is not enabled", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn disable_two_factor(
+    State(app_state): State<AppState>,
+    auth_user: AuthToken,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> Result<StatusCode, AppError> {
+    auth_user.require_session()?;
+    info!(user_id = %auth_user.user_id, "Disable two-factor authentication");
+
+    app_state
+        .two_factor_service
+        .disable(auth_user.user_id, &payload.code)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
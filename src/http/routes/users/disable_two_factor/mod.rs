@@ -0,0 +1 @@
+pub(crate) mod disable_two_factor;
\ No newline at end of file
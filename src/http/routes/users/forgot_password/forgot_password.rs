@@ -0,0 +1,29 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::user::ForgotPasswordRequest;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+#[utoipa::path(
+    post,
+    path = "/users/password/forgot",
+    tag = "users",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 202, description = "A reset link was mailed if the email belongs to a user"),
+        (status = 422, description = "Invalid email", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn forgot_password(
+    State(app_state): State<AppState>,
+    Json(payload): Json<ForgotPasswordRequest>,
+) -> Result<StatusCode, AppError> {
+    app_state
+        .password_reset_service
+        .request_reset(payload.email)
+        .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
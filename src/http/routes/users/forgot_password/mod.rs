@@ -0,0 +1 @@
+pub(crate) mod forgot_password;
\ No newline at end of file
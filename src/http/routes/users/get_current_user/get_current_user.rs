@@ -2,10 +2,21 @@ use crate::app_error::AppError;
 use crate::http::AppState;
 use crate::http::dto::user::{UserData, UserResponse};
 use crate::http::extractors::auth_token::AuthToken;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::State;
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    get,
+    path = "/user",
+    tag = "users",
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Current user", body = UserResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn get_current_user(
     State(app_state): State<AppState>,
     auth_user: AuthToken,
@@ -18,13 +29,7 @@ pub(crate) async fn get_current_user(
         .await?
         .ok_or(AppError::Unauthorized)?;
 
-    let user = UserData {
-        email: user.email,
-        token: auth_user.raw_token,
-        username: user.username,
-        bio: user.bio,
-        image: user.image,
-    };
+    let user = UserData::new(user, auth_user.raw_token);
 
     Ok(Json(UserResponse { user }))
 }
\ No newline at end of file
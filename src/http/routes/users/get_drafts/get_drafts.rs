@@ -0,0 +1,35 @@
+use crate::app_error::AppError;
+use crate::domain::commands::get_drafts_query::GetDraftsQuery;
+use crate::http::AppState;
+use crate::http::dto::article::{ArticleFeedListQuery, ArticlesResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::{Query, State};
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/user/drafts",
+    tag = "users",
+    params(ArticleFeedListQuery),
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Draft and scheduled articles of the current user", body = ArticlesResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn get_drafts(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Query(params): Query<ArticleFeedListQuery>,
+) -> Result<Json<ArticlesResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, params = ?params, "Get drafts");
+
+    let query = GetDraftsQuery::from_request(params, auth.user_id);
+
+    let page = state.article_service.get_drafts(query).await?;
+
+    let articles_count = state.article_service.count_drafts(auth.user_id).await?;
+    Ok(Json(ArticlesResponse::from_article_page(&page, articles_count)))
+}
@@ -0,0 +1 @@
+pub(crate) mod get_drafts;
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::access_token::{AccessTokenItem, AccessTokensResponse};
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/user/tokens",
+    tag = "users",
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Personal access tokens that were not revoked, newest first", body = AccessTokensResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot list tokens", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn list_access_tokens(
+    State(state): State<AppState>,
+    auth: AuthToken,
+) -> Result<Json<AccessTokensResponse>, AppError> {
+    auth.require_session()?;
+    info!(user_id = %auth.user_id, "List personal access tokens");
+
+    let tokens = state.access_token_service.list_tokens(auth.user_id).await?;
+
+    Ok(Json(AccessTokensResponse {
+        access_tokens: tokens
+            .into_iter()
+            .map(AccessTokenItem::from_access_token)
+            .collect(),
+    }))
+}
@@ -0,0 +1 @@
+pub(crate) mod list_access_tokens;
\ No newline at end of file
@@ -0,0 +1,34 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::oidc::{IdentitiesResponse, IdentityItem};
+use crate::http::extractors::auth_token::AuthToken;
+use axum::Json;
+use axum::extract::State;
+use tracing::info;
+
+#[utoipa::path(
+    get,
+    path = "/user/identities",
+    tag = "users",
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Identity provider accounts linked to the user", body = IdentitiesResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn list_identities(
+    State(state): State<AppState>,
+    auth: AuthToken,
+) -> Result<Json<IdentitiesResponse>, AppError> {
+    info!(user_id = %auth.user_id, "List linked identities");
+
+    let identities = state.oidc_service.list_identities(auth.user_id).await?;
+
+    Ok(Json(IdentitiesResponse {
+        identities: identities
+            .into_iter()
+            .map(IdentityItem::from_identity)
+            .collect(),
+    }))
+}
@@ -0,0 +1 @@
+pub(crate) mod list_identities;
\ No newline at end of file
@@ -2,25 +2,49 @@ use crate::app_error::AppError;
 use crate::domain::commands::login_command::LoginCommand;
 use crate::http::AppState;
 use crate::http::dto::login::LoginRequest;
+use crate::http::dto::two_factor::LoginChallengeResponse;
 use crate::http::dto::user::{UserData, UserResponse};
-use crate::utils::jwt::generate_token;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::session_cookies::session_cookies;
 use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
 use axum::{Json};
 use tracing::info;
 
+#[utoipa::path(
+    post,
+    path = "/users/login",
+    tag = "users",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Authenticated user with a fresh access and refresh token, also set as cookies when cookie sessions are enabled", body = UserResponse),
+        (status = 202, description = "Password accepted; complete the login at /users/login/2fa", body = LoginChallengeResponse),
+        (status = 401, description = "Invalid credentials", body = ErrorResponse),
+        (status = 403, description = "Account is suspended or needs a password reset", body = ErrorResponse),
+        (status = 429, description = "Too many attempts; see the Retry-After header", body = ErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds to wait before trying again"))),
+    )
+)]
 pub(crate) async fn login(
     State(app_state): State<AppState>,
     Json(payload): Json<LoginRequest>,
-) -> Result<Json<UserResponse>, AppError> {
+) -> Result<Response, AppError> {
     info!("Login attempt for email: {}", payload.user.email);
 
     let command = LoginCommand::from_request(payload);
 
     let user = app_state.user_service.login_user(command).await?;
 
-    let token = generate_token(app_state.jwt, user.id)?;
+    if app_state.two_factor_service.is_enabled(user.id).await? {
+        let challenge = app_state.two_factor_service.create_challenge(user.id)?;
+        return Ok((StatusCode::ACCEPTED, Json(LoginChallengeResponse::from(challenge))).into_response());
+    }
 
-    let user = UserData::new(user, token);
+    let tokens = app_state.auth_service.create_session(user.id, user.role).await?;
 
-    Ok(Json(UserResponse { user }))
-}
\ No newline at end of file
+    let cookies = session_cookies(&app_state.config, &tokens);
+    let user = UserData::with_tokens(user, tokens);
+
+    Ok((cookies, Json(UserResponse { user })).into_response())
+}
@@ -0,0 +1,45 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::two_factor::LoginTwoFactorRequest;
+use crate::http::dto::user::{UserData, UserResponse};
+use crate::http::session_cookies::session_cookies;
+use axum::Json;
+use axum::extract::State;
+use axum_extra::extract::cookie::CookieJar;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/users/login/2fa",
+    tag = "users",
+    request_body = LoginTwoFactorRequest,
+    responses(
+        (status = 200, description = "Authenticated user with a fresh access and refresh token, also set as cookies when cookie sessions are enabled", body = UserResponse),
+        (status = 401, description = "Invalid or expired challenge, or wrong code", body = ErrorResponse),
+        (status = 403, description = "Account is suspended or needs a password reset", body = ErrorResponse),
+        (status = 429, description = "Too many attempts; see the Retry-After header", body = ErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds to wait before trying again"))),
+    )
+)]
+pub(crate) async fn login_two_factor(
+    State(app_state): State<AppState>,
+    Json(payload): Json<LoginTwoFactorRequest>,
+) -> Result<(CookieJar, Json<UserResponse>), AppError> {
+    let user = app_state
+        .two_factor_service
+        .complete_login(&payload.challenge_token, &payload.code)
+        .await?;
+
+    info!(user_id = %user.id, "Completed two-factor login");
+
+    let tokens = app_state
+        .auth_service
+        .create_session(user.id, user.role)
+        .await?;
+
+    let cookies = session_cookies(&app_state.config, &tokens);
+    let user = UserData::with_tokens(user, tokens);
+
+    Ok((cookies, Json(UserResponse { user })))
+}
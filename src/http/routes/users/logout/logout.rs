@@ -0,0 +1,38 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::token::LogoutQuery;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::http::session_cookies::cleared_session_cookies;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum_extra::extract::cookie::CookieJar;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/users/logout",
+    tag = "users",
+    params(LogoutQuery),
+    security(("token" = [])),
+    responses(
+        (status = 204, description = "Session revoked and session cookies cleared"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn logout(
+    State(app_state): State<AppState>,
+    auth_user: AuthToken,
+    Query(params): Query<LogoutQuery>,
+) -> Result<(CookieJar, StatusCode), AppError> {
+    let all_sessions = params.all.unwrap_or(false);
+    info!(user_id = %auth_user.user_id, all_sessions, "Logout");
+
+    app_state
+        .auth_service
+        .logout(auth_user.user_id, auth_user.require_session()?, all_sessions)
+        .await?;
+
+    Ok((cleared_session_cookies(&app_state.config), StatusCode::NO_CONTENT))
+}
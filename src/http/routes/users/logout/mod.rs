@@ -0,0 +1 @@
+pub(crate) mod logout;
\ No newline at end of file
@@ -1,5 +1,21 @@
+pub(crate) mod create_access_token;
+pub(crate) mod forgot_password;
+pub(crate) mod list_access_tokens;
+pub(crate) mod list_identities;
 pub(crate) mod login;
+pub(crate) mod login_two_factor;
+pub(crate) mod logout;
+pub(crate) mod refresh_token;
 pub(crate) mod register;
+pub(crate) mod reset_password;
+pub(crate) mod resend_verification;
+pub(crate) mod revoke_access_token;
 pub(crate) mod user_routes;
 pub(crate) mod get_current_user;
-pub(crate) mod update_user;
\ No newline at end of file
+pub(crate) mod get_drafts;
+pub(crate) mod unlink_identity;
+pub(crate) mod update_user;
+pub(crate) mod begin_two_factor;
+pub(crate) mod confirm_two_factor;
+pub(crate) mod disable_two_factor;
+pub(crate) mod verify_email;
\ No newline at end of file
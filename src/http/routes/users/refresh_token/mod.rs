@@ -0,0 +1 @@
+pub(crate) mod refresh_token;
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::token::{RefreshTokenRequest, TokenResponse};
+use crate::http::session_cookies;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::Json;
+use axum_extra::extract::cookie::CookieJar;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/users/token/refresh",
+    tag = "users",
+    request_body(content = RefreshTokenRequest, description = "May be left out with cookie sessions, which send the refresh token as a cookie and need the X-CSRF-Token header"),
+    responses(
+        (status = 200, description = "New access token and rotated refresh token, also set as cookies when cookie sessions are enabled", body = TokenResponse),
+        (status = 401, description = "Unknown, expired, revoked or reused refresh token", body = ErrorResponse),
+        (status = 403, description = "Missing or invalid CSRF token", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn refresh_token(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    payload: Option<Json<RefreshTokenRequest>>,
+) -> Result<(CookieJar, Json<TokenResponse>), AppError> {
+    info!("Refresh access token");
+
+    let refresh_token = match &payload {
+        Some(Json(payload)) => payload.refresh_token.as_str(),
+        None => {
+            let token = session_cookies::refresh_token(&app_state.config, &jar)
+                .ok_or(AppError::Unauthorized)?;
+            session_cookies::verify_csrf(&headers, &jar)?;
+            token
+        }
+    };
+
+    let tokens = app_state.auth_service.refresh(refresh_token).await?;
+
+    let cookies = session_cookies::session_cookies(&app_state.config, &tokens);
+    Ok((cookies, Json(TokenResponse::from_token_pair(tokens))))
+}
@@ -3,16 +3,31 @@ use crate::domain::commands::register_command::RegisterCommand;
 use crate::http::AppState;
 use crate::http::dto::register::RegisterRequest;
 use crate::http::dto::user::{UserData, UserResponse};
-use crate::utils::jwt::generate_token;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::session_cookies::session_cookies;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::{Json};
-use tracing::info;
+use axum_extra::extract::cookie::CookieJar;
+use tracing::{error, info};
 
+#[utoipa::path(
+    post,
+    path = "/users",
+    tag = "users",
+    request_body = RegisterRequest,
+    responses(
+        (status = 201, description = "Registered user, signed in with cookies too when cookie sessions are enabled", body = UserResponse),
+        (status = 409, description = "Username or email already taken", body = ErrorResponse),
+        (status = 422, description = "Invalid registration data", body = ErrorResponse),
+        (status = 429, description = "Too many attempts; see the Retry-After header", body = ErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds to wait before trying again"))),
+    )
+)]
 pub(crate) async fn register(
     State(app_state): State<AppState>,
     Json(payload): Json<RegisterRequest>,
-) -> Result<(StatusCode, Json<UserResponse>), AppError> {
+) -> Result<(StatusCode, CookieJar, Json<UserResponse>), AppError> {
     info!(
         "Registration attempt for email: {}, username: {}, hash of password: {}",
         payload.user.email, payload.user.username, payload.user.password
@@ -22,15 +37,15 @@ pub(crate) async fn register(
 
     let user = app_state.user_service.register_user(command).await?;
 
-    let token = generate_token(app_state.jwt, user.id)?;
+    // The account is usable either way; the user can ask for another email.
+    if let Err(err) = app_state.email_verification_service.send_verification(&user).await {
+        error!("Failed to send verification email to user {}: {err}", user.id);
+    }
 
-    let user = UserData {
-        email: user.email,
-        token,
-        username: user.username,
-        bio: user.bio,
-        image: user.image,
-    };
+    let tokens = app_state.auth_service.create_session(user.id, user.role).await?;
 
-    Ok((StatusCode::CREATED, Json(UserResponse { user })))
+    let cookies = session_cookies(&app_state.config, &tokens);
+    let user = UserData::with_tokens(user, tokens);
+
+    Ok((StatusCode::CREATED, cookies, Json(UserResponse { user })))
 }
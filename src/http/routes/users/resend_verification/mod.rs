@@ -0,0 +1 @@
+pub(crate) mod resend_verification;
\ No newline at end of file
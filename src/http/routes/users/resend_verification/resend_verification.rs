@@ -0,0 +1,34 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use axum::extract::State;
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    post,
+    path = "/users/verify/resend",
+    tag = "users",
+    security(("token" = [])),
+    responses(
+        (status = 202, description = "Verification email sent"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 409, description = "Email is already verified", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn resend_verification(
+    State(app_state): State<AppState>,
+    auth_user: AuthToken,
+) -> Result<StatusCode, AppError> {
+    auth_user.require_session()?;
+    info!(user_id = %auth_user.user_id, "Resend email verification");
+
+    app_state
+        .email_verification_service
+        .resend_verification(auth_user.user_id)
+        .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
@@ -0,0 +1 @@
+pub(crate) mod reset_password;
\ No newline at end of file
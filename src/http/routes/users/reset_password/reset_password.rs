@@ -0,0 +1,29 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::user::ResetPasswordRequest;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+#[utoipa::path(
+    post,
+    path = "/users/password/reset",
+    tag = "users",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 204, description = "Password changed and all sessions signed out"),
+        (status = 422, description = "Invalid or expired token, or invalid password", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn reset_password(
+    State(app_state): State<AppState>,
+    Json(payload): Json<ResetPasswordRequest>,
+) -> Result<StatusCode, AppError> {
+    app_state
+        .password_reset_service
+        .reset_password(&payload.token, &payload.password)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1 @@
+pub(crate) mod revoke_access_token;
\ No newline at end of file
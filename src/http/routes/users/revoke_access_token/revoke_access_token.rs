@@ -0,0 +1,37 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::access_token_id::AccessTokenId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    delete,
+    path = "/user/tokens/{id}",
+    tag = "users",
+    params(("id" = AccessTokenId, Path, description = "Token id")),
+    security(("token" = [])),
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot revoke tokens", body = ErrorResponse),
+        (status = 404, description = "No such token", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn revoke_access_token(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(id): Path<AccessTokenId>,
+) -> Result<StatusCode, AppError> {
+    auth.require_session()?;
+    info!(user_id = %auth.user_id, token_id = %id, "Revoke personal access token");
+
+    state
+        .access_token_service
+        .revoke_token(auth.user_id, id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1 @@
+pub(crate) mod unlink_identity;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::identity_id::IdentityId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use tracing::info;
+
+#[utoipa::path(
+    delete,
+    path = "/user/identities/{id}",
+    tag = "users",
+    params(("id" = IdentityId, Path, description = "Identity id")),
+    security(("token" = [])),
+    responses(
+        (status = 204, description = "Identity unlinked"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 404, description = "No such identity", body = ErrorResponse),
+        (status = 409, description = "It is the only way to sign in; set a password first", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn unlink_identity(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(id): Path<IdentityId>,
+) -> Result<StatusCode, AppError> {
+    auth.require_session()?;
+    info!(user_id = %auth.user_id, identity_id = %id, "Unlink identity");
+
+    state.oidc_service.unlink_identity(auth.user_id, id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -3,21 +3,45 @@ use crate::domain::commands::update_user_command::UpdateUserCommand;
 use crate::http::AppState;
 use crate::http::dto::user::{UpdateUserRequest, UserData, UserResponse};
 use crate::http::extractors::auth_token::AuthToken;
+use crate::http::dto::error::ErrorResponse;
 use axum::extract::State;
 use axum::{Json};
-use tracing::info;
+use tracing::{error, info};
 
+#[utoipa::path(
+    put,
+    path = "/user",
+    tag = "users",
+    request_body = UpdateUserRequest,
+    security(("token" = [])),
+    responses(
+        (status = 200, description = "Updated user", body = UserResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Personal access tokens cannot be used here", body = ErrorResponse),
+        (status = 422, description = "Invalid user data", body = ErrorResponse),
+    )
+)]
 pub(crate) async fn update_user(
     State(app_state): State<AppState>,
     auth_user: AuthToken,
     Json(payload): Json<UpdateUserRequest>,
 ) -> Result<Json<UserResponse>, AppError> {
+    auth_user.require_session()?;
     info!(user_id = %{auth_user.user_id}, payload = ?payload, "Update user with id: {}", auth_user.user_id);
 
+    let email_changed = payload.user.email.is_some();
     let command = UpdateUserCommand::from_request(payload, auth_user.user_id);
 
     let user = app_state.user_service.update_user(command).await?;
 
+    // A new address is unverified until its owner confirms it.
+    if email_changed
+        && !user.is_verified()
+        && let Err(err) = app_state.email_verification_service.send_verification(&user).await
+    {
+        error!("Failed to send verification email to user {}: {err}", user.id);
+    }
+
     let user_date = UserData::new(user, auth_user.raw_token);
 
     Ok(Json(UserResponse { user: user_date }))
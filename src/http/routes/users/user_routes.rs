@@ -1,15 +1,55 @@
 use crate::http::AppState;
-use crate::http::routes::users::get_current_user::get_current_user::get_current_user;
-use crate::http::routes::users::login::login::login;
-use crate::http::routes::users::register::register::register;
-use crate::http::routes::users::update_user::update_user::update_user;
-use axum::routing::{get, post, put};
-use axum::{ Router};
+use crate::http::middleware::login_rate_limit::login_rate_limit;
+use crate::http::routes::users::{
+    begin_two_factor::begin_two_factor,
+    confirm_two_factor::confirm_two_factor,
+    create_access_token::create_access_token,
+    disable_two_factor::disable_two_factor,
+    forgot_password::forgot_password,
+    get_current_user::get_current_user,
+    get_drafts::get_drafts,
+    list_access_tokens::list_access_tokens,
+    list_identities::list_identities,
+    login::login,
+    login_two_factor::login_two_factor,
+    logout::logout,
+    refresh_token::refresh_token,
+    register::register,
+    resend_verification::resend_verification,
+    revoke_access_token::revoke_access_token,
+    reset_password::reset_password,
+    unlink_identity::unlink_identity,
+    update_user::update_user,
+    verify_email::verify_email
+};
+use axum::middleware::from_fn_with_state;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub(crate) fn user_routes() -> Router<AppState> {
-    Router::new()
-        .route("/users/login", post(login))
-        .route("/users", post(register))
-        .route("/user", get(get_current_user))
-        .route("/user", put(update_user))
+pub(crate) fn user_routes(state: &AppState) -> OpenApiRouter<AppState> {
+    let credential_routes = OpenApiRouter::new()
+        .routes(routes!(login::login))
+        .routes(routes!(login_two_factor::login_two_factor))
+        .routes(routes!(register::register))
+        .route_layer(from_fn_with_state(state.clone(), login_rate_limit));
+
+    OpenApiRouter::new()
+        .merge(credential_routes)
+        .routes(routes!(get_current_user::get_current_user))
+        .routes(routes!(update_user::update_user))
+        .routes(routes!(refresh_token::refresh_token))
+        .routes(routes!(logout::logout))
+        .routes(routes!(get_drafts::get_drafts))
+        .routes(routes!(verify_email::verify_email))
+        .routes(routes!(resend_verification::resend_verification))
+        .routes(routes!(forgot_password::forgot_password))
+        .routes(routes!(reset_password::reset_password))
+        .routes(routes!(begin_two_factor::begin_two_factor))
+        .routes(routes!(confirm_two_factor::confirm_two_factor))
+        .routes(routes!(disable_two_factor::disable_two_factor))
+        .routes(routes!(create_access_token::create_access_token))
+        .routes(routes!(list_access_tokens::list_access_tokens))
+        .routes(routes!(revoke_access_token::revoke_access_token))
+        .routes(routes!(list_identities::list_identities))
+        .routes(routes!(unlink_identity::unlink_identity))
 }
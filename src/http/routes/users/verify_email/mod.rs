@@ -0,0 +1 @@
+pub(crate) mod verify_email;
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::error::ErrorResponse;
+use crate::http::dto::user::VerifyEmailRequest;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+#[utoipa::path(
+    post,
+    path = "/users/verify",
+    tag = "users",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 204, description = "Email address verified"),
+        (status = 422, description = "Invalid or expired verification token", body = ErrorResponse),
+    )
+)]
+pub(crate) async fn verify_email(
+    State(app_state): State<AppState>,
+    Json(payload): Json<VerifyEmailRequest>,
+) -> Result<StatusCode, AppError> {
+    app_state
+        .email_verification_service
+        .verify(&payload.token)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
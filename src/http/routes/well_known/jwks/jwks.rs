@@ -0,0 +1,21 @@
+use crate::http::AppState;
+use crate::http::dto::jwks::JwksResponse;
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::Json;
+
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    tag = "keys",
+    responses(
+        (status = 200, description = "Public keys that verify access tokens, including retired ones", body = JwksResponse),
+    )
+)]
+pub(crate) async fn jwks(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Json(JwksResponse::from_jwk_set(state.auth_service.jwks())),
+    )
+}
@@ -0,0 +1 @@
+pub(crate) mod jwks;
\ No newline at end of file
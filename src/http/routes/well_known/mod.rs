@@ -0,0 +1,2 @@
+pub(crate) mod jwks;
+pub(crate) mod well_known_routes;
\ No newline at end of file
@@ -0,0 +1,8 @@
+use crate::http::AppState;
+use crate::http::routes::well_known::jwks::jwks;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub(crate) fn well_known_routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new().routes(routes!(jwks::jwks))
+}
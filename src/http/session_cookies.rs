@@ -0,0 +1,145 @@
+//! Cookie sessions for browser apps; see `SessionCookieConfig`.
+
+use crate::app_config::{AppConfig, CookieSameSite, SessionCookieConfig};
+use crate::app_error::AppError;
+use crate::model::token_pair::TokenPair;
+use crate::utils::opaque_token::random_token;
+use aws_lc_rs::constant_time::verify_slices_are_equal;
+use axum::http::HeaderMap;
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+use time::Duration;
+
+/// HttpOnly access token.
+pub(crate) const SESSION_COOKIE: &str = "session";
+/// HttpOnly refresh token, only sent to the refresh endpoint.
+pub(crate) const REFRESH_COOKIE: &str = "refresh_token";
+/// Readable by the frontend, which echoes it in `CSRF_HEADER`.
+pub(crate) const CSRF_COOKIE: &str = "csrf_token";
+pub(crate) const CSRF_HEADER: &str = "x-csrf-token";
+
+const SESSION_PATH: &str = "/api";
+const REFRESH_PATH: &str = "/api/users/token/refresh";
+const CSRF_PATH: &str = "/";
+
+fn cookie(
+    config: &SessionCookieConfig,
+    name: &'static str,
+    value: String,
+    path: &'static str,
+    max_age: Duration,
+) -> Cookie<'static> {
+    let same_site = match config.same_site {
+        CookieSameSite::Strict => SameSite::Strict,
+        CookieSameSite::Lax => SameSite::Lax,
+        CookieSameSite::None => SameSite::None,
+    };
+
+    Cookie::build((name, value))
+        .path(path)
+        .max_age(max_age)
+        .same_site(same_site)
+        .secure(config.secure)
+        .http_only(name != CSRF_COOKIE)
+        .build()
+}
+
+/// Cookies for a new or refreshed session, with a fresh CSRF token; empty
+/// when cookie sessions are off.
+pub(crate) fn session_cookies(config: &AppConfig, tokens: &TokenPair) -> CookieJar {
+    let cookies = &config.session_cookies;
+    if !cookies.enabled {
+        return CookieJar::new();
+    }
+
+    let access_ttl = Duration::seconds(config.auth.access_token_ttl_secs);
+    let refresh_ttl = Duration::days(config.auth.refresh_token_ttl_days);
+
+    CookieJar::new()
+        .add(cookie(
+            cookies,
+            SESSION_COOKIE,
+            tokens.access_token.clone(),
+            SESSION_PATH,
+            access_ttl,
+        ))
+        .add(cookie(
+            cookies,
+            REFRESH_COOKIE,
+            tokens.refresh_token.clone(),
+            REFRESH_PATH,
+            refresh_ttl,
+        ))
+        .add(cookie(
+            cookies,
+            CSRF_COOKIE,
+            random_token(),
+            CSRF_PATH,
+            refresh_ttl,
+        ))
+}
+
+/// Expires the session cookies; empty when cookie sessions are off.
+pub(crate) fn cleared_session_cookies(config: &AppConfig) -> CookieJar {
+    let cookies = &config.session_cookies;
+    if !cookies.enabled {
+        return CookieJar::new();
+    }
+
+    CookieJar::new()
+        .add(cookie(
+            cookies,
+            SESSION_COOKIE,
+            String::new(),
+            SESSION_PATH,
+            Duration::ZERO,
+        ))
+        .add(cookie(
+            cookies,
+            REFRESH_COOKIE,
+            String::new(),
+            REFRESH_PATH,
+            Duration::ZERO,
+        ))
+        .add(cookie(
+            cookies,
+            CSRF_COOKIE,
+            String::new(),
+            CSRF_PATH,
+            Duration::ZERO,
+        ))
+}
+
+fn cookie_value<'a>(config: &AppConfig, jar: &'a CookieJar, name: &str) -> Option<&'a str> {
+    if !config.session_cookies.enabled {
+        return None;
+    }
+    jar.get(name)
+        .map(Cookie::value)
+        .filter(|value| !value.is_empty())
+}
+
+/// The access token of a cookie session.
+pub(crate) fn session_token<'a>(config: &AppConfig, jar: &'a CookieJar) -> Option<&'a str> {
+    cookie_value(config, jar, SESSION_COOKIE)
+}
+
+/// The refresh token of a cookie session.
+pub(crate) fn refresh_token<'a>(config: &AppConfig, jar: &'a CookieJar) -> Option<&'a str> {
+    cookie_value(config, jar, REFRESH_COOKIE)
+}
+
+/// Double-submit check for unsafe requests authenticated by cookie: the
+/// browser attaches cookies to cross-site requests too, but only a page of
+/// our own origin can read the CSRF cookie to repeat it in the header.
+pub(crate) fn verify_csrf(headers: &HeaderMap, jar: &CookieJar) -> Result<(), AppError> {
+    let cookie = jar.get(CSRF_COOKIE).map(Cookie::value).unwrap_or_default();
+    let header = headers
+        .get(CSRF_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    if cookie.is_empty() || verify_slices_are_equal(cookie.as_bytes(), header.as_bytes()).is_err() {
+        return Err(AppError::CsrfTokenMismatch);
+    }
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Article content that is tracked by revisions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ArticleField {
+    Title,
+    Description,
+    Body,
+}
+
+impl ArticleField {
+    pub const ALL: [ArticleField; 3] = [
+        ArticleField::Title,
+        ArticleField::Description,
+        ArticleField::Body,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArticleField::Title => "title",
+            ArticleField::Description => "description",
+            ArticleField::Body => "body",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ArticleField> {
+        Self::ALL.into_iter().find(|field| field.as_str() == name)
+    }
+}
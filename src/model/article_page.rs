@@ -0,0 +1,47 @@
+use crate::model::cursor::{Cursor, CursorDirection};
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::article_view::ArticleListView;
+
+pub struct ArticlePage {
+    pub articles: Vec<ArticleListView>,
+    pub next_cursor: Option<Cursor>,
+    pub prev_cursor: Option<Cursor>,
+}
+
+impl ArticlePage {
+    /// Builds a page from rows fetched in cursor order with one extra row
+    /// beyond `limit`, which is only used to tell whether more rows follow.
+    pub(crate) fn from_rows(
+        mut rows: Vec<ArticleListView>,
+        limit: Limit,
+        cursor: Option<Cursor>,
+        offset: Option<Offset>,
+    ) -> ArticlePage {
+        let has_more = rows.len() as u64 > limit.value();
+        rows.truncate(limit.value() as usize);
+
+        let direction = cursor.map(|c| c.direction);
+        if direction == Some(CursorDirection::Before) {
+            rows.reverse();
+        }
+
+        let first = rows.first().map(|a| Cursor::before(a.created_at, a.id));
+        let last = rows.last().map(|a| Cursor::after(a.created_at, a.id));
+
+        let (next_cursor, prev_cursor) = match direction {
+            Some(CursorDirection::Before) => (last, first.filter(|_| has_more)),
+            Some(CursorDirection::After) => (last.filter(|_| has_more), first),
+            None => {
+                let skipped = offset.is_some_and(|o| o.value() > 0);
+                (last.filter(|_| has_more), first.filter(|_| skipped))
+            }
+        };
+
+        ArticlePage {
+            articles: rows,
+            next_cursor,
+            prev_cursor,
+        }
+    }
+}
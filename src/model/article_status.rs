@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Where an article is in its lifecycle. Only published articles are shown to
+/// readers other than the author.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum ArticleStatus {
+    Draft,
+    /// Published automatically once `publish_at` has passed.
+    Scheduled,
+    Published,
+    Archived,
+}
+
+impl ArticleStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArticleStatus::Draft => "draft",
+            ArticleStatus::Scheduled => "scheduled",
+            ArticleStatus::Published => "published",
+            ArticleStatus::Archived => "archived",
+        }
+    }
+}
+
+impl From<ArticleStatus> for Value {
+    fn from(status: ArticleStatus) -> Self {
+        Value::String(Some(Box::new(status.as_str().to_string())))
+    }
+}
+
+/// A status together with the publish time it needs, checked to be
+/// consistent: only scheduled articles have a `publish_at`, and it lies in
+/// the future.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Publication {
+    pub status: ArticleStatus,
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+impl Publication {
+    pub const PUBLISHED: Publication = Publication {
+        status: ArticleStatus::Published,
+        publish_at: None,
+    };
+
+    /// Combines the `status` and `publishAt` of a request. A `publishAt` on its
+    /// own schedules the article; `None` means neither was given.
+    pub fn from_request(
+        status: Option<ArticleStatus>,
+        publish_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<Publication>, String> {
+        let status = match (status, publish_at) {
+            (None, None) => return Ok(None),
+            (None, Some(_)) => ArticleStatus::Scheduled,
+            (Some(status), _) => status,
+        };
+
+        match (status, publish_at) {
+            (ArticleStatus::Scheduled, None) => {
+                Err("publishAt is required for scheduled articles".to_string())
+            }
+            (ArticleStatus::Scheduled, Some(publish_at)) if publish_at <= now => {
+                Err("publishAt must be in the future".to_string())
+            }
+            (ArticleStatus::Scheduled, Some(_)) => Ok(Some(Publication { status, publish_at })),
+            (_, Some(_)) => Err("publishAt is only allowed for scheduled articles".to_string()),
+            (_, None) => Ok(Some(Publication {
+                status,
+                publish_at: None,
+            })),
+        }
+    }
+}
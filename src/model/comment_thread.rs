@@ -0,0 +1,41 @@
+use crate::model::persistence::comment_view::CommentView;
+use crate::model::values::comment_id::CommentId;
+use std::collections::HashMap;
+
+pub struct CommentThread {
+    pub comment: CommentView,
+    pub replies: Vec<CommentThread>,
+}
+
+impl CommentThread {
+    /// Nests a flat, newest-first list of an article's comments. Top-level
+    /// comments keep that order; replies read oldest first, like a conversation.
+    pub(crate) fn build(views: Vec<CommentView>) -> Vec<CommentThread> {
+        let mut roots = vec![];
+        let mut children: HashMap<CommentId, Vec<CommentView>> = HashMap::new();
+
+        for view in views {
+            match view.parent_id {
+                Some(parent_id) => children.entry(parent_id).or_default().push(view),
+                None => roots.push(view),
+            }
+        }
+
+        roots
+            .into_iter()
+            .map(|root| Self::attach(root, &mut children))
+            .collect()
+    }
+
+    fn attach(comment: CommentView, children: &mut HashMap<CommentId, Vec<CommentView>>) -> Self {
+        let replies = children
+            .remove(&comment.id)
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+            .map(|reply| Self::attach(reply, children))
+            .collect();
+
+        CommentThread { comment, replies }
+    }
+}
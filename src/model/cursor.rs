@@ -0,0 +1,117 @@
+use crate::model::values::article_id::ArticleId;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorDirection {
+    /// Rows strictly older than the cursor position.
+    After,
+    /// Rows strictly newer than the cursor position.
+    Before,
+}
+
+/// Opaque keyset position over `(created_at, id)`, ordered newest first.
+///
+/// Encoded as URL-safe base64 so clients treat it as a token rather than
+/// something to construct by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cursor {
+    pub(crate) direction: CursorDirection,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) id: ArticleId,
+}
+
+impl Cursor {
+    pub fn after(created_at: DateTime<Utc>, id: ArticleId) -> Self {
+        Cursor {
+            direction: CursorDirection::After,
+            created_at,
+            id,
+        }
+    }
+
+    pub fn before(created_at: DateTime<Utc>, id: ArticleId) -> Self {
+        Cursor {
+            direction: CursorDirection::Before,
+            created_at,
+            id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            CursorDirection::After => 'a',
+            CursorDirection::Before => 'b',
+        };
+
+        URL_SAFE_NO_PAD.encode(format!(
+            "{direction}:{}:{}",
+            self.created_at.timestamp_micros(),
+            self.id
+        ))
+    }
+}
+
+impl TryFrom<String> for Cursor {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let invalid = || "Cursor is invalid".to_string();
+
+        let decoded = URL_SAFE_NO_PAD
+            .decode(value.trim())
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+
+        let mut parts = decoded.splitn(3, ':');
+        let (Some(direction), Some(micros), Some(id)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+
+        let direction = match direction {
+            "a" => CursorDirection::After,
+            "b" => CursorDirection::Before,
+            _ => return Err(invalid()),
+        };
+        let created_at = micros
+            .parse::<i64>()
+            .ok()
+            .and_then(DateTime::from_timestamp_micros)
+            .ok_or_else(invalid)?;
+        let id = Uuid::parse_str(id).map_err(|_| invalid())?;
+
+        Ok(Cursor {
+            direction,
+            created_at,
+            id: ArticleId::from(id),
+        })
+    }
+}
+
+impl From<Cursor> for String {
+    fn from(cursor: Cursor) -> String {
+        cursor.encode()
+    }
+}
+
+impl PartialSchema for Cursor {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .description(Some(
+                "Opaque pagination cursor taken from `nextCursor` or `prevCursor`",
+            ))
+            .into()
+    }
+}
+
+impl ToSchema for Cursor {}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+pub struct PoolStats {
+    pub connections: u32,
+    pub idle_connections: u32,
+}
+
+pub struct MigrationStatus {
+    pub applied: u64,
+    pub pending: u64,
+    pub mismatched: Vec<i64>,
+    pub dirty: Vec<i64>,
+}
+
+impl MigrationStatus {
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending == 0 && self.mismatched.is_empty() && self.dirty.is_empty()
+    }
+}
+
+pub struct DependencyCheck<T> {
+    pub latency: Duration,
+    pub outcome: Result<T, String>,
+}
+
+pub struct ReadinessReport {
+    pub database: DependencyCheck<PoolStats>,
+    pub migrations: DependencyCheck<MigrationStatus>,
+}
+
+impl ReadinessReport {
+    pub fn database_status(&self) -> HealthStatus {
+        match self.database.outcome {
+            Ok(_) => HealthStatus::Up,
+            Err(_) => HealthStatus::Down,
+        }
+    }
+
+    pub fn migrations_status(&self) -> HealthStatus {
+        match &self.migrations.outcome {
+            Ok(status) if status.is_up_to_date() => HealthStatus::Up,
+            _ => HealthStatus::Down,
+        }
+    }
+
+    pub fn status(&self) -> HealthStatus {
+        if self.database_status() == HealthStatus::Up
+            && self.migrations_status() == HealthStatus::Up
+        {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        }
+    }
+}
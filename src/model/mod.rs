@@ -1,6 +1,17 @@
+pub(crate) mod article_field;
+pub(crate) mod article_page;
+pub(crate) mod article_status;
+pub(crate) mod comment_thread;
+pub(crate) mod cursor;
+pub(crate) mod health;
 pub(crate) mod indexed_article_field;
 pub(crate) mod indexed_user_field;
 pub(crate) mod limit;
+pub(crate) mod line_diff;
+pub(crate) mod token_pair;
 pub(crate) mod offset;
 pub(crate) mod persistence;
+pub(crate) mod report;
+pub(crate) mod role;
+pub(crate) mod scope;
 pub(crate) mod values;
@@ -1,3 +1,4 @@
+use crate::model::article_status::ArticleStatus;
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_id::ArticleId;
@@ -17,6 +18,9 @@ pub struct Article {
     pub author_id: UserId,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub status: ArticleStatus,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub hidden_at: Option<DateTime<Utc>>,
 }
 
 impl Article {
@@ -30,6 +34,9 @@ impl Article {
             author_id: row.get("author_id"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
+            status: row.get("status"),
+            publish_at: row.get("publish_at"),
+            hidden_at: row.get("hidden_at"),
         }
     }
 }
@@ -0,0 +1,38 @@
+use crate::model::article_field::ArticleField;
+use crate::model::values::article_body::ArticleBody;
+use crate::model::values::article_description::ArticleDescription;
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct ArticleRevision {
+    pub number: i32,
+    /// Whoever made the change; `None` once their account is gone.
+    pub author: Option<Username>,
+    pub title: ArticleTitle,
+    pub description: ArticleDescription,
+    pub body: ArticleBody,
+    pub changed_fields: Vec<ArticleField>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ArticleRevision {
+    pub fn from_row(row: PgRow) -> Self {
+        let changed_fields: Vec<String> = row.get("changed_fields");
+
+        Self {
+            number: row.get("number"),
+            author: row.get("author_username"),
+            title: row.get("title"),
+            description: row.get("description"),
+            body: row.get("body"),
+            changed_fields: changed_fields
+                .iter()
+                .filter_map(|name| ArticleField::from_name(name))
+                .collect(),
+            created_at: row.get("created_at"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::model::article_status::ArticleStatus;
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_id::ArticleId;
@@ -27,6 +28,8 @@ pub struct ArticleView {
     pub author_image: Option<Image>,
     pub following: bool,
     pub body: ArticleBody,
+    pub status: ArticleStatus,
+    pub publish_at: Option<DateTime<Utc>>,
 }
 
 impl ArticleView {
@@ -47,11 +50,14 @@ impl ArticleView {
             author_image: row.get("author_image"),
             following: row.get("following"),
             body: row.get("body"),
+            status: row.get("status"),
+            publish_at: row.get("publish_at"),
         }
     }
 }
 
 pub struct ArticleListView {
+    pub id: ArticleId,
     pub slug: Slug,
     pub title: ArticleTitle,
     pub description: ArticleDescription,
@@ -64,11 +70,14 @@ pub struct ArticleListView {
     pub author_bio: Option<Bio>,
     pub author_image: Option<Image>,
     pub following: bool,
+    pub status: ArticleStatus,
+    pub publish_at: Option<DateTime<Utc>>,
 }
 
 impl ArticleListView {
     pub fn from_row(row: sqlx::postgres::PgRow) -> ArticleListView {
         ArticleListView {
+            id: row.get("id"),
             slug: row.get("slug"),
             title: row.get("title"),
             description: row.get("description"),
@@ -81,6 +90,24 @@ impl ArticleListView {
             author_bio: row.get("author_bio"),
             author_image: row.get("author_image"),
             following: row.get("following"),
+            status: row.get("status"),
+            publish_at: row.get("publish_at"),
+        }
+    }
+}
+
+pub struct ArticleSearchView {
+    pub article: ArticleListView,
+    pub score: f32,
+    pub snippet: String,
+}
+
+impl ArticleSearchView {
+    pub fn from_row(row: sqlx::postgres::PgRow) -> ArticleSearchView {
+        ArticleSearchView {
+            score: row.get("score"),
+            snippet: row.get("snippet"),
+            article: ArticleListView::from_row(row),
         }
     }
 }
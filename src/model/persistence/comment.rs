@@ -13,6 +13,11 @@ pub struct Comment {
     pub author_id: UserId,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub parent_id: Option<CommentId>,
+    pub depth: i16,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub edited_at: Option<DateTime<Utc>>,
+    pub hidden_at: Option<DateTime<Utc>>,
 }
 
 impl Comment {
@@ -24,6 +29,11 @@ impl Comment {
             author_id: row.get("author_id"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
+            parent_id: row.get("parent_id"),
+            depth: row.get("depth"),
+            deleted_at: row.get("deleted_at"),
+            edited_at: row.get("edited_at"),
+            hidden_at: row.get("hidden_at"),
         }
     }
 }
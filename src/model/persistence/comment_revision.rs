@@ -0,0 +1,20 @@
+use crate::model::values::comment_body::CommentBody;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct CommentRevision {
+    pub body: CommentBody,
+    pub created_at: DateTime<Utc>,
+    pub replaced_at: DateTime<Utc>,
+}
+
+impl CommentRevision {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            body: row.get("body"),
+            created_at: row.get("created_at"),
+            replaced_at: row.get("replaced_at"),
+        }
+    }
+}
@@ -6,8 +6,15 @@ use crate::model::values::username::Username;
 use chrono::{DateTime, Utc};
 use sqlx::Row;
 
+/// Body stored in place of a deleted comment that still has replies.
+pub const DELETED_COMMENT_BODY: &str = "[deleted]";
+
 pub struct CommentView {
     pub id: CommentId,
+    pub parent_id: Option<CommentId>,
+    pub depth: i16,
+    pub deleted: bool,
+    pub edited: bool,
     pub body: CommentBody,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -21,6 +28,10 @@ impl CommentView {
     pub fn from_row(row: sqlx::postgres::PgRow) -> CommentView {
         CommentView {
             id: row.get("id"),
+            parent_id: row.get("parent_id"),
+            depth: row.get("depth"),
+            deleted: row.get("deleted"),
+            edited: row.get("edited"),
             body: row.get("body"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
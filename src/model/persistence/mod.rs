@@ -1,6 +1,15 @@
 pub mod article;
+pub mod article_revision;
 pub mod article_view;
 pub mod comment;
+pub mod comment_revision;
 pub mod comment_view;
+pub mod oidc_login;
+pub mod personal_access_token;
+pub mod report;
+pub mod session;
 pub mod tag;
+pub mod totp_credential;
 pub mod user;
+pub mod user_account;
+pub mod user_identity;
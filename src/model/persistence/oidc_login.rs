@@ -0,0 +1,20 @@
+use crate::model::values::user_id::UserId;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct OidcLogin {
+    pub code_verifier: String,
+    pub nonce: String,
+    /// Set when a signed-in user is linking an identity.
+    pub user_id: Option<UserId>,
+}
+
+impl OidcLogin {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            code_verifier: row.get("code_verifier"),
+            nonce: row.get("nonce"),
+            user_id: row.get("user_id"),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::model::scope::Scope;
+use crate::model::values::access_token_id::AccessTokenId;
+use crate::model::values::user_id::UserId;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct PersonalAccessToken {
+    pub id: AccessTokenId,
+    pub user_id: UserId,
+    pub name: String,
+    pub scopes: Vec<Scope>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl PersonalAccessToken {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            user_id: row.get("user_id"),
+            name: row.get("name"),
+            scopes: row.get("scopes"),
+            created_at: row.get("created_at"),
+            expires_at: row.get("expires_at"),
+            last_used_at: row.get("last_used_at"),
+        }
+    }
+}
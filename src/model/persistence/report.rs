@@ -0,0 +1,58 @@
+use crate::model::report::ReportReason;
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::comment_body::CommentBody;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::report_details::ReportDetails;
+use crate::model::values::slug::Slug;
+use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct Report {
+    pub reason: ReportReason,
+    pub details: Option<ReportDetails>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Report {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            reason: row.get("reason"),
+            details: row.get("details"),
+            created_at: row.get("created_at"),
+        }
+    }
+}
+
+/// An article or comment with open reports, as listed in the moderation queue.
+pub struct ReportedContent {
+    pub slug: Slug,
+    pub title: ArticleTitle,
+    /// Set when the reports are about a comment on the article.
+    pub comment_id: Option<CommentId>,
+    pub comment_body: Option<CommentBody>,
+    pub author: Username,
+    pub reports_count: i64,
+    pub reasons: Vec<ReportReason>,
+    pub first_reported_at: DateTime<Utc>,
+    pub last_reported_at: DateTime<Utc>,
+    pub hidden: bool,
+}
+
+impl ReportedContent {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            slug: row.get("slug"),
+            title: row.get("title"),
+            comment_id: row.get("comment_id"),
+            comment_body: row.get("comment_body"),
+            author: row.get("author_username"),
+            reports_count: row.get("reports_count"),
+            reasons: row.get("reasons"),
+            first_reported_at: row.get("first_reported_at"),
+            last_reported_at: row.get("last_reported_at"),
+            hidden: row.get("hidden"),
+        }
+    }
+}
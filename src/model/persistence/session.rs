@@ -0,0 +1,21 @@
+use crate::model::values::session_id::SessionId;
+use crate::model::values::user_id::UserId;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct Session {
+    pub id: SessionId,
+    pub user_id: UserId,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Session {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            user_id: row.get("user_id"),
+            expires_at: row.get("expires_at"),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct TotpCredential {
+    pub secret_encrypted: Vec<u8>,
+    /// Unset while enrollment waits for the first code.
+    pub confirmed_at: Option<DateTime<Utc>>,
+}
+
+impl TotpCredential {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            secret_encrypted: row.get("secret_encrypted"),
+            confirmed_at: row.get("confirmed_at"),
+        }
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
@@ -1,19 +1,28 @@
+use crate::model::role::Role;
 use crate::model::values::bio::Bio;
 use crate::model::values::email::Email;
 use crate::model::values::image::Image;
 use crate::model::values::password_hash::PasswordHash;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
 use sqlx::Row;
 use sqlx::postgres::PgRow;
 
 pub struct User {
     pub id: UserId,
     pub email: Email,
-    pub password_hash: PasswordHash,
+    /// Unset for accounts created through an identity provider until the
+    /// user chooses a password.
+    pub password_hash: Option<PasswordHash>,
     pub username: Username,
     pub bio: Option<Bio>,
     pub image: Option<Image>,
+    pub role: Role,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub password_reset_required: bool,
+    /// Unset until the user follows the link mailed to their current address.
+    pub email_verified_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -25,6 +34,14 @@ impl User {
             password_hash: row.get("password_hash"),
             bio: row.get("bio"),
             image: row.get("image"),
+            role: row.get("role"),
+            suspended_at: row.get("suspended_at"),
+            password_reset_required: row.get("password_reset_required"),
+            email_verified_at: row.get("email_verified_at"),
         }
     }
+
+    pub fn is_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
 }
@@ -0,0 +1,46 @@
+use crate::model::role::Role;
+use crate::model::values::bio::Bio;
+use crate::model::values::email::Email;
+use crate::model::values::image::Image;
+use crate::model::values::user_id::UserId;
+use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+/// A user as admins see them: account state and how active they are.
+pub struct UserAccount {
+    pub id: UserId,
+    pub username: Username,
+    pub email: Email,
+    pub bio: Option<Bio>,
+    pub image: Option<Image>,
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub suspension_reason: Option<String>,
+    pub password_reset_required: bool,
+    pub articles_count: i64,
+    pub comments_count: i64,
+    pub followers_count: i64,
+}
+
+impl UserAccount {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            username: row.get("username"),
+            email: row.get("email"),
+            bio: row.get("bio"),
+            image: row.get("image"),
+            role: row.get("role"),
+            created_at: row.get("created_at"),
+            suspended_at: row.get("suspended_at"),
+            suspension_reason: row.get("suspension_reason"),
+            password_reset_required: row.get("password_reset_required"),
+            articles_count: row.get("articles_count"),
+            comments_count: row.get("comments_count"),
+            followers_count: row.get("followers_count"),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::model::values::identity_id::IdentityId;
+use crate::model::values::user_id::UserId;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct UserIdentity {
+    pub id: IdentityId,
+    pub user_id: UserId,
+    pub provider: String,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserIdentity {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            user_id: row.get("user_id"),
+            provider: row.get("provider"),
+            email: row.get("email"),
+            created_at: row.get("created_at"),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Why a reader flagged an article or comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Misinformation,
+    Other,
+}
+
+impl ReportReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportReason::Spam => "spam",
+            ReportReason::Harassment => "harassment",
+            ReportReason::HateSpeech => "hate_speech",
+            ReportReason::Misinformation => "misinformation",
+            ReportReason::Other => "other",
+        }
+    }
+}
+
+impl From<ReportReason> for Value {
+    fn from(reason: ReportReason) -> Self {
+        Value::String(Some(Box::new(reason.as_str().to_string())))
+    }
+}
+
+/// How a moderator settles the open reports on a piece of content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum ReportAction {
+    /// Leaves the content as it is.
+    Dismiss,
+    /// Hides the content from everyone but moderators.
+    Hide,
+    /// Hides the content and suspends its author; admins only.
+    SuspendAuthor,
+}
+
+impl ReportAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportAction::Dismiss => "dismiss",
+            ReportAction::Hide => "hide",
+            ReportAction::SuspendAuthor => "suspend_author",
+        }
+    }
+
+    pub fn hides_content(&self) -> bool {
+        matches!(self, ReportAction::Hide | ReportAction::SuspendAuthor)
+    }
+}
+
+impl From<ReportAction> for Value {
+    fn from(action: ReportAction) -> Self {
+        Value::String(Some(Box::new(action.as_str().to_string())))
+    }
+}
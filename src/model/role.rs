@@ -0,0 +1,46 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// What a user is allowed to do beyond managing their own content. Roles are
+/// ordered, and each one includes the permissions of the roles before it.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    sqlx::Type,
+)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    User,
+    Moderator,
+    Admin,
+}
+
+impl Role {
+    pub const ALL: [Role; 3] = [Role::User, Role::Moderator, Role::Admin];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::User => "user",
+            Role::Moderator => "moderator",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+impl From<Role> for Value {
+    fn from(role: Role) -> Self {
+        Value::String(Some(Box::new(role.as_str().to_string())))
+    }
+}
@@ -0,0 +1,33 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// What a personal access token may change. Any token can read what its user
+/// can; sessions from a login are not limited by scopes at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[sqlx(type_name = "text")]
+pub enum Scope {
+    /// Create, edit and delete the user's articles.
+    #[serde(rename = "articles:write")]
+    #[sqlx(rename = "articles:write")]
+    ArticlesWrite,
+    /// Post, edit and delete the user's comments.
+    #[serde(rename = "comments:write")]
+    #[sqlx(rename = "comments:write")]
+    CommentsWrite,
+}
+
+impl Scope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::ArticlesWrite => "articles:write",
+            Scope::CommentsWrite => "comments:write",
+        }
+    }
+}
+
+impl From<Scope> for Value {
+    fn from(scope: Scope) -> Self {
+        Value::String(Some(Box::new(scope.as_str().to_string())))
+    }
+}
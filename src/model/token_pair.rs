@@ -0,0 +1,4 @@
+pub struct TokenPair {
+    pub access_token: String,
+    pub refresh_token: String,
+}
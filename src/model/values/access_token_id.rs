@@ -0,0 +1,40 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+pub struct AccessTokenId(Uuid);
+
+impl AccessTokenId {
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for AccessTokenId {
+    fn from(id: Uuid) -> Self {
+        AccessTokenId(id)
+    }
+}
+
+impl From<AccessTokenId> for Uuid {
+    fn from(id: AccessTokenId) -> Uuid {
+        id.0
+    }
+}
+
+impl Display for AccessTokenId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<AccessTokenId> for Value {
+    fn from(id: AccessTokenId) -> Self {
+        Value::Uuid(Some(Box::new(id.value())))
+    }
+}
@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[sqlx(transparent)]
@@ -62,3 +65,14 @@ impl From<ArticleBody> for Value {
         Value::String(Some(Box::new(b.value().to_string())))
     }
 }
+
+impl PartialSchema for ArticleBody {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .min_length(Some(1))
+            .into()
+    }
+}
+
+impl ToSchema for ArticleBody {}
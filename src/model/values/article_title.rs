@@ -3,6 +3,11 @@ use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+const MAX_TITLE_LENGTH: usize = 255;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[sqlx(transparent)]
@@ -25,8 +30,10 @@ impl TryFrom<String> for ArticleTitle {
             return Err("Article title cannot be blank".to_string());
         }
 
-        if trimmed.len() > 255 {
-            return Err("Article title cannot be longer than 255 characters".to_string());
+        if trimmed.len() > MAX_TITLE_LENGTH {
+            return Err(format!(
+                "Article title cannot be longer than {MAX_TITLE_LENGTH} characters"
+            ));
         }
 
         Ok(ArticleTitle(trimmed.to_string()))
@@ -66,3 +73,15 @@ impl From<ArticleTitle> for Value {
         Value::String(Some(Box::new(t.value().to_string())))
     }
 }
+
+impl PartialSchema for ArticleTitle {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .min_length(Some(1))
+            .max_length(Some(MAX_TITLE_LENGTH))
+            .into()
+    }
+}
+
+impl ToSchema for ArticleTitle {}
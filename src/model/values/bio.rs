@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
 
 const MAX_BIO_LENGTH: usize = 1000;
 
@@ -66,3 +69,14 @@ impl From<Bio> for Value {
         Value::String(Some(Box::new(b.value().to_string())))
     }
 }
+
+impl PartialSchema for Bio {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .max_length(Some(MAX_BIO_LENGTH))
+            .into()
+    }
+}
+
+impl ToSchema for Bio {}
@@ -4,6 +4,9 @@ use sqlx::Type;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use validator::ValidateEmail;
+use utoipa::openapi::schema::{self, KnownFormat, ObjectBuilder, Schema, SchemaFormat};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
 #[sqlx(transparent)]
@@ -61,3 +64,14 @@ impl From<Email> for Value {
         Value::String(Some(Box::new(e.value().to_string())))
     }
 }
+
+impl PartialSchema for Email {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Email)))
+            .into()
+    }
+}
+
+impl ToSchema for Email {}
@@ -0,0 +1,40 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+pub struct IdentityId(Uuid);
+
+impl IdentityId {
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for IdentityId {
+    fn from(id: Uuid) -> Self {
+        IdentityId(id)
+    }
+}
+
+impl From<IdentityId> for Uuid {
+    fn from(id: IdentityId) -> Uuid {
+        id.0
+    }
+}
+
+impl Display for IdentityId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<IdentityId> for Value {
+    fn from(id: IdentityId) -> Self {
+        Value::Uuid(Some(Box::new(id.value())))
+    }
+}
@@ -3,6 +3,11 @@ use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+const MAX_IMAGE_URL_LENGTH: usize = 2048;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
 #[sqlx(transparent)]
@@ -29,8 +34,10 @@ impl TryFrom<String> for Image {
             return Err("Image URL must start with http:// or https://".to_string());
         }
 
-        if trimmed.len() > 2048 {
-            return Err("Image URL cannot be longer than 2048 characters".to_string());
+        if trimmed.len() > MAX_IMAGE_URL_LENGTH {
+            return Err(format!(
+                "Image URL cannot be longer than {MAX_IMAGE_URL_LENGTH} characters"
+            ));
         }
 
         Ok(Image(trimmed.to_string()))
@@ -70,3 +77,16 @@ impl From<Image> for Value {
         Value::String(Some(Box::new(i.value().to_string())))
     }
 }
+
+impl PartialSchema for Image {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .min_length(Some(1))
+            .max_length(Some(MAX_IMAGE_URL_LENGTH))
+            .pattern(Some("^https?://"))
+            .into()
+    }
+}
+
+impl ToSchema for Image {}
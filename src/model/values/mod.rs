@@ -1,3 +1,4 @@
+pub mod access_token_id;
 pub mod article_body;
 pub mod article_description;
 pub mod article_id;
@@ -6,9 +7,13 @@ pub mod bio;
 pub mod comment_body;
 pub mod comment_id;
 pub mod email;
+pub mod identity_id;
 pub mod image;
 pub mod password;
 pub mod password_hash;
+pub mod report_details;
+pub mod search_terms;
+pub mod session_id;
 pub mod slug;
 pub mod tag_id;
 pub mod tag_name;
@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::ops::Deref;
+use utoipa::openapi::schema::{self, KnownFormat, ObjectBuilder, Schema, SchemaFormat};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
 
 const MIN_PASSWORD_LENGTH: usize = 8;
 const MAX_PASSWORD_LENGTH: usize = 128;
@@ -75,3 +78,16 @@ impl Deref for Password {
         &self.0
     }
 }
+
+impl PartialSchema for Password {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Password)))
+            .min_length(Some(MIN_PASSWORD_LENGTH))
+            .max_length(Some(MAX_PASSWORD_LENGTH))
+            .into()
+    }
+}
+
+impl ToSchema for Password {}
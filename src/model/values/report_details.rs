@@ -0,0 +1,82 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+const MAX_REPORT_DETAILS_LENGTH: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(transparent)]
+#[serde(try_from = "String", into = "String")]
+pub struct ReportDetails(String);
+
+impl ReportDetails {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for ReportDetails {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        if trimmed.len() > MAX_REPORT_DETAILS_LENGTH {
+            return Err(format!(
+                "Report details cannot be longer than {MAX_REPORT_DETAILS_LENGTH} characters"
+            ));
+        }
+
+        Ok(ReportDetails(trimmed.to_string()))
+    }
+}
+
+impl TryFrom<&str> for ReportDetails {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.to_string().try_into()
+    }
+}
+
+impl From<ReportDetails> for String {
+    fn from(details: ReportDetails) -> String {
+        details.0
+    }
+}
+
+impl Display for ReportDetails {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for ReportDetails {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<ReportDetails> for Value {
+    fn from(d: ReportDetails) -> Self {
+        Value::String(Some(Box::new(d.value().to_string())))
+    }
+}
+
+impl PartialSchema for ReportDetails {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .max_length(Some(MAX_REPORT_DETAILS_LENGTH))
+            .into()
+    }
+}
+
+impl ToSchema for ReportDetails {}
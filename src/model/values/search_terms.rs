@@ -0,0 +1,79 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+const MAX_SEARCH_TERMS_LENGTH: usize = 256;
+
+/// Free-text search input, parsed by Postgres with `websearch_to_tsquery`,
+/// so quoted phrases, `or` and `-exclusions` are supported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SearchTerms(String);
+
+impl SearchTerms {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for SearchTerms {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Err("Search query cannot be blank".to_string());
+        }
+
+        if trimmed.chars().count() > MAX_SEARCH_TERMS_LENGTH {
+            return Err(format!(
+                "Search query cannot be longer than {MAX_SEARCH_TERMS_LENGTH} characters"
+            ));
+        }
+
+        Ok(SearchTerms(trimmed.to_string()))
+    }
+}
+
+impl From<SearchTerms> for String {
+    fn from(terms: SearchTerms) -> String {
+        terms.0
+    }
+}
+
+impl Display for SearchTerms {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for SearchTerms {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<&SearchTerms> for Value {
+    fn from(t: &SearchTerms) -> Self {
+        Value::String(Some(Box::new(t.value().to_string())))
+    }
+}
+
+impl PartialSchema for SearchTerms {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .min_length(Some(1))
+            .max_length(Some(MAX_SEARCH_TERMS_LENGTH))
+            .into()
+    }
+}
+
+impl ToSchema for SearchTerms {}
@@ -0,0 +1,49 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[sqlx(transparent)]
+pub struct SessionId(Uuid);
+
+impl SessionId {
+    pub fn new() -> Self {
+        SessionId(Uuid::new_v4())
+    }
+
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for SessionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for SessionId {
+    fn from(id: Uuid) -> Self {
+        SessionId(id)
+    }
+}
+
+impl From<SessionId> for Uuid {
+    fn from(id: SessionId) -> Uuid {
+        id.0
+    }
+}
+
+impl Display for SessionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<SessionId> for Value {
+    fn from(id: SessionId) -> Self {
+        Value::Uuid(Some(Box::new(id.value())))
+    }
+}
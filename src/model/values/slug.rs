@@ -1,39 +1,131 @@
+use deunicode::deunicode_char;
+use rand::Rng;
 use sea_query::Value;
 use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use tryphon::ConfigValueDecoder;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+/// Longest slug accepted anywhere, matching the `articles.slug` column.
+const MAX_SLUG_LENGTH: usize = 255;
+
+/// What a title with nothing to transliterate, such as one made only of emoji
+/// or punctuation, turns into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ConfigValueDecoder)]
+pub enum SlugFallback {
+    /// Eight random hex digits.
+    RandomId,
+    /// The word `untitled`, made unique like any other slug.
+    Untitled,
+}
+
+/// How titles are turned into slugs.
+#[derive(Debug, Clone)]
+pub struct SlugOptions {
+    /// Generated slugs are cut at the last word boundary that fits; a single
+    /// longer word is cut mid-word.
+    pub max_length: usize,
+    pub fallback: SlugFallback,
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
 #[sqlx(transparent)]
 #[serde(try_from = "String", into = "String")]
 pub struct Slug(String);
 
+/// Folds a title to lowercase ASCII: letters and digits are transliterated
+/// (`Tiếng Việt` → `tieng viet`, `Привет` → `privet`), diacritics and
+/// apostrophes are dropped, and everything else becomes a word break.
+fn fold_to_ascii(title: &str) -> String {
+    let mut folded = String::with_capacity(title.len());
+
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            folded.push(c.to_ascii_lowercase());
+        } else if c == '\'' || c == '\u{2019}' {
+            continue;
+        } else {
+            match deunicode_char(c) {
+                Some(ascii) if c.is_alphanumeric() => folded.push_str(&ascii.to_ascii_lowercase()),
+                // Combining marks, as in decomposed accented letters.
+                Some("") => {}
+                _ => folded.push(' '),
+            }
+        }
+    }
+
+    folded
+}
+
+/// Joins the ASCII words of `text` with dashes, stopping before the first word
+/// that would not fit in `max_length`.
+fn join_words(text: &str, max_length: usize) -> String {
+    let mut slug = String::new();
+
+    for word in text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if slug.is_empty() && word.len() > max_length {
+            slug.push_str(&word[..max_length]);
+            break;
+        }
+        if !slug.is_empty() && slug.len() + 1 + word.len() > max_length {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(word);
+    }
+
+    slug
+}
+
 impl Slug {
-    pub fn from_title(title: &str) -> Self {
-        let slug = title
-            .to_lowercase()
-            .chars()
-            .map(|c| {
-                if c.is_alphanumeric() {
-                    c
-                } else if c.is_whitespace() || c == '-' {
-                    '-'
-                } else {
-                    ' '
-                }
-            })
-            .collect::<String>()
-            .split_whitespace()
-            .collect::<Vec<&str>>()
-            .join("-");
-
-        Slug(slug)
+    pub fn from_title(title: &str, options: &SlugOptions) -> Self {
+        let max_length = options.max_length.clamp(1, MAX_SLUG_LENGTH);
+        let slug = join_words(&fold_to_ascii(title), max_length);
+
+        if !slug.is_empty() {
+            return Slug(slug);
+        }
+
+        let fallback = match options.fallback {
+            SlugFallback::RandomId => format!("{:08x}", rand::rng().random::<u32>()),
+            SlugFallback::Untitled => "untitled".to_string(),
+        };
+
+        Slug(join_words(&fallback, max_length))
     }
 
     pub fn value(&self) -> &str {
         &self.0
     }
+
+    /// `{self}-{suffix}`, cutting the base short where needed to stay within
+    /// `max_length`. When there is no room for any of the base, the suffix is
+    /// used on its own, cut to `max_length` too.
+    pub fn with_suffix(&self, suffix: &str, max_length: usize) -> Self {
+        let max_length = max_length.clamp(1, MAX_SLUG_LENGTH);
+        let mut end = self
+            .0
+            .len()
+            .min(max_length.saturating_sub(suffix.len() + 1));
+        while !self.0.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let base = self.0[..end].trim_end_matches('-');
+        if base.is_empty() {
+            return Slug(suffix[..suffix.len().min(max_length)].to_string());
+        }
+        Slug(format!("{base}-{suffix}"))
+    }
 }
 
 impl TryFrom<String> for Slug {
@@ -46,8 +138,10 @@ impl TryFrom<String> for Slug {
             return Err("Slug cannot be blank".to_string());
         }
 
-        if trimmed.len() > 255 {
-            return Err("Slug cannot be longer than 255 characters".to_string());
+        if trimmed.len() > MAX_SLUG_LENGTH {
+            return Err(format!(
+                "Slug cannot be longer than {MAX_SLUG_LENGTH} characters"
+            ));
         }
 
         Ok(Slug(trimmed.to_string()))
@@ -93,3 +187,141 @@ impl From<&Slug> for Value {
         Value::String(Some(Box::new(s.value().to_string())))
     }
 }
+
+impl PartialSchema for Slug {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .min_length(Some(1))
+            .max_length(Some(MAX_SLUG_LENGTH))
+            .into()
+    }
+}
+
+impl ToSchema for Slug {}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::values::slug::{Slug, SlugFallback, SlugOptions};
+    use proptest::prelude::*;
+
+    fn options(max_length: usize) -> SlugOptions {
+        SlugOptions {
+            max_length,
+            fallback: SlugFallback::Untitled,
+        }
+    }
+
+    fn slug(title: &str) -> String {
+        Slug::from_title(title, &options(100)).value().to_string()
+    }
+
+    fn is_well_formed(slug: &str) -> bool {
+        !slug.is_empty()
+            && slug
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && !slug.starts_with('-')
+            && !slug.ends_with('-')
+            && !slug.contains("--")
+    }
+
+    #[test]
+    fn test_transliterates_non_latin_scripts() {
+        assert_eq!(slug("Привет мир"), "privet-mir");
+        assert_eq!(slug("Ελληνικά"), "ellenika");
+        assert_eq!(slug("中文标题"), "zhong-wen-biao-ti");
+    }
+
+    #[test]
+    fn test_strips_diacritics_and_apostrophes() {
+        assert_eq!(slug("Tiếng Việt"), "tieng-viet");
+        assert_eq!(slug("Cre\u{301}me bru\u{302}le\u{301}e"), "creme-brulee");
+        assert_eq!(slug("Don\u{2019}t Panic, it's fine"), "dont-panic-its-fine");
+    }
+
+    #[test]
+    fn test_title_without_words_uses_fallback() {
+        assert_eq!(slug("🚀🔥 !!! ???"), "untitled");
+
+        let random = Slug::from_title(
+            "🚀🔥",
+            &SlugOptions {
+                max_length: 100,
+                fallback: SlugFallback::RandomId,
+            },
+        );
+        assert_eq!(random.len(), 8);
+        assert!(random.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_cuts_at_word_boundary() {
+        let slug = Slug::from_title("The quick brown fox", &options(12));
+        assert_eq!(slug.value(), "the-quick");
+
+        let slug = Slug::from_title("Supercalifragilistic", &options(5));
+        assert_eq!(slug.value(), "super");
+    }
+
+    proptest! {
+        #[test]
+        fn prop_slug_is_well_formed(title in any::<String>(), max_length in 1usize..300) {
+            let slug = Slug::from_title(&title, &options(max_length));
+
+            prop_assert!(is_well_formed(&slug), "{:?} -> {:?}", title, slug);
+            prop_assert!(slug.len() <= max_length.min(255));
+            prop_assert_eq!(Slug::try_from(slug.value()), Ok(slug.clone()));
+        }
+
+        #[test]
+        fn prop_slug_is_idempotent(title in any::<String>()) {
+            let slug = Slug::from_title(&title, &options(100));
+
+            prop_assert_eq!(Slug::from_title(&slug, &options(100)), slug);
+        }
+
+        #[test]
+        fn prop_ascii_words_are_joined_with_dashes(words in prop::collection::vec("[a-zA-Z0-9]{1,10}", 1..8)) {
+            let slug = Slug::from_title(&words.join(" - "), &options(255));
+
+            prop_assert_eq!(slug.value(), words.join("-").to_lowercase());
+        }
+
+        #[test]
+        fn prop_long_titles_are_cut_at_word_boundary(
+            words in prop::collection::vec("[a-z]{1,10}", 1..20),
+            max_length in 10usize..60,
+        ) {
+            let full = words.join("-");
+            let slug = Slug::from_title(&words.join(" "), &options(max_length));
+
+            if full.len() <= max_length {
+                prop_assert_eq!(slug.value(), full);
+            } else {
+                let prefix = format!("{}-", slug.value());
+                prop_assert!(full.starts_with(&prefix));
+                prop_assert!(slug.len() <= max_length);
+            }
+        }
+
+        #[test]
+        fn prop_suffixed_slugs_respect_max_length(title in any::<String>(), max_length in 1usize..300) {
+            let options = options(max_length);
+            let slug = Slug::from_title(&title, &options).with_suffix("0a1f", options.max_length);
+
+            prop_assert!(is_well_formed(&slug), "{:?} -> {:?}", title, slug);
+            prop_assert!(slug.len() <= max_length.min(255));
+            if max_length >= 4 {
+                prop_assert!(slug.ends_with("0a1f"));
+            }
+        }
+
+        #[test]
+        fn prop_combining_accents_are_stripped(word in "[a-z]{1,20}") {
+            let accented: String = word.chars().flat_map(|c| [c, '\u{301}']).collect();
+
+            prop_assert_eq!(slug(&accented), word);
+        }
+    }
+}
@@ -3,6 +3,11 @@ use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+const MAX_TAG_NAME_LENGTH: usize = 255;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
 #[sqlx(transparent)]
@@ -25,8 +30,10 @@ impl TryFrom<String> for TagName {
             return Err("Tag name cannot be blank".to_string());
         }
 
-        if trimmed.len() > 255 {
-            return Err("Tag name cannot be longer than 255 characters".to_string());
+        if trimmed.len() > MAX_TAG_NAME_LENGTH {
+            return Err(format!(
+                "Tag name cannot be longer than {MAX_TAG_NAME_LENGTH} characters"
+            ));
         }
 
         Ok(TagName(trimmed.to_string()))
@@ -72,3 +79,15 @@ impl From<&TagName> for Value {
         Value::String(Some(Box::new(t.value().to_string())))
     }
 }
+
+impl PartialSchema for TagName {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .min_length(Some(1))
+            .max_length(Some(MAX_TAG_NAME_LENGTH))
+            .into()
+    }
+}
+
+impl ToSchema for TagName {}
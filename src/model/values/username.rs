@@ -3,6 +3,12 @@ use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use utoipa::openapi::schema::{self, ObjectBuilder, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+const MIN_USERNAME_LENGTH: usize = 2;
+const MAX_USERNAME_LENGTH: usize = 50;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
 #[sqlx(transparent)]
@@ -25,12 +31,16 @@ impl TryFrom<String> for Username {
             return Err("Username cannot be blank".to_string());
         }
 
-        if trimmed.len() < 2 {
-            return Err("Username must be at least 2 characters long".to_string());
+        if trimmed.len() < MIN_USERNAME_LENGTH {
+            return Err(format!(
+                "Username must be at least {MIN_USERNAME_LENGTH} characters long"
+            ));
         }
 
-        if trimmed.len() > 50 {
-            return Err("Username cannot be longer than 50 characters".to_string());
+        if trimmed.len() > MAX_USERNAME_LENGTH {
+            return Err(format!(
+                "Username cannot be longer than {MAX_USERNAME_LENGTH} characters"
+            ));
         }
 
         Ok(Username(trimmed.to_string()))
@@ -76,3 +86,15 @@ impl From<&Username> for Value {
         Value::String(Some(Box::new(u.value().to_string())))
     }
 }
+
+impl PartialSchema for Username {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(schema::Type::String)
+            .min_length(Some(MIN_USERNAME_LENGTH))
+            .max_length(Some(MAX_USERNAME_LENGTH))
+            .into()
+    }
+}
+
+impl ToSchema for Username {}
@@ -0,0 +1,162 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::persistence::personal_access_token::PersonalAccessToken;
+use crate::model::values::access_token_id::AccessTokenId;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_access_token_params::InsertAccessTokenParams;
+use crate::persistence::schema::PersonalAccessTokens;
+use anyhow::Result;
+use sea_query::{
+    ArrayType, Expr, Order, PostgresQueryBuilder, Query, SimpleExpr, UpdateStatement, Value,
+};
+use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+
+/// Neither revoked nor expired.
+fn is_active() -> SimpleExpr {
+    Expr::col(PersonalAccessTokens::RevokedAt).is_null().and(
+        Expr::col(PersonalAccessTokens::ExpiresAt)
+            .is_null()
+            .or(Expr::col(PersonalAccessTokens::ExpiresAt).gt(Expr::current_timestamp())),
+    )
+}
+
+/// Revokes every token of `user_id` that is still usable.
+pub(crate) fn revoke_all_access_tokens_statement(user_id: UserId) -> UpdateStatement {
+    Query::update()
+        .table(PersonalAccessTokens::Table)
+        .value(PersonalAccessTokens::RevokedAt, Expr::current_timestamp())
+        .and_where(Expr::col(PersonalAccessTokens::UserId).eq(user_id))
+        .and_where(Expr::col(PersonalAccessTokens::RevokedAt).is_null())
+        .to_owned()
+}
+
+#[derive(Clone)]
+pub struct AccessTokenRepository {
+    database: Database,
+}
+
+impl AccessTokenRepository {
+    pub fn new(database: Database) -> Self {
+        AccessTokenRepository { database }
+    }
+
+    pub async fn insert_token(
+        &self,
+        params: InsertAccessTokenParams,
+    ) -> Result<PersonalAccessToken, AppError> {
+        let scopes = Value::Array(
+            ArrayType::String,
+            Some(Box::new(
+                params.scopes.into_iter().map(Value::from).collect(),
+            )),
+        );
+
+        let (sql, values) = Query::insert()
+            .into_table(PersonalAccessTokens::Table)
+            .columns([
+                PersonalAccessTokens::UserId,
+                PersonalAccessTokens::Name,
+                PersonalAccessTokens::TokenHash,
+                PersonalAccessTokens::Scopes,
+                PersonalAccessTokens::ExpiresAt,
+            ])
+            .values_panic([
+                params.user_id.into(),
+                params.name.into(),
+                params.token_hash.into(),
+                scopes.into(),
+                params.expires_at.into(),
+            ])
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?;
+
+        Ok(PersonalAccessToken::from_row(row))
+    }
+
+    /// Tokens of the user that were not revoked, newest first. Expired ones
+    /// are included so users can see why a script stopped working.
+    pub async fn list_tokens(&self, user_id: UserId) -> Result<Vec<PersonalAccessToken>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                PersonalAccessTokens::Id,
+                PersonalAccessTokens::UserId,
+                PersonalAccessTokens::Name,
+                PersonalAccessTokens::Scopes,
+                PersonalAccessTokens::CreatedAt,
+                PersonalAccessTokens::ExpiresAt,
+                PersonalAccessTokens::LastUsedAt,
+            ])
+            .from(PersonalAccessTokens::Table)
+            .and_where(Expr::col(PersonalAccessTokens::UserId).eq(user_id))
+            .and_where(Expr::col(PersonalAccessTokens::RevokedAt).is_null())
+            .order_by(PersonalAccessTokens::CreatedAt, Order::Desc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(PersonalAccessToken::from_row)
+            .collect())
+    }
+
+    /// Returns `false` if the user has no such token.
+    pub async fn revoke_token(&self, user_id: UserId, id: AccessTokenId) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(PersonalAccessTokens::Table)
+            .value(PersonalAccessTokens::RevokedAt, Expr::current_timestamp())
+            .and_where(Expr::col(PersonalAccessTokens::Id).eq(id))
+            .and_where(Expr::col(PersonalAccessTokens::UserId).eq(user_id))
+            .and_where(Expr::col(PersonalAccessTokens::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Owner of an active token, without recording a use.
+    pub async fn find_user_id(&self, token_hash: &str) -> Result<Option<UserId>, AppError> {
+        let (sql, values) = Query::select()
+            .column(PersonalAccessTokens::UserId)
+            .from(PersonalAccessTokens::Table)
+            .and_where(Expr::col(PersonalAccessTokens::TokenHash).eq(token_hash))
+            .and_where(is_active())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(|row| row.get("user_id")))
+    }
+
+    /// Looks up an active token and records that it was used.
+    pub async fn use_token(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalAccessToken>, AppError> {
+        let (sql, values) = Query::update()
+            .table(PersonalAccessTokens::Table)
+            .value(PersonalAccessTokens::LastUsedAt, Expr::current_timestamp())
+            .and_where(Expr::col(PersonalAccessTokens::TokenHash).eq(token_hash))
+            .and_where(is_active())
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(PersonalAccessToken::from_row))
+    }
+}
@@ -1,24 +1,71 @@
 use crate::app_error::AppError;
+use crate::domain::policy::Permission;
 use crate::database::Database;
+use crate::model::article_field::ArticleField;
+use crate::model::article_page::ArticlePage;
+use crate::model::article_status::ArticleStatus;
+use crate::model::cursor::{Cursor, CursorDirection};
 use crate::model::indexed_article_field::IndexedArticleField;
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
 use crate::model::persistence::article::Article;
-use crate::model::persistence::article_view::{ArticleListView, ArticleView};
+use crate::model::persistence::article_view::{ArticleListView, ArticleSearchView, ArticleView};
 use crate::model::values::article_id::ArticleId;
+use crate::model::values::slug::Slug;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
+use crate::persistence::article_revision_repository::{
+    insert_revision_statement, lock_article_statement,
+};
 use crate::persistence::params::insert_article_params::InsertArticleParams;
 use crate::persistence::params::list_articles_params::ListArticlesParams;
 use crate::persistence::params::update_article_params::UpdateArticleParams;
 use crate::persistence::schema::{
-    ArticleFavorites, ArticleTags, Articles, Tags, UserFollows, Users,
+    ArticleFavorites, ArticleSlugHistory, ArticleTags, Articles, Tags, UserFollows, Users,
 };
+use crate::persistence::user_repository::user_has_permission;
 use anyhow::Result;
-use sea_query::{Alias, Expr, Order, PostgresQueryBuilder, Query, SelectStatement};
+use sea_query::{
+    Alias, Expr, OnConflict, Order, PostgresQueryBuilder, Query, SelectStatement, SimpleExpr,
+};
 use sea_query_binder::SqlxBinder;
 use sqlx::Row;
 
+/// Name of the unique constraint on `articles.slug`.
+const ARTICLES_SLUG_KEY: &str = "articles_slug_key";
+
+fn is_slug_conflict(error: &sqlx::Error) -> bool {
+    matches!(error, sqlx::Error::Database(e) if e.constraint() == Some(ARTICLES_SLUG_KEY))
+}
+
+/// Drops `slug` from the history once an article takes it as its live slug.
+fn release_retired_slug(slug: &Slug) -> sea_query::DeleteStatement {
+    Query::delete()
+        .from_table(ArticleSlugHistory::Table)
+        .and_where(Expr::col(ArticleSlugHistory::Slug).eq(slug))
+        .to_owned()
+}
+
+/// Published articles, plus any article written by `user_id`.
+fn visible_to(user_id: Option<UserId>) -> SimpleExpr {
+    let published = Expr::col((Articles::Table, Articles::Status)).eq(ArticleStatus::Published);
+
+    match user_id {
+        Some(user_id) => published.or(Expr::col((Articles::Table, Articles::AuthorId)).eq(user_id)),
+        None => published,
+    }
+}
+
+/// Unpublished articles of `user_id` that are still to go live.
+fn drafts_of(user_id: UserId) -> SimpleExpr {
+    Expr::col((Articles::Table, Articles::AuthorId))
+        .eq(user_id)
+        .and(
+            Expr::col((Articles::Table, Articles::Status))
+                .is_in([ArticleStatus::Draft, ArticleStatus::Scheduled]),
+        )
+}
+
 #[derive(Clone)]
 pub struct ArticleRepository {
     database: Database,
@@ -65,6 +112,8 @@ fn build_article_view_query(
           .column((Articles::Table, Articles::CreatedAt))
           .column((Articles::Table, Articles::UpdatedAt))
           .column((Articles::Table, Articles::Body))
+          .column((Articles::Table, Articles::Status))
+          .column((Articles::Table, Articles::PublishAt))
           .expr_as(
             Expr::col((Users::Table, Users::Id)),
             Alias::new("author_id"),
@@ -142,6 +191,11 @@ fn build_article_view_query(
         );
 
     where_statement(&mut query);
+    query.and_where(
+        Expr::col((Articles::Table, Articles::HiddenAt))
+            .is_null()
+            .or(user_has_permission(user_id, Permission::ModerateContent)),
+    );
 
     query
         .group_by_col((Articles::Table, Articles::Id))
@@ -150,7 +204,51 @@ fn build_article_view_query(
     query
 }
 
+/// Orders newest first on `(created_at, id)` and positions the query either
+/// by keyset `cursor` or, for older clients, by `offset`. One row beyond
+/// `limit` is fetched so the caller can tell whether another page exists.
+fn apply_pagination(
+    query: &mut SelectStatement,
+    limit: Limit,
+    offset: Option<Offset>,
+    cursor: Option<Cursor>,
+) {
+    let order = match cursor {
+        Some(cursor) => {
+            let key = Expr::tuple([
+                Expr::col((Articles::Table, Articles::CreatedAt)).into(),
+                Expr::col((Articles::Table, Articles::Id)).into(),
+            ]);
+            let position: SimpleExpr =
+                Expr::tuple([Expr::val(cursor.created_at).into(), Expr::val(cursor.id).into()])
+                    .into();
+
+            match cursor.direction {
+                CursorDirection::After => {
+                    query.and_where(key.lt(position));
+                    Order::Desc
+                }
+                CursorDirection::Before => {
+                    query.and_where(key.gt(position));
+                    Order::Asc
+                }
+            }
+        }
+        None => {
+            query.offset(offset.unwrap_or_default().value());
+            Order::Desc
+        }
+    };
+
+    query
+        .order_by((Articles::Table, Articles::CreatedAt), order.clone())
+        .order_by((Articles::Table, Articles::Id), order)
+        .limit(limit.value() + 1);
+}
+
 fn article_list_where_statement(params: &ListArticlesParams, query: &mut SelectStatement) {
+    query.and_where(visible_to(params.user_id));
+
     if let Some(tag) = &params.tag {
         query.and_where(Expr::exists(
             Query::select()
@@ -178,6 +276,13 @@ fn article_list_where_statement(params: &ListArticlesParams, query: &mut SelectS
         let favorited_subquery = favorited_subquery(favorited_by_username.clone());
         query.and_where(Expr::col((Articles::Table, Articles::Id)).in_subquery(favorited_subquery));
     }
+
+    if let Some(terms) = &params.search {
+        query.and_where(Expr::cust_with_values(
+            "articles.search_vector @@ websearch_to_tsquery('english', $1)",
+            [terms],
+        ));
+    }
 }
 
 impl ArticleRepository {
@@ -185,7 +290,12 @@ impl ArticleRepository {
         ArticleRepository { database }
     }
 
-    pub async fn insert_article(&self, params: InsertArticleParams) -> Result<Article, AppError> {
+    /// Returns `None` when another article already has the slug, leaving it to
+    /// the caller to retry with a different one.
+    pub async fn insert_article(
+        &self,
+        params: InsertArticleParams,
+    ) -> Result<Option<Article>, AppError> {
         let (sql, values) = Query::insert()
             .into_table(Articles::Table)
             .columns([
@@ -194,6 +304,8 @@ impl ArticleRepository {
                 Articles::Description,
                 Articles::Body,
                 Articles::AuthorId,
+                Articles::Status,
+                Articles::PublishAt,
             ])
             .values_panic([
                 params.slug.into(),
@@ -201,15 +313,31 @@ impl ArticleRepository {
                 params.description.into(),
                 params.body.into(),
                 params.author_id.into(),
+                params.publication.status.into(),
+                params.publication.publish_at.into(),
             ])
             .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
-        let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
-            .await?;
+        let mut tx = self.database.pool().begin().await?;
+
+        let row = match sqlx::query_with(&sql, values).fetch_one(&mut *tx).await {
+            Ok(row) => row,
+            Err(e) if is_slug_conflict(&e) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let article = Article::from_row(row);
+
+        let (sql, values) = release_retired_slug(&article.slug).build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = insert_revision_statement(article.id, article.author_id, &ArticleField::ALL)
+            .build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
 
-        Ok(Article::from_row(row))
+        Ok(Some(article))
     }
 
     pub async fn get_article_by<T>(
@@ -231,6 +359,9 @@ impl ArticleRepository {
             .column(Articles::AuthorId)
             .column(Articles::CreatedAt)
             .column(Articles::UpdatedAt)
+            .column(Articles::Status)
+            .column(Articles::PublishAt)
+            .column(Articles::HiddenAt)
             .from(Articles::Table)
             .and_where(Expr::col(field_name).eq(value))
             .build_sqlx(PostgresQueryBuilder);
@@ -242,6 +373,7 @@ impl ArticleRepository {
         Ok(row.map(Article::from_row))
     }
 
+    /// Only finds articles `user_id` is allowed to see.
     pub async fn get_article_view_by<T>(
         &self,
         field: IndexedArticleField,
@@ -253,7 +385,8 @@ impl ArticleRepository {
         T: Copy,
     {
         let query = build_article_view_query(user_id, move |q| {
-            q.and_where(Expr::col(field.to_field_name()).eq(value));
+            q.and_where(Expr::col(field.to_field_name()).eq(value))
+                .and_where(visible_to(user_id));
         });
 
         let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
@@ -283,10 +416,14 @@ impl ArticleRepository {
         Ok(ArticleView::from_row(row))
     }
 
-    pub async fn update_article(&self, params: UpdateArticleParams) -> Result<Article, AppError> {
+    /// Returns `None` when the new slug is already taken by another article.
+    pub async fn update_article(
+        &self,
+        params: &UpdateArticleParams,
+    ) -> Result<Option<Article>, AppError> {
         let updates = params.as_list();
 
-        if updates.is_empty() {
+        if updates.is_empty() && params.publication.is_none() {
             return Err(AppError::BadData("No fields to update".to_string()));
         }
 
@@ -299,16 +436,95 @@ impl ArticleRepository {
             query.value(column.clone(), value.clone());
         }
 
+        if let Some(publication) = params.publication {
+            query
+                .value(Articles::Status, publication.status)
+                .value(Articles::PublishAt, publication.publish_at);
+        }
+
         let (sql, values) = query
             .and_where(Expr::col(Articles::Id).eq(params.article_id))
             .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
+        let mut tx = self.database.pool().begin().await?;
+
+        let (lock_sql, lock_values) =
+            lock_article_statement(params.article_id).build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&lock_sql, lock_values).execute(&mut *tx).await?;
+
+        let row = match sqlx::query_with(&sql, values).fetch_one(&mut *tx).await {
+            Ok(row) => row,
+            Err(e) if is_slug_conflict(&e) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Some(slug) = &params.slug {
+            let (sql, values) = Query::insert()
+                .into_table(ArticleSlugHistory::Table)
+                .columns([ArticleSlugHistory::Slug, ArticleSlugHistory::ArticleId])
+                .values_panic([params.previous_slug.clone().into(), params.article_id.into()])
+                .on_conflict(
+                    OnConflict::column(ArticleSlugHistory::Slug)
+                        .update_columns([ArticleSlugHistory::ArticleId, ArticleSlugHistory::CreatedAt])
+                        .to_owned(),
+                )
+                .build_sqlx(PostgresQueryBuilder);
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+            let (sql, values) = release_retired_slug(slug).build_sqlx(PostgresQueryBuilder);
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+        }
+
+        if !params.changed_fields.is_empty() {
+            let (sql, values) =
+                insert_revision_statement(params.article_id, params.editor_id, &params.changed_fields)
+                    .build_sqlx(PostgresQueryBuilder);
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(Some(Article::from_row(row)))
+    }
+
+    /// Current slug of the article that was reachable under `retired_slug`
+    /// before a title change.
+    pub async fn get_current_slug(&self, retired_slug: &Slug) -> Result<Option<Slug>, AppError> {
+        let (sql, values) = Query::select()
+            .column((Articles::Table, Articles::Slug))
+            .from(ArticleSlugHistory::Table)
+            .inner_join(
+                Articles::Table,
+                Expr::col((ArticleSlugHistory::Table, ArticleSlugHistory::ArticleId))
+                    .equals((Articles::Table, Articles::Id)),
+            )
+            .and_where(Expr::col((ArticleSlugHistory::Table, ArticleSlugHistory::Slug)).eq(retired_slug))
+            .build_sqlx(PostgresQueryBuilder);
+
         let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
+            .fetch_optional(self.database.pool())
             .await?;
 
-        Ok(Article::from_row(row))
+        Ok(row.map(|row| row.get("slug")))
+    }
+
+    /// Publishes every scheduled article whose `publish_at` has passed and
+    /// returns how many there were.
+    pub async fn publish_due_articles(&self) -> Result<u64, AppError> {
+        let (sql, values) = Query::update()
+            .table(Articles::Table)
+            .value(Articles::Status, ArticleStatus::Published)
+            .value(Articles::UpdatedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Articles::Status).eq(ArticleStatus::Scheduled))
+            .and_where(Expr::col(Articles::PublishAt).lte(Expr::current_timestamp()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected())
     }
 
     pub async fn delete_article(&self, article_id: ArticleId) -> Result<(), AppError> {
@@ -324,15 +540,57 @@ impl ArticleRepository {
         Ok(())
     }
 
-    pub async fn list_articles(
+    pub async fn list_articles(&self, params: ListArticlesParams) -> Result<ArticlePage, AppError> {
+        let limit = params.limit.unwrap_or_default();
+        let mut query =
+            build_article_view_query(params.user_id, |q| article_list_where_statement(&params, q));
+        apply_pagination(&mut query, limit, params.offset, params.cursor);
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(ArticlePage::from_rows(
+            rows.into_iter().map(ArticleListView::from_row).collect(),
+            limit,
+            params.cursor,
+            params.offset,
+        ))
+    }
+
+    /// Ranks articles matching `params.search`; callers must set it.
+    pub async fn search_articles(
         &self,
         params: ListArticlesParams,
-    ) -> Result<Vec<ArticleListView>, AppError> {
+    ) -> Result<Vec<ArticleSearchView>, AppError> {
+        let terms = params
+            .search
+            .clone()
+            .ok_or_else(|| AppError::BadData("Search query is required".to_string()))?;
+
         let mut query =
             build_article_view_query(params.user_id, |q| article_list_where_statement(&params, q));
 
         let (sql, values) = query
+            .expr_as(
+                Expr::cust_with_values(
+                    "ts_rank(articles.search_vector, websearch_to_tsquery('english', $1))",
+                    [&terms],
+                ),
+                Alias::new("score"),
+            )
+            .expr_as(
+                Expr::cust_with_values(
+                    "ts_headline('english', articles.body, websearch_to_tsquery('english', $1), 'StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=30, MinWords=10')",
+                    [&terms],
+                ),
+                Alias::new("snippet"),
+            )
+            .order_by(Alias::new("score"), Order::Desc)
             .order_by((Articles::Table, Articles::CreatedAt), Order::Desc)
+            .order_by((Articles::Table, Articles::Id), Order::Desc)
             .limit(params.limit.unwrap_or_default().value())
             .offset(params.offset.unwrap_or_default().value())
             .build_sqlx(PostgresQueryBuilder);
@@ -341,7 +599,7 @@ impl ArticleRepository {
             .fetch_all(self.database.pool())
             .await?;
 
-        Ok(rows.into_iter().map(ArticleListView::from_row).collect())
+        Ok(rows.into_iter().map(ArticleSearchView::from_row).collect())
     }
 
     pub async fn count_articles(&self, params: ListArticlesParams) -> Result<u64, AppError> {
@@ -366,27 +624,33 @@ impl ArticleRepository {
         user_id: UserId,
         limit: Option<Limit>,
         offset: Option<Offset>,
-    ) -> Result<Vec<ArticleListView>, AppError> {
+        cursor: Option<Cursor>,
+    ) -> Result<ArticlePage, AppError> {
+        let limit = limit.unwrap_or_default();
         let mut query = build_article_view_query(Some(user_id), |q| {
-            q.and_where(Expr::exists(following_subquery(user_id)));
+            q.and_where(Expr::exists(following_subquery(user_id)))
+                .and_where(visible_to(Some(user_id)));
         });
+        apply_pagination(&mut query, limit, offset, cursor);
 
-        let (sql, values) = query
-            .order_by((Articles::Table, Articles::CreatedAt), Order::Desc)
-            .limit(limit.unwrap_or_default().value())
-            .offset(offset.unwrap_or_default().value())
-            .build_sqlx(PostgresQueryBuilder);
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
         let rows = sqlx::query_with(&sql, values)
             .fetch_all(self.database.pool())
             .await?;
 
-        Ok(rows.into_iter().map(ArticleListView::from_row).collect())
+        Ok(ArticlePage::from_rows(
+            rows.into_iter().map(ArticleListView::from_row).collect(),
+            limit,
+            cursor,
+            offset,
+        ))
     }
 
     pub async fn count_feed_articles(&self, user_id: UserId) -> Result<u64, AppError> {
         let subquery = build_article_view_query(Some(user_id), |q| {
-            q.and_where(Expr::exists(following_subquery(user_id)));
+            q.and_where(Expr::exists(following_subquery(user_id)))
+                .and_where(visible_to(Some(user_id)));
         });
 
         let (sql, values) = Query::select()
@@ -402,6 +666,48 @@ impl ArticleRepository {
         Ok(count as u64)
     }
 
+    pub async fn get_draft_articles(
+        &self,
+        user_id: UserId,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+        cursor: Option<Cursor>,
+    ) -> Result<ArticlePage, AppError> {
+        let limit = limit.unwrap_or_default();
+        let mut query = build_article_view_query(Some(user_id), |q| {
+            q.and_where(drafts_of(user_id));
+        });
+        apply_pagination(&mut query, limit, offset, cursor);
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(ArticlePage::from_rows(
+            rows.into_iter().map(ArticleListView::from_row).collect(),
+            limit,
+            cursor,
+            offset,
+        ))
+    }
+
+    pub async fn count_draft_articles(&self, user_id: UserId) -> Result<u64, AppError> {
+        let (sql, values) = Query::select()
+            .expr_as(Expr::cust("COUNT(*)"), "count")
+            .from(Articles::Table)
+            .and_where(drafts_of(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let count: i64 = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("count");
+
+        Ok(count as u64)
+    }
+
     pub async fn favorite_article(
         &self,
         user_id: UserId,
@@ -0,0 +1,140 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::article_field::ArticleField;
+use crate::model::persistence::article_revision::ArticleRevision;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::user_id::UserId;
+use crate::persistence::schema::{ArticleRevisions, Articles, Users};
+use anyhow::Result;
+use sea_query::{
+    Alias, Expr, InsertStatement, LockType, Order, PostgresQueryBuilder, Query, SelectStatement,
+    SimpleExpr, SubQueryStatement,
+};
+use sea_query_binder::SqlxBinder;
+
+#[derive(Clone)]
+pub struct ArticleRevisionRepository {
+    database: Database,
+}
+
+/// Locks the article row for the rest of the transaction. Taken before
+/// `insert_revision_statement` so concurrent edits number their revisions one
+/// after the other.
+pub(crate) fn lock_article_statement(article_id: ArticleId) -> SelectStatement {
+    Query::select()
+        .column(Articles::Id)
+        .from(Articles::Table)
+        .and_where(Expr::col(Articles::Id).eq(article_id))
+        .lock(LockType::Update)
+        .to_owned()
+}
+
+/// Snapshots the article's current content as its next revision. Runs in the
+/// transaction of the write it records, once `lock_article_statement` holds the
+/// article row, so concurrent edits cannot take the same number.
+pub(crate) fn insert_revision_statement(
+    article_id: ArticleId,
+    author_id: UserId,
+    changed_fields: &[ArticleField],
+) -> InsertStatement {
+    let next_number = Query::select()
+        .expr(Expr::cust("COALESCE(MAX(number), 0) + 1"))
+        .from(ArticleRevisions::Table)
+        .and_where(Expr::col(ArticleRevisions::ArticleId).eq(article_id))
+        .to_owned();
+
+    let changed_fields: Vec<String> = changed_fields
+        .iter()
+        .map(|field| field.as_str().to_string())
+        .collect();
+
+    let snapshot = Query::select()
+        .column(Articles::Id)
+        .expr(SimpleExpr::SubQuery(
+            None,
+            Box::new(SubQueryStatement::SelectStatement(next_number)),
+        ))
+        .expr(Expr::val(author_id))
+        .columns([Articles::Title, Articles::Description, Articles::Body])
+        .expr(Expr::val(changed_fields))
+        .from(Articles::Table)
+        .and_where(Expr::col(Articles::Id).eq(article_id))
+        .to_owned();
+
+    Query::insert()
+        .into_table(ArticleRevisions::Table)
+        .columns([
+            ArticleRevisions::ArticleId,
+            ArticleRevisions::Number,
+            ArticleRevisions::AuthorId,
+            ArticleRevisions::Title,
+            ArticleRevisions::Description,
+            ArticleRevisions::Body,
+            ArticleRevisions::ChangedFields,
+        ])
+        .select_from(snapshot)
+        .expect("revision snapshot selects one value per column")
+        .to_owned()
+}
+
+fn revision_query(article_id: ArticleId) -> SelectStatement {
+    Query::select()
+        .columns([
+            (ArticleRevisions::Table, ArticleRevisions::Number),
+            (ArticleRevisions::Table, ArticleRevisions::Title),
+            (ArticleRevisions::Table, ArticleRevisions::Description),
+            (ArticleRevisions::Table, ArticleRevisions::Body),
+            (ArticleRevisions::Table, ArticleRevisions::ChangedFields),
+            (ArticleRevisions::Table, ArticleRevisions::CreatedAt),
+        ])
+        .expr_as(
+            Expr::col((Users::Table, Users::Username)),
+            Alias::new("author_username"),
+        )
+        .from(ArticleRevisions::Table)
+        .left_join(
+            Users::Table,
+            Expr::col((ArticleRevisions::Table, ArticleRevisions::AuthorId))
+                .equals((Users::Table, Users::Id)),
+        )
+        .and_where(Expr::col((ArticleRevisions::Table, ArticleRevisions::ArticleId)).eq(article_id))
+        .to_owned()
+}
+
+impl ArticleRevisionRepository {
+    pub fn new(database: Database) -> Self {
+        ArticleRevisionRepository { database }
+    }
+
+    /// All revisions of an article, newest first.
+    pub async fn list_revisions(
+        &self,
+        article_id: ArticleId,
+    ) -> Result<Vec<ArticleRevision>, AppError> {
+        let (sql, values) = revision_query(article_id)
+            .order_by((ArticleRevisions::Table, ArticleRevisions::Number), Order::Desc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(ArticleRevision::from_row).collect())
+    }
+
+    pub async fn get_revision(
+        &self,
+        article_id: ArticleId,
+        number: i32,
+    ) -> Result<Option<ArticleRevision>, AppError> {
+        let (sql, values) = revision_query(article_id)
+            .and_where(Expr::col((ArticleRevisions::Table, ArticleRevisions::Number)).eq(number))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(ArticleRevision::from_row))
+    }
+}
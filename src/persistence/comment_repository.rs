@@ -1,14 +1,19 @@
 use crate::app_error::AppError;
+use crate::domain::policy::Permission;
 use crate::database::Database;
 use crate::model::persistence::comment::Comment;
-use crate::model::persistence::comment_view::CommentView;
+use crate::model::persistence::comment_revision::CommentRevision;
+use crate::model::persistence::comment_view::{CommentView, DELETED_COMMENT_BODY};
 use crate::model::values::article_id::ArticleId;
 use crate::model::values::comment_id::CommentId;
 use crate::model::values::user_id::UserId;
 use crate::persistence::params::insert_comment_params::InsertCommentParams;
-use crate::persistence::schema::{Comments, UserFollows, Users};
+use crate::persistence::params::update_comment_params::UpdateCommentParams;
+use crate::persistence::schema::{CommentRevisions, Comments, UserFollows, Users};
+use crate::persistence::user_repository::user_has_permission;
 use anyhow::Result;
-use sea_query::{Alias, Expr, Order, PostgresQueryBuilder, Query};
+use chrono::{DateTime, Utc};
+use sea_query::{Alias, Asterisk, Expr, LockType, Order, PostgresQueryBuilder, Query};
 use sea_query_binder::SqlxBinder;
 use sqlx::Row;
 
@@ -17,11 +22,23 @@ pub struct CommentRepository {
     database: Database,
 }
 
+/// Hidden comments are left out for readers who cannot moderate, and their
+/// replies with them.
 fn comment_view_query(user_id: Option<UserId>) -> sea_query::SelectStatement {
     let mut select = Query::select();
 
     select
         .column((Comments::Table, Comments::Id))
+        .column((Comments::Table, Comments::ParentId))
+        .column((Comments::Table, Comments::Depth))
+        .expr_as(
+            Expr::col((Comments::Table, Comments::DeletedAt)).is_not_null(),
+            Alias::new("deleted"),
+        )
+        .expr_as(
+            Expr::col((Comments::Table, Comments::EditedAt)).is_not_null(),
+            Alias::new("edited"),
+        )
         .column((Comments::Table, Comments::Body))
         .column((Comments::Table, Comments::CreatedAt))
         .column((Comments::Table, Comments::UpdatedAt))
@@ -66,6 +83,12 @@ fn comment_view_query(user_id: Option<UserId>) -> sea_query::SelectStatement {
         }
     }
 
+    select.and_where(
+        Expr::col((Comments::Table, Comments::HiddenAt))
+            .is_null()
+            .or(user_has_permission(user_id, Permission::ModerateContent)),
+    );
+
     select
 }
 
@@ -77,11 +100,19 @@ impl CommentRepository {
     pub async fn insert_comment(&self, params: InsertCommentParams) -> Result<Comment, AppError> {
         let (sql, values) = Query::insert()
             .into_table(Comments::Table)
-            .columns([Comments::Body, Comments::ArticleId, Comments::AuthorId])
+            .columns([
+                Comments::Body,
+                Comments::ArticleId,
+                Comments::AuthorId,
+                Comments::ParentId,
+                Comments::Depth,
+            ])
             .values_panic([
                 params.body.into(),
                 params.article_id.into(),
                 params.author_id.into(),
+                params.parent_id.map(|id| id.value()).into(),
+                params.depth.into(),
             ])
             .returning_all()
             .build_sqlx(PostgresQueryBuilder);
@@ -106,27 +137,143 @@ impl CommentRepository {
         Ok(())
     }
 
-    pub async fn is_comment_author(
+    /// Keeps the row so replies stay attached, but drops its content along
+    /// with any earlier revisions of it.
+    pub async fn soft_delete_comment(&self, comment_id: CommentId) -> Result<(), AppError> {
+        let mut tx = self.database.pool().begin().await?;
+
+        let (sql, values) = Query::update()
+            .table(Comments::Table)
+            .value(Comments::Body, DELETED_COMMENT_BODY)
+            .value(Comments::DeletedAt, Expr::current_timestamp())
+            .value(Comments::UpdatedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Comments::Id).eq(comment_id))
+            .and_where(Expr::col(Comments::DeletedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(CommentRevisions::Table)
+            .and_where(Expr::col(CommentRevisions::CommentId).eq(comment_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Replaces the body and records the previous one as a revision. The row
+    /// is locked first so concurrent edits cannot lose a revision.
+    pub async fn update_comment(&self, params: UpdateCommentParams) -> Result<(), AppError> {
+        let mut tx = self.database.pool().begin().await?;
+
+        let (sql, values) = Query::select()
+            .columns([Comments::Body, Comments::UpdatedAt])
+            .from(Comments::Table)
+            .and_where(Expr::col(Comments::Id).eq(params.comment_id))
+            .lock(LockType::Update)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let Some(current) = sqlx::query_with(&sql, values)
+            .fetch_optional(&mut *tx)
+            .await?
+        else {
+            return Err(AppError::NotFound);
+        };
+
+        let current_body: String = current.get("body");
+        let written_at: DateTime<Utc> = current.get("updated_at");
+        if current_body == params.body.value() {
+            return Ok(());
+        }
+
+        let (sql, values) = Query::insert()
+            .into_table(CommentRevisions::Table)
+            .columns([
+                CommentRevisions::CommentId,
+                CommentRevisions::Body,
+                CommentRevisions::CreatedAt,
+            ])
+            .values_panic([
+                params.comment_id.into(),
+                current_body.into(),
+                written_at.into(),
+            ])
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = Query::update()
+            .table(Comments::Table)
+            .value(Comments::Body, params.body)
+            .value(Comments::UpdatedAt, Expr::current_timestamp())
+            .value(Comments::EditedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Comments::Id).eq(params.comment_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Previous bodies of a comment, oldest first.
+    pub async fn get_comment_revisions(
         &self,
         comment_id: CommentId,
-        user_id: UserId,
-    ) -> Result<bool, AppError> {
+    ) -> Result<Vec<CommentRevision>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                CommentRevisions::Body,
+                CommentRevisions::CreatedAt,
+                CommentRevisions::ReplacedAt,
+            ])
+            .from(CommentRevisions::Table)
+            .and_where(Expr::col(CommentRevisions::CommentId).eq(comment_id))
+            .order_by(CommentRevisions::ReplacedAt, Order::Asc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(CommentRevision::from_row).collect())
+    }
+
+    pub async fn has_replies(&self, comment_id: CommentId) -> Result<bool, AppError> {
         let subquery = Query::select()
             .expr(Expr::value(1))
             .from(Comments::Table)
-            .and_where(Expr::col(Comments::Id).eq(comment_id))
-            .and_where(Expr::col(Comments::AuthorId).eq(user_id))
+            .and_where(Expr::col(Comments::ParentId).eq(comment_id))
             .to_owned();
 
         let (sql, values) = Query::select()
-            .expr_as(Expr::exists(subquery), "is_author")
+            .expr_as(Expr::exists(subquery), "has_replies")
             .build_sqlx(PostgresQueryBuilder);
 
         let row = sqlx::query_with(&sql, values)
             .fetch_one(self.database.pool())
             .await?;
 
-        Ok(row.get("is_author"))
+        Ok(row.get("has_replies"))
+    }
+
+    pub async fn get_comment_by_id(&self, comment_id: CommentId) -> Result<Option<Comment>, AppError> {
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(Comments::Table)
+            .and_where(Expr::col(Comments::Id).eq(comment_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(Comment::from_row))
     }
 
     pub async fn get_comments(
@@ -0,0 +1,95 @@
+use crate::app_error::AppError;
+use crate::database::{Database, MIGRATOR};
+use crate::model::health::{MigrationStatus, PoolStats};
+use crate::persistence::schema::SqlxMigrations;
+use anyhow::Result;
+use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+use std::collections::HashMap;
+
+const UNDEFINED_TABLE: &str = "42P01";
+
+#[derive(Clone)]
+pub struct HealthRepository {
+    database: Database,
+}
+
+impl HealthRepository {
+    pub fn new(database: Database) -> Self {
+        HealthRepository { database }
+    }
+
+    pub async fn ping(&self) -> Result<PoolStats, AppError> {
+        let (sql, values) = Query::select()
+            .expr(Expr::cust("1"))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        let pool = self.database.pool();
+
+        Ok(PoolStats {
+            connections: pool.size(),
+            idle_connections: pool.num_idle() as u32,
+        })
+    }
+
+    pub async fn get_migration_status(&self) -> Result<MigrationStatus, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                SqlxMigrations::Version,
+                SqlxMigrations::Checksum,
+                SqlxMigrations::Success,
+            ])
+            .from(SqlxMigrations::Table)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = match sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await
+        {
+            Ok(rows) => rows,
+            // Migrations have never run against this database.
+            Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some(UNDEFINED_TABLE) => {
+                Vec::new()
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let recorded: HashMap<i64, (Vec<u8>, bool)> = rows
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get("version"),
+                    (row.get("checksum"), row.get("success")),
+                )
+            })
+            .collect();
+
+        let mut status = MigrationStatus {
+            applied: 0,
+            pending: 0,
+            mismatched: Vec::new(),
+            dirty: Vec::new(),
+        };
+
+        for migration in MIGRATOR
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+        {
+            match recorded.get(&migration.version) {
+                None => status.pending += 1,
+                Some((_, false)) => status.dirty.push(migration.version),
+                Some((checksum, true)) if *checksum != *migration.checksum => {
+                    status.mismatched.push(migration.version)
+                }
+                Some(_) => status.applied += 1,
+            }
+        }
+
+        Ok(status)
+    }
+}
@@ -0,0 +1,196 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::persistence::user::User;
+use crate::model::persistence::user_identity::UserIdentity;
+use crate::model::values::email::Email;
+use crate::model::values::identity_id::IdentityId;
+use crate::model::values::user_id::UserId;
+use crate::model::values::username::Username;
+use crate::persistence::access_token_repository::revoke_all_access_tokens_statement;
+use crate::persistence::params::insert_identity_params::InsertIdentityParams;
+use crate::persistence::schema::{UserIdentities, Users};
+use crate::persistence::session_repository::revoke_all_sessions_statement;
+use crate::persistence::totp_repository::delete_totp_statements;
+use anyhow::Result;
+use sea_query::{Expr, InsertStatement, OnConflict, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+
+fn insert_identity_statement(user_id: UserId, params: InsertIdentityParams) -> InsertStatement {
+    Query::insert()
+        .into_table(UserIdentities::Table)
+        .columns([
+            UserIdentities::UserId,
+            UserIdentities::Provider,
+            UserIdentities::Subject,
+            UserIdentities::Email,
+        ])
+        .values_panic([
+            user_id.into(),
+            params.provider.into(),
+            params.subject.into(),
+            params.email.into(),
+        ])
+        .on_conflict(
+            OnConflict::columns([UserIdentities::Provider, UserIdentities::Subject])
+                .do_nothing()
+                .to_owned(),
+        )
+        .to_owned()
+}
+
+#[derive(Clone)]
+pub struct IdentityRepository {
+    database: Database,
+}
+
+impl IdentityRepository {
+    pub fn new(database: Database) -> Self {
+        IdentityRepository { database }
+    }
+
+    /// The user a provider account signs in as, if it is linked.
+    pub async fn find_user_id(&self, provider: &str, subject: &str) -> Result<Option<UserId>, AppError> {
+        let (sql, values) = Query::select()
+            .column(UserIdentities::UserId)
+            .from(UserIdentities::Table)
+            .and_where(Expr::col(UserIdentities::Provider).eq(provider))
+            .and_where(Expr::col(UserIdentities::Subject).eq(subject))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(|row| row.get("user_id")))
+    }
+
+    /// Returns `false` if the provider account is already linked, to this
+    /// user or another one.
+    pub async fn link_identity(&self, user_id: UserId, params: InsertIdentityParams) -> Result<bool, AppError> {
+        let (sql, values) = insert_identity_statement(user_id, params).build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Links an identity to an account whose email was never verified. The
+    /// provider has now proven who owns the address, so the email counts as
+    /// verified, and everything whoever registered it could get back in with
+    /// ends: the password, sessions, access tokens, other identities and 2FA.
+    pub async fn claim_unverified_account(
+        &self,
+        user_id: UserId,
+        params: InsertIdentityParams,
+    ) -> Result<bool, AppError> {
+        let mut tx = self.database.pool().begin().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(UserIdentities::Table)
+            .and_where(Expr::col(UserIdentities::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = insert_identity_statement(user_id, params).build_sqlx(PostgresQueryBuilder);
+        if sqlx::query_with(&sql, values)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected()
+            == 0
+        {
+            return Ok(false);
+        }
+
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(Users::EmailVerifiedAt, Expr::current_timestamp())
+            .value(Users::PasswordHash, Expr::val(None::<String>))
+            .value(Users::UpdatedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Users::Id).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = revoke_all_sessions_statement(user_id).build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = revoke_all_access_tokens_statement(user_id).build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        for statement in delete_totp_statements(user_id) {
+            let (sql, values) = statement.build_sqlx(PostgresQueryBuilder);
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Creates a user without a password, with the email the provider
+    /// verified, and links the identity to it.
+    pub async fn create_user_with_identity(
+        &self,
+        username: Username,
+        email: Email,
+        params: InsertIdentityParams,
+    ) -> Result<User, AppError> {
+        let mut tx = self.database.pool().begin().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(Users::Table)
+            .columns([Users::Email, Users::Username, Users::EmailVerifiedAt])
+            .values_panic([
+                email.into(),
+                username.into(),
+                Expr::current_timestamp().into(),
+            ])
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+        let user = User::from_row(sqlx::query_with(&sql, values).fetch_one(&mut *tx).await?);
+
+        let (sql, values) = insert_identity_statement(user.id, params).build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(user)
+    }
+
+    pub async fn list_identities(&self, user_id: UserId) -> Result<Vec<UserIdentity>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                UserIdentities::Id,
+                UserIdentities::UserId,
+                UserIdentities::Provider,
+                UserIdentities::Email,
+                UserIdentities::CreatedAt,
+            ])
+            .from(UserIdentities::Table)
+            .and_where(Expr::col(UserIdentities::UserId).eq(user_id))
+            .order_by(UserIdentities::CreatedAt, Order::Asc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(UserIdentity::from_row).collect())
+    }
+
+    pub async fn delete_identity(&self, user_id: UserId, id: IdentityId) -> Result<bool, AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(UserIdentities::Table)
+            .and_where(Expr::col(UserIdentities::Id).eq(id))
+            .and_where(Expr::col(UserIdentities::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -1,7 +1,16 @@
+pub mod access_token_repository;
 pub mod article_repository;
+pub mod article_revision_repository;
 pub mod comment_repository;
+pub mod health_repository;
+pub mod identity_repository;
+pub mod oidc_login_repository;
 pub mod params;
+pub mod password_reset_repository;
 pub mod profile_repository;
+pub mod report_repository;
 pub mod schema;
+pub mod session_repository;
 pub mod tag_repository;
+pub mod totp_repository;
 pub mod user_repository;
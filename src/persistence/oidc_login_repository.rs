@@ -0,0 +1,73 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::persistence::oidc_login::OidcLogin;
+use crate::persistence::params::insert_oidc_login_params::InsertOidcLoginParams;
+use crate::persistence::schema::OidcLogins;
+use anyhow::Result;
+use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+
+#[derive(Clone)]
+pub struct OidcLoginRepository {
+    database: Database,
+}
+
+impl OidcLoginRepository {
+    pub fn new(database: Database) -> Self {
+        OidcLoginRepository { database }
+    }
+
+    /// Also clears out sign-ins that were abandoned.
+    pub async fn insert_login(&self, params: InsertOidcLoginParams) -> Result<(), AppError> {
+        let (delete_sql, delete_values) = Query::delete()
+            .from_table(OidcLogins::Table)
+            .and_where(Expr::col(OidcLogins::ExpiresAt).lt(Expr::current_timestamp()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let (sql, values) = Query::insert()
+            .into_table(OidcLogins::Table)
+            .columns([
+                OidcLogins::StateHash,
+                OidcLogins::Provider,
+                OidcLogins::CodeVerifier,
+                OidcLogins::Nonce,
+                OidcLogins::UserId,
+                OidcLogins::ExpiresAt,
+            ])
+            .values_panic([
+                params.state_hash.into(),
+                params.provider.into(),
+                params.code_verifier.into(),
+                params.nonce.into(),
+                params.user_id.map(|id| id.value()).into(),
+                params.expires_at.into(),
+            ])
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&delete_sql, delete_values)
+            .execute(self.database.pool())
+            .await?;
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes and returns the sign-in, so each `state` works only once.
+    pub async fn take_login(&self, state_hash: &str, provider: &str) -> Result<Option<OidcLogin>, AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(OidcLogins::Table)
+            .and_where(Expr::col(OidcLogins::StateHash).eq(state_hash))
+            .and_where(Expr::col(OidcLogins::Provider).eq(provider))
+            .and_where(Expr::col(OidcLogins::ExpiresAt).gt(Expr::current_timestamp()))
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(OidcLogin::from_row))
+    }
+}
@@ -0,0 +1,11 @@
+use crate::model::scope::Scope;
+use crate::model::values::user_id::UserId;
+use chrono::{DateTime, Utc};
+
+pub struct InsertAccessTokenParams {
+    pub user_id: UserId,
+    pub name: String,
+    pub token_hash: String,
+    pub scopes: Vec<Scope>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
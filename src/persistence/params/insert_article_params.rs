@@ -1,3 +1,4 @@
+use crate::model::article_status::Publication;
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_title::ArticleTitle;
@@ -10,4 +11,5 @@ pub struct InsertArticleParams {
     pub description: ArticleDescription,
     pub body: ArticleBody,
     pub author_id: UserId,
+    pub publication: Publication,
 }
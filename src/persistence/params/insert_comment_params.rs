@@ -1,9 +1,12 @@
 use crate::model::values::article_id::ArticleId;
 use crate::model::values::comment_body::CommentBody;
+use crate::model::values::comment_id::CommentId;
 use crate::model::values::user_id::UserId;
 
 pub struct InsertCommentParams {
     pub body: CommentBody,
     pub article_id: ArticleId,
     pub author_id: UserId,
+    pub parent_id: Option<CommentId>,
+    pub depth: i16,
 }
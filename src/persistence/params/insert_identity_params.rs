@@ -0,0 +1,5 @@
+pub struct InsertIdentityParams {
+    pub provider: String,
+    pub subject: String,
+    pub email: String,
+}
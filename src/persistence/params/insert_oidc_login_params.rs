@@ -0,0 +1,11 @@
+use crate::model::values::user_id::UserId;
+use chrono::{DateTime, Utc};
+
+pub struct InsertOidcLoginParams {
+    pub state_hash: String,
+    pub provider: String,
+    pub code_verifier: String,
+    pub nonce: String,
+    pub user_id: Option<UserId>,
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,8 @@
+use crate::model::values::user_id::UserId;
+use chrono::{DateTime, Utc};
+
+pub struct InsertPasswordResetParams {
+    pub user_id: UserId,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,14 @@
+use crate::model::report::ReportReason;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::report_details::ReportDetails;
+use crate::model::values::user_id::UserId;
+
+pub struct InsertReportParams {
+    pub article_id: ArticleId,
+    /// Set when the report is about a comment on the article.
+    pub comment_id: Option<CommentId>,
+    pub reporter_id: UserId,
+    pub reason: ReportReason,
+    pub details: Option<ReportDetails>,
+}
@@ -1,6 +1,9 @@
 use crate::domain::commands::list_articles_query::ListArticlesQuery;
+use crate::domain::commands::search_articles_query::SearchArticlesQuery;
+use crate::model::cursor::Cursor;
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
+use crate::model::values::search_terms::SearchTerms;
 use crate::model::values::tag_name::TagName;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
@@ -12,6 +15,8 @@ pub struct ListArticlesParams {
     pub(crate) user_id: Option<UserId>,
     pub(crate) limit: Option<Limit>,
     pub(crate) offset: Option<Offset>,
+    pub(crate) cursor: Option<Cursor>,
+    pub(crate) search: Option<SearchTerms>,
 }
 
 impl ListArticlesParams {
@@ -23,6 +28,21 @@ impl ListArticlesParams {
             user_id,
             limit: query.limit,
             offset: query.offset,
+            cursor: query.cursor,
+            search: None,
+        }
+    }
+
+    pub fn from_search_query(query: SearchArticlesQuery, user_id: Option<UserId>) -> ListArticlesParams {
+        ListArticlesParams {
+            tag: query.tag,
+            author: query.author,
+            favorited_by: None,
+            user_id,
+            limit: query.limit,
+            offset: query.offset,
+            cursor: None,
+            search: Some(query.terms),
         }
     }
 }
@@ -0,0 +1,22 @@
+use crate::domain::commands::list_users_query::ListUsersQuery;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+
+pub struct ListUsersParams {
+    /// Matched as a case-insensitive substring of the username or email.
+    pub(crate) search: Option<String>,
+    pub(crate) suspended: Option<bool>,
+    pub(crate) limit: Option<Limit>,
+    pub(crate) offset: Option<Offset>,
+}
+
+impl ListUsersParams {
+    pub fn from_query(query: ListUsersQuery) -> ListUsersParams {
+        ListUsersParams {
+            search: query.search,
+            suspended: query.suspended,
+            limit: query.limit,
+            offset: query.offset,
+        }
+    }
+}
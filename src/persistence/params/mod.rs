@@ -1,7 +1,15 @@
+pub mod insert_access_token_params;
 pub mod insert_article_params;
 pub mod insert_comment_params;
+pub mod insert_identity_params;
+pub mod insert_oidc_login_params;
+pub mod insert_password_reset_params;
+pub mod insert_report_params;
+pub mod insert_session_params;
 pub mod insert_tag_params;
 pub mod insert_user_params;
 pub mod list_articles_params;
+pub mod list_users_params;
 pub mod update_article_params;
+pub mod update_comment_params;
 pub mod update_user_params;
@@ -1,16 +1,27 @@
+use crate::model::article_field::ArticleField;
+use crate::model::article_status::Publication;
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_id::ArticleId;
 use crate::model::values::article_title::ArticleTitle;
 use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
 use crate::persistence::schema::Articles;
 
 pub struct UpdateArticleParams {
     pub article_id: ArticleId,
+    /// Slug before the update; kept in the slug history when `slug` replaces it.
+    pub previous_slug: Slug,
     pub slug: Option<Slug>,
     pub title: Option<ArticleTitle>,
     pub description: Option<ArticleDescription>,
     pub body: Option<ArticleBody>,
+    /// New status and publish time, written together.
+    pub publication: Option<Publication>,
+    pub editor_id: UserId,
+    /// Content fields whose value differs from the stored article. A revision
+    /// is only recorded when this is not empty.
+    pub changed_fields: Vec<ArticleField>,
 }
 
 impl UpdateArticleParams {
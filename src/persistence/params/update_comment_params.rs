@@ -0,0 +1,7 @@
+use crate::model::values::comment_body::CommentBody;
+use crate::model::values::comment_id::CommentId;
+
+pub struct UpdateCommentParams {
+    pub comment_id: CommentId,
+    pub body: CommentBody,
+}
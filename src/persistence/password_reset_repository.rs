@@ -0,0 +1,114 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::values::password_hash::PasswordHash;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_password_reset_params::InsertPasswordResetParams;
+use crate::persistence::schema::{PasswordResetTokens, Users};
+use crate::persistence::session_repository::revoke_all_sessions_statement;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+
+#[derive(Clone)]
+pub struct PasswordResetRepository {
+    database: Database,
+}
+
+impl PasswordResetRepository {
+    pub fn new(database: Database) -> Self {
+        PasswordResetRepository { database }
+    }
+
+    pub async fn insert_token(&self, params: InsertPasswordResetParams) -> Result<(), AppError> {
+        let (sql, values) = Query::insert()
+            .into_table(PasswordResetTokens::Table)
+            .columns([
+                PasswordResetTokens::UserId,
+                PasswordResetTokens::TokenHash,
+                PasswordResetTokens::ExpiresAt,
+            ])
+            .values_panic([
+                params.user_id.into(),
+                params.token_hash.into(),
+                params.expires_at.into(),
+            ])
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Whether a token was issued to the user after `since`.
+    pub async fn has_token_since(&self, user_id: UserId, since: DateTime<Utc>) -> Result<bool, AppError> {
+        let (sql, values) = Query::select()
+            .expr_as(Expr::cust("COUNT(*) > 0"), "exists")
+            .from(PasswordResetTokens::Table)
+            .and_where(Expr::col(PasswordResetTokens::UserId).eq(user_id))
+            .and_where(Expr::col(PasswordResetTokens::CreatedAt).gt(since))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let exists: bool = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("exists");
+
+        Ok(exists)
+    }
+
+    /// Uses up the token and sets the new password, lifting any reset an admin
+    /// required. All of the user's other reset tokens and sessions end with
+    /// it. Returns `None` when the token is unknown, used or expired.
+    pub async fn reset_password(
+        &self,
+        token_hash: &str,
+        password_hash: PasswordHash,
+    ) -> Result<Option<UserId>, AppError> {
+        let mut tx = self.database.pool().begin().await?;
+
+        let (sql, values) = Query::update()
+            .table(PasswordResetTokens::Table)
+            .value(PasswordResetTokens::UsedAt, Expr::current_timestamp())
+            .and_where(Expr::col(PasswordResetTokens::TokenHash).eq(token_hash))
+            .and_where(Expr::col(PasswordResetTokens::UsedAt).is_null())
+            .and_where(Expr::col(PasswordResetTokens::ExpiresAt).gt(Expr::current_timestamp()))
+            .returning_col(PasswordResetTokens::UserId)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let Some(row) = sqlx::query_with(&sql, values)
+            .fetch_optional(&mut *tx)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let user_id: UserId = row.get("user_id");
+
+        let (sql, values) = Query::update()
+            .table(PasswordResetTokens::Table)
+            .value(PasswordResetTokens::UsedAt, Expr::current_timestamp())
+            .and_where(Expr::col(PasswordResetTokens::UserId).eq(user_id))
+            .and_where(Expr::col(PasswordResetTokens::UsedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(Users::PasswordHash, password_hash)
+            .value(Users::PasswordResetRequired, false)
+            .value(Users::UpdatedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Users::Id).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = revoke_all_sessions_statement(user_id).build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(Some(user_id))
+    }
+}
@@ -0,0 +1,232 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::report::{Report, ReportedContent};
+use crate::model::report::ReportAction;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_report_params::InsertReportParams;
+use crate::persistence::schema::{Articles, Comments, Reports, Users};
+use crate::persistence::session_repository::revoke_all_sessions_statement;
+use crate::persistence::user_repository::suspend_user_statement;
+use anyhow::Result;
+use sea_query::{Alias, Expr, Order, PostgresQueryBuilder, Query, SelectStatement, SimpleExpr};
+use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+
+/// Name of the unique index allowing one open report per reader and target.
+const REPORTS_OPEN_REPORTER_KEY: &str = "reports_open_reporter_key";
+
+fn is_duplicate_report(error: &sqlx::Error) -> bool {
+    matches!(error, sqlx::Error::Database(e) if e.constraint() == Some(REPORTS_OPEN_REPORTER_KEY))
+}
+
+/// Open reports about the article itself, or about one of its comments when
+/// `comment_id` is given.
+fn open_reports_on(article_id: ArticleId, comment_id: Option<CommentId>) -> SimpleExpr {
+    let target = Expr::col((Reports::Table, Reports::ArticleId)).eq(article_id);
+    let target = match comment_id {
+        Some(comment_id) => target.and(Expr::col((Reports::Table, Reports::CommentId)).eq(comment_id)),
+        None => target.and(Expr::col((Reports::Table, Reports::CommentId)).is_null()),
+    };
+
+    target.and(Expr::col((Reports::Table, Reports::ResolvedAt)).is_null())
+}
+
+/// Open reports grouped per target, with the content they point at.
+fn reported_content_query() -> SelectStatement {
+    Query::select()
+        .column((Articles::Table, Articles::Slug))
+        .column((Articles::Table, Articles::Title))
+        .column((Reports::Table, Reports::CommentId))
+        .expr_as(
+            Expr::col((Comments::Table, Comments::Body)),
+            Alias::new("comment_body"),
+        )
+        .expr_as(
+            Expr::col((Users::Table, Users::Username)),
+            Alias::new("author_username"),
+        )
+        .expr_as(Expr::cust("COUNT(*)"), Alias::new("reports_count"))
+        .expr_as(
+            Expr::cust("ARRAY_AGG(DISTINCT reports.reason ORDER BY reports.reason)"),
+            Alias::new("reasons"),
+        )
+        .expr_as(
+            Expr::cust("MIN(reports.created_at)"),
+            Alias::new("first_reported_at"),
+        )
+        .expr_as(
+            Expr::cust("MAX(reports.created_at)"),
+            Alias::new("last_reported_at"),
+        )
+        .expr_as(
+            Expr::cust("CASE WHEN reports.comment_id IS NULL THEN articles.hidden_at ELSE comments.hidden_at END IS NOT NULL"),
+            Alias::new("hidden"),
+        )
+        .from(Reports::Table)
+        .inner_join(
+            Articles::Table,
+            Expr::col((Reports::Table, Reports::ArticleId))
+                .equals((Articles::Table, Articles::Id)),
+        )
+        .left_join(
+            Comments::Table,
+            Expr::col((Reports::Table, Reports::CommentId))
+                .equals((Comments::Table, Comments::Id)),
+        )
+        .inner_join(
+            Users::Table,
+            Expr::cust("users.id = COALESCE(comments.author_id, articles.author_id)"),
+        )
+        .and_where(Expr::col((Reports::Table, Reports::ResolvedAt)).is_null())
+        .group_by_col((Reports::Table, Reports::ArticleId))
+        .group_by_col((Reports::Table, Reports::CommentId))
+        .group_by_col((Articles::Table, Articles::Id))
+        .group_by_col((Comments::Table, Comments::Id))
+        .group_by_col((Users::Table, Users::Id))
+        .to_owned()
+}
+
+#[derive(Clone)]
+pub struct ReportRepository {
+    database: Database,
+}
+
+impl ReportRepository {
+    pub fn new(database: Database) -> Self {
+        ReportRepository { database }
+    }
+
+    /// Returns `None` when the reporter already has an open report on the
+    /// same target.
+    pub async fn insert_report(&self, params: InsertReportParams) -> Result<Option<Report>, AppError> {
+        let (sql, values) = Query::insert()
+            .into_table(Reports::Table)
+            .columns([
+                Reports::ArticleId,
+                Reports::CommentId,
+                Reports::ReporterId,
+                Reports::Reason,
+                Reports::Details,
+            ])
+            .values_panic([
+                params.article_id.into(),
+                params.comment_id.map(|id| id.value()).into(),
+                params.reporter_id.into(),
+                params.reason.into(),
+                params.details.map(String::from).into(),
+            ])
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        match sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await
+        {
+            Ok(row) => Ok(Some(Report::from_row(row))),
+            Err(e) if is_duplicate_report(&e) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Most reported content first; ties go to whatever was reported earliest.
+    pub async fn get_reported_content(
+        &self,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<Vec<ReportedContent>, AppError> {
+        let (sql, values) = reported_content_query()
+            .order_by(Alias::new("reports_count"), Order::Desc)
+            .order_by(Alias::new("first_reported_at"), Order::Asc)
+            .limit(limit.unwrap_or_default().value())
+            .offset(offset.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(ReportedContent::from_row).collect())
+    }
+
+    pub async fn count_reported_content(&self) -> Result<u64, AppError> {
+        let (sql, values) = Query::select()
+            .expr_as(Expr::cust("COUNT(*)"), "count")
+            .from_subquery(reported_content_query(), Alias::new("r"))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let count: i64 = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("count");
+
+        Ok(count as u64)
+    }
+
+    /// Closes every open report on the target with `action`, hiding the
+    /// content and suspending its author `author_id` when the action calls for
+    /// it, all or nothing. Returns `false` without changing anything if no
+    /// reports were open, as when another moderator just resolved them.
+    pub async fn resolve_reports(
+        &self,
+        article_id: ArticleId,
+        comment_id: Option<CommentId>,
+        author_id: UserId,
+        resolved_by: UserId,
+        action: ReportAction,
+    ) -> Result<bool, AppError> {
+        let mut tx = self.database.pool().begin().await?;
+
+        // Claims the reports first: a concurrent resolve waits here and then
+        // finds none open.
+        let (sql, values) = Query::update()
+            .table(Reports::Table)
+            .value(Reports::ResolvedAt, Expr::current_timestamp())
+            .value(Reports::ResolvedBy, resolved_by)
+            .value(Reports::Resolution, action)
+            .and_where(open_reports_on(article_id, comment_id))
+            .build_sqlx(PostgresQueryBuilder);
+        if sqlx::query_with(&sql, values)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected()
+            == 0
+        {
+            return Ok(false);
+        }
+
+        if action.hides_content() {
+            let (sql, values) = match comment_id {
+                Some(comment_id) => Query::update()
+                    .table(Comments::Table)
+                    .value(Comments::HiddenAt, Expr::current_timestamp())
+                    .and_where(Expr::col(Comments::Id).eq(comment_id))
+                    .and_where(Expr::col(Comments::HiddenAt).is_null())
+                    .build_sqlx(PostgresQueryBuilder),
+                None => Query::update()
+                    .table(Articles::Table)
+                    .value(Articles::HiddenAt, Expr::current_timestamp())
+                    .and_where(Expr::col(Articles::Id).eq(article_id))
+                    .and_where(Expr::col(Articles::HiddenAt).is_null())
+                    .build_sqlx(PostgresQueryBuilder),
+            };
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+        }
+
+        if action == ReportAction::SuspendAuthor {
+            let (sql, values) = suspend_user_statement(author_id, Some("Reported content".to_string()))
+                .build_sqlx(PostgresQueryBuilder);
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+            let (sql, values) = revoke_all_sessions_statement(author_id).build_sqlx(PostgresQueryBuilder);
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+}
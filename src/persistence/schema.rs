@@ -12,6 +12,11 @@ pub enum Users {
     Image,
     CreatedAt,
     UpdatedAt,
+    Role,
+    SuspendedAt,
+    SuspensionReason,
+    PasswordResetRequired,
+    EmailVerifiedAt,
 }
 
 #[allow(dead_code)]
@@ -26,6 +31,33 @@ pub enum Articles {
     AuthorId,
     CreatedAt,
     UpdatedAt,
+    Status,
+    PublishAt,
+    HiddenAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum ArticleRevisions {
+    Table,
+    Id,
+    ArticleId,
+    Number,
+    AuthorId,
+    Title,
+    Description,
+    Body,
+    ChangedFields,
+    CreatedAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum ArticleSlugHistory {
+    Table,
+    Slug,
+    ArticleId,
+    CreatedAt,
 }
 
 #[derive(Iden)]
@@ -55,6 +87,38 @@ pub enum Comments {
     AuthorId,
     CreatedAt,
     UpdatedAt,
+    ParentId,
+    Depth,
+    DeletedAt,
+    EditedAt,
+    HiddenAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum Reports {
+    Table,
+    Id,
+    ArticleId,
+    CommentId,
+    ReporterId,
+    Reason,
+    Details,
+    CreatedAt,
+    ResolvedAt,
+    ResolvedBy,
+    Resolution,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum CommentRevisions {
+    Table,
+    Id,
+    CommentId,
+    Body,
+    CreatedAt,
+    ReplacedAt,
 }
 
 #[allow(dead_code)]
@@ -107,3 +171,106 @@ pub enum CommentView {
     AuthorImage,
     Following,
 }
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum SqlxMigrations {
+    #[iden(rename = "_sqlx_migrations")]
+    Table,
+    Version,
+    Checksum,
+    Success,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum UserSessions {
+    Table,
+    Id,
+    UserId,
+    RefreshTokenHash,
+    PreviousRefreshTokenHash,
+    CreatedAt,
+    RefreshedAt,
+    ExpiresAt,
+    RevokedAt,
+}
+
+#[derive(Iden)]
+pub enum RevokedTokens {
+    Table,
+    Jti,
+    ExpiresAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum PasswordResetTokens {
+    Table,
+    Id,
+    UserId,
+    TokenHash,
+    CreatedAt,
+    ExpiresAt,
+    UsedAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum UserTotp {
+    Table,
+    UserId,
+    SecretEncrypted,
+    CreatedAt,
+    ConfirmedAt,
+    LastUsedStep,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum TotpRecoveryCodes {
+    Table,
+    Id,
+    UserId,
+    CodeHash,
+    UsedAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum PersonalAccessTokens {
+    Table,
+    Id,
+    UserId,
+    Name,
+    TokenHash,
+    Scopes,
+    CreatedAt,
+    ExpiresAt,
+    LastUsedAt,
+    RevokedAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum UserIdentities {
+    Table,
+    Id,
+    UserId,
+    Provider,
+    Subject,
+    Email,
+    CreatedAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum OidcLogins {
+    Table,
+    StateHash,
+    Provider,
+    CodeVerifier,
+    Nonce,
+    UserId,
+    ExpiresAt,
+}
@@ -0,0 +1,181 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::persistence::session::Session;
+use crate::model::values::session_id::SessionId;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_session_params::InsertSessionParams;
+use crate::persistence::schema::{RevokedTokens, UserSessions};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sea_query::{Expr, OnConflict, PostgresQueryBuilder, Query, UpdateStatement};
+use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Ends every active session of `user_id`, so its tokens stop working.
+pub(crate) fn revoke_all_sessions_statement(user_id: UserId) -> UpdateStatement {
+    Query::update()
+        .table(UserSessions::Table)
+        .value(UserSessions::RevokedAt, Expr::current_timestamp())
+        .and_where(Expr::col(UserSessions::UserId).eq(user_id))
+        .and_where(Expr::col(UserSessions::RevokedAt).is_null())
+        .to_owned()
+}
+
+#[derive(Clone)]
+pub struct SessionRepository {
+    database: Database,
+}
+
+impl SessionRepository {
+    pub fn new(database: Database) -> Self {
+        SessionRepository { database }
+    }
+
+    pub async fn insert_session(&self, params: InsertSessionParams) -> Result<Session, AppError> {
+        let (sql, values) = Query::insert()
+            .into_table(UserSessions::Table)
+            .columns([
+                UserSessions::UserId,
+                UserSessions::RefreshTokenHash,
+                UserSessions::ExpiresAt,
+            ])
+            .values_panic([
+                params.user_id.into(),
+                params.refresh_token_hash.into(),
+                params.expires_at.into(),
+            ])
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?;
+
+        Ok(Session::from_row(row))
+    }
+
+    /// Swaps the refresh token of a live session in a single statement, so two
+    /// concurrent refreshes with the same token cannot both succeed.
+    pub async fn rotate_refresh_token(
+        &self,
+        current_hash: &str,
+        new_hash: &str,
+    ) -> Result<Option<Session>, AppError> {
+        let (sql, values) = Query::update()
+            .table(UserSessions::Table)
+            .value(UserSessions::RefreshTokenHash, new_hash)
+            .value(UserSessions::PreviousRefreshTokenHash, current_hash)
+            .value(UserSessions::RefreshedAt, Expr::current_timestamp())
+            .and_where(Expr::col(UserSessions::RefreshTokenHash).eq(current_hash))
+            .and_where(Expr::col(UserSessions::RevokedAt).is_null())
+            .and_where(Expr::col(UserSessions::ExpiresAt).gt(Expr::current_timestamp()))
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(Session::from_row))
+    }
+
+    /// Revokes the session whose refresh token was already rotated away from
+    /// `previous_hash`. Returns whether such a session was found.
+    pub async fn revoke_session_by_previous_hash(&self, previous_hash: &str) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(UserSessions::Table)
+            .value(UserSessions::RevokedAt, Expr::current_timestamp())
+            .and_where(Expr::col(UserSessions::PreviousRefreshTokenHash).eq(previous_hash))
+            .and_where(Expr::col(UserSessions::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn revoke_session(&self, session_id: SessionId) -> Result<(), AppError> {
+        let (sql, values) = Query::update()
+            .table(UserSessions::Table)
+            .value(UserSessions::RevokedAt, Expr::current_timestamp())
+            .and_where(Expr::col(UserSessions::Id).eq(session_id))
+            .and_where(Expr::col(UserSessions::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn revoke_all_sessions(&self, user_id: UserId) -> Result<(), AppError> {
+        let (sql, values) = revoke_all_sessions_statement(user_id).build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Records `jti` as revoked until the token would have expired anyway, and
+    /// drops entries that are past that point.
+    pub async fn revoke_token(&self, jti: Uuid, expires_at: DateTime<Utc>) -> Result<(), AppError> {
+        let (sql, values) = Query::insert()
+            .into_table(RevokedTokens::Table)
+            .columns([RevokedTokens::Jti, RevokedTokens::ExpiresAt])
+            .values_panic([jti.into(), expires_at.into()])
+            .on_conflict(OnConflict::column(RevokedTokens::Jti).do_nothing().to_owned())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(RevokedTokens::Table)
+            .and_where(Expr::col(RevokedTokens::ExpiresAt).lt(Expr::current_timestamp()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    /// A token is revoked if its own `jti` was revoked or its session is no
+    /// longer active.
+    pub async fn is_token_revoked(&self, jti: Uuid, session_id: SessionId) -> Result<bool, AppError> {
+        let revoked_token = Query::select()
+            .expr(Expr::cust("1"))
+            .from(RevokedTokens::Table)
+            .and_where(Expr::col(RevokedTokens::Jti).eq(jti))
+            .to_owned();
+
+        let active_session = Query::select()
+            .expr(Expr::cust("1"))
+            .from(UserSessions::Table)
+            .and_where(Expr::col(UserSessions::Id).eq(session_id))
+            .and_where(Expr::col(UserSessions::RevokedAt).is_null())
+            .to_owned();
+
+        let (sql, values) = Query::select()
+            .expr_as(
+                Expr::exists(revoked_token).or(Expr::exists(active_session).not()),
+                "revoked",
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let revoked: bool = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("revoked");
+
+        Ok(revoked)
+    }
+}
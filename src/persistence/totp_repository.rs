@@ -0,0 +1,180 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::persistence::totp_credential::TotpCredential;
+use crate::model::values::user_id::UserId;
+use crate::persistence::schema::{TotpRecoveryCodes, UserTotp};
+use anyhow::Result;
+use sea_query::{DeleteStatement, Expr, OnConflict, PostgresQueryBuilder, Query, SimpleExpr};
+use sea_query_binder::SqlxBinder;
+
+/// Codes of a step after the last one used; older steps were used already or
+/// have passed.
+fn step_unused(step: i64) -> SimpleExpr {
+    Expr::col(UserTotp::LastUsedStep)
+        .is_null()
+        .or(Expr::col(UserTotp::LastUsedStep).lt(step))
+}
+
+/// Drops the 2FA secret and the recovery codes of `user_id`.
+pub(crate) fn delete_totp_statements(user_id: UserId) -> [DeleteStatement; 2] {
+    [
+        Query::delete()
+            .from_table(TotpRecoveryCodes::Table)
+            .and_where(Expr::col(TotpRecoveryCodes::UserId).eq(user_id))
+            .to_owned(),
+        Query::delete()
+            .from_table(UserTotp::Table)
+            .and_where(Expr::col(UserTotp::UserId).eq(user_id))
+            .to_owned(),
+    ]
+}
+
+#[derive(Clone)]
+pub struct TotpRepository {
+    database: Database,
+}
+
+impl TotpRepository {
+    pub fn new(database: Database) -> Self {
+        TotpRepository { database }
+    }
+
+    pub async fn get_credential(&self, user_id: UserId) -> Result<Option<TotpCredential>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([UserTotp::SecretEncrypted, UserTotp::ConfirmedAt])
+            .from(UserTotp::Table)
+            .and_where(Expr::col(UserTotp::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(TotpCredential::from_row))
+    }
+
+    /// Stores a secret awaiting confirmation, replacing an unconfirmed one.
+    /// Returns `false`, leaving it alone, when 2FA is already on.
+    pub async fn save_pending_secret(
+        &self,
+        user_id: UserId,
+        secret_encrypted: Vec<u8>,
+    ) -> Result<bool, AppError> {
+        let (sql, values) = Query::insert()
+            .into_table(UserTotp::Table)
+            .columns([UserTotp::UserId, UserTotp::SecretEncrypted])
+            .values_panic([user_id.into(), secret_encrypted.into()])
+            .on_conflict(
+                OnConflict::column(UserTotp::UserId)
+                    .update_column(UserTotp::SecretEncrypted)
+                    .value(UserTotp::CreatedAt, Expr::current_timestamp())
+                    .value(UserTotp::LastUsedStep, Expr::val(None::<i64>))
+                    .action_and_where(Expr::col((UserTotp::Table, UserTotp::ConfirmedAt)).is_null())
+                    .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Turns 2FA on with the code of `step`, replacing any recovery codes.
+    /// Returns `false` if it was on already or the step was used.
+    pub async fn confirm(
+        &self,
+        user_id: UserId,
+        step: i64,
+        recovery_code_hashes: &[String],
+    ) -> Result<bool, AppError> {
+        let mut tx = self.database.pool().begin().await?;
+
+        let (sql, values) = Query::update()
+            .table(UserTotp::Table)
+            .value(UserTotp::ConfirmedAt, Expr::current_timestamp())
+            .value(UserTotp::LastUsedStep, step)
+            .and_where(Expr::col(UserTotp::UserId).eq(user_id))
+            .and_where(Expr::col(UserTotp::ConfirmedAt).is_null())
+            .and_where(step_unused(step))
+            .build_sqlx(PostgresQueryBuilder);
+        if sqlx::query_with(&sql, values)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected()
+            == 0
+        {
+            return Ok(false);
+        }
+
+        let (sql, values) = Query::delete()
+            .from_table(TotpRecoveryCodes::Table)
+            .and_where(Expr::col(TotpRecoveryCodes::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let mut insert = Query::insert();
+        insert
+            .into_table(TotpRecoveryCodes::Table)
+            .columns([TotpRecoveryCodes::UserId, TotpRecoveryCodes::CodeHash]);
+        for code_hash in recovery_code_hashes {
+            insert.values_panic([user_id.into(), code_hash.clone().into()]);
+        }
+        let (sql, values) = insert.build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Records that the code of `step` was used. Returns `false` if it, or a
+    /// later one, was used before.
+    pub async fn use_step(&self, user_id: UserId, step: i64) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(UserTotp::Table)
+            .value(UserTotp::LastUsedStep, step)
+            .and_where(Expr::col(UserTotp::UserId).eq(user_id))
+            .and_where(step_unused(step))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Uses up a recovery code. Returns `false` if there is no such unused
+    /// code.
+    pub async fn use_recovery_code(&self, user_id: UserId, code_hash: &str) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(TotpRecoveryCodes::Table)
+            .value(TotpRecoveryCodes::UsedAt, Expr::current_timestamp())
+            .and_where(Expr::col(TotpRecoveryCodes::UserId).eq(user_id))
+            .and_where(Expr::col(TotpRecoveryCodes::CodeHash).eq(code_hash))
+            .and_where(Expr::col(TotpRecoveryCodes::UsedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Turns 2FA off, dropping the secret and the recovery codes.
+    pub async fn delete(&self, user_id: UserId) -> Result<(), AppError> {
+        let mut tx = self.database.pool().begin().await?;
+
+        for statement in delete_totp_statements(user_id) {
+            let (sql, values) = statement.build_sqlx(PostgresQueryBuilder);
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
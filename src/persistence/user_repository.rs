@@ -1,13 +1,111 @@
 use crate::app_error::AppError;
 use crate::database::Database;
+use crate::domain::policy::{Permission, roles_with};
 use crate::model::indexed_user_field::IndexedUserField;
 use crate::model::persistence::user::User;
+use crate::model::persistence::user_account::UserAccount;
+use crate::model::values::email::Email;
+use crate::model::values::user_id::UserId;
+use crate::model::values::username::Username;
 use crate::persistence::params::insert_user_params::InsertUserParams;
+use crate::persistence::params::list_users_params::ListUsersParams;
 use crate::persistence::params::update_user_params::UpdateUserParams;
 use crate::persistence::schema::Users;
+use crate::persistence::session_repository::revoke_all_sessions_statement;
 use anyhow::Result;
-use sea_query::{Expr, PostgresQueryBuilder, Query};
+use chrono::{DateTime, Utc};
+use sea_query::extension::postgres::PgExpr;
+use sea_query::{
+    Alias, Expr, Order, PostgresQueryBuilder, Query, SelectStatement, SimpleExpr, UpdateStatement,
+};
 use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+
+/// Suspends `user_id`; run with `revoke_all_sessions_statement` to sign them
+/// out as well.
+pub(crate) fn suspend_user_statement(user_id: UserId, reason: Option<String>) -> UpdateStatement {
+    Query::update()
+        .table(Users::Table)
+        .value(Users::SuspendedAt, Expr::current_timestamp())
+        .value(Users::SuspensionReason, reason)
+        .value(Users::UpdatedAt, Expr::current_timestamp())
+        .and_where(Expr::col(Users::Id).eq(user_id))
+        .to_owned()
+}
+
+/// Whether `user_id` currently holds `permission`, for filtering rows by who
+/// is reading them. Reads the role from the database rather than the token.
+pub(crate) fn user_has_permission(user_id: Option<UserId>, permission: Permission) -> SimpleExpr {
+    let Some(user_id) = user_id else {
+        return Expr::cust("FALSE");
+    };
+    let viewer = Alias::new("viewer");
+
+    Expr::exists(
+        Query::select()
+            .expr(Expr::cust("1"))
+            .from_as(Users::Table, viewer.clone())
+            .and_where(Expr::col((viewer.clone(), Users::Id)).eq(user_id))
+            .and_where(Expr::col((viewer, Users::Role)).is_in(roles_with(permission)))
+            .to_owned(),
+    )
+}
+
+fn build_user_account_query(mut where_statement: impl FnMut(&mut SelectStatement)) -> SelectStatement {
+    let mut query = Query::select();
+    query
+        .columns([
+            (Users::Table, Users::Id),
+            (Users::Table, Users::Username),
+            (Users::Table, Users::Email),
+            (Users::Table, Users::Bio),
+            (Users::Table, Users::Image),
+            (Users::Table, Users::Role),
+            (Users::Table, Users::CreatedAt),
+            (Users::Table, Users::SuspendedAt),
+            (Users::Table, Users::SuspensionReason),
+            (Users::Table, Users::PasswordResetRequired),
+        ])
+        .expr_as(
+            Expr::cust("(SELECT COUNT(*) FROM articles WHERE articles.author_id = users.id)"),
+            Alias::new("articles_count"),
+        )
+        .expr_as(
+            Expr::cust("(SELECT COUNT(*) FROM comments WHERE comments.author_id = users.id AND comments.deleted_at IS NULL)"),
+            Alias::new("comments_count"),
+        )
+        .expr_as(
+            Expr::cust("(SELECT COUNT(*) FROM user_follows WHERE user_follows.followee_id = users.id)"),
+            Alias::new("followers_count"),
+        )
+        .from(Users::Table);
+
+    where_statement(&mut query);
+
+    query
+}
+
+fn user_list_where_statement(params: &ListUsersParams, query: &mut SelectStatement) {
+    if let Some(search) = &params.search {
+        let escaped = search.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+        let pattern = format!("%{escaped}%");
+        query.and_where(
+            Expr::col((Users::Table, Users::Username))
+                .ilike(&pattern)
+                .or(Expr::col((Users::Table, Users::Email)).ilike(&pattern)),
+        );
+    }
+
+    match params.suspended {
+        Some(true) => {
+            query.and_where(Expr::col((Users::Table, Users::SuspendedAt)).is_not_null());
+        }
+        Some(false) => {
+            query.and_where(Expr::col((Users::Table, Users::SuspendedAt)).is_null());
+        }
+        None => {}
+    }
+}
 
 #[derive(Clone)]
 pub struct UserRepository {
@@ -51,6 +149,17 @@ impl UserRepository {
         for (column, value) in updates {
             query.value(column, value);
         }
+        // A new address has to be verified again.
+        if let Some(email) = &params.email {
+            query.value(
+                Users::EmailVerifiedAt,
+                Expr::case(
+                    Expr::col(Users::Email).eq(email.clone()),
+                    Expr::col(Users::EmailVerifiedAt),
+                )
+                .finally(Expr::val(None::<DateTime<Utc>>)),
+            );
+        }
 
         let (sql, values) = query
             .and_where(Expr::col(Users::Id).eq(params.user_id))
@@ -81,6 +190,10 @@ impl UserRepository {
             .column(Users::PasswordHash)
             .column(Users::Bio)
             .column(Users::Image)
+            .column(Users::Role)
+            .column(Users::SuspendedAt)
+            .column(Users::PasswordResetRequired)
+            .column(Users::EmailVerifiedAt)
             .from(Users::Table)
             .and_where(Expr::col(field_name).eq(value))
             .build_sqlx(PostgresQueryBuilder);
@@ -91,4 +204,149 @@ impl UserRepository {
 
         Ok(row.map(User::from_row))
     }
+    pub(crate) async fn list_user_accounts(
+        &self,
+        params: ListUsersParams,
+    ) -> Result<Vec<UserAccount>, AppError> {
+        let mut query = build_user_account_query(|q| user_list_where_statement(&params, q));
+
+        let (sql, values) = query
+            .order_by((Users::Table, Users::CreatedAt), Order::Desc)
+            .order_by((Users::Table, Users::Id), Order::Desc)
+            .limit(params.limit.unwrap_or_default().value())
+            .offset(params.offset.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(UserAccount::from_row).collect())
+    }
+
+    pub(crate) async fn count_users(&self, params: ListUsersParams) -> Result<u64, AppError> {
+        let mut query = Query::select();
+        query
+            .expr_as(Expr::cust("COUNT(*)"), "count")
+            .from(Users::Table);
+        user_list_where_statement(&params, &mut query);
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        let count: i64 = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("count");
+
+        Ok(count as u64)
+    }
+
+    pub(crate) async fn get_user_account(
+        &self,
+        username: &Username,
+    ) -> Result<Option<UserAccount>, AppError> {
+        let (sql, values) = build_user_account_query(|q| {
+            q.and_where(Expr::col((Users::Table, Users::Username)).eq(username.clone()));
+        })
+        .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(UserAccount::from_row))
+    }
+
+    /// Suspends the user and ends all of their sessions.
+    pub(crate) async fn suspend_user(
+        &self,
+        user_id: UserId,
+        reason: Option<String>,
+    ) -> Result<(), AppError> {
+        let (sql, values) = suspend_user_statement(user_id, reason).build_sqlx(PostgresQueryBuilder);
+
+        let mut tx = self.database.pool().begin().await?;
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = revoke_all_sessions_statement(user_id).build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Marks the address verified, provided it is still the user's email.
+    /// Returns whether it was.
+    pub(crate) async fn verify_email(&self, user_id: UserId, email: &Email) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(
+                Users::EmailVerifiedAt,
+                Expr::cust("COALESCE(email_verified_at, NOW())"),
+            )
+            .and_where(Expr::col(Users::Id).eq(user_id))
+            .and_where(Expr::col(Users::Email).eq(email.clone()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub(crate) async fn unsuspend_user(&self, user_id: UserId) -> Result<(), AppError> {
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(Users::SuspendedAt, Expr::val(None::<DateTime<Utc>>))
+            .value(Users::SuspensionReason, Expr::val(None::<String>))
+            .value(Users::UpdatedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Users::Id).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Makes the user pick a new password before signing in again, and signs
+    /// them out everywhere.
+    pub(crate) async fn require_password_reset(&self, user_id: UserId) -> Result<(), AppError> {
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(Users::PasswordResetRequired, true)
+            .value(Users::UpdatedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Users::Id).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let mut tx = self.database.pool().begin().await?;
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = revoke_all_sessions_statement(user_id).build_sqlx(PostgresQueryBuilder);
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Removes the user together with their articles, comments, follows and
+    /// sessions.
+    pub(crate) async fn delete_user(&self, user_id: UserId) -> Result<(), AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(Users::Table)
+            .and_where(Expr::col(Users::Id).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
 }
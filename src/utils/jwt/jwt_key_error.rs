@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum JwtKeyError {
+    #[error("Couldn't read JWT keys from {0}: {1}")]
+    Io(String, std::io::Error),
+    #[error("JWT key '{0}' is neither an RSA nor an Ed25519 private key")]
+    UnsupportedKey(String),
+    #[error("Invalid JWT key '{0}': {1}")]
+    InvalidKey(String, String),
+    #[error("JWT_ACTIVE_KEY_ID must name one of the keys in {0}")]
+    MissingActiveKey(String),
+}
@@ -0,0 +1,171 @@
+use crate::utils::jwt::jwt_key_error::JwtKeyError;
+use aws_lc_rs::signature::{Ed25519KeyPair, KeyPair};
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+struct SigningKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    key: EncodingKey,
+}
+
+struct Keys {
+    signing: SigningKey,
+    verifying: HashMap<String, (Algorithm, DecodingKey)>,
+    /// HS256 secret for tokens without a `kid`, only set in shared-secret mode.
+    secret: Option<DecodingKey>,
+    jwks: JwkSet,
+}
+
+/// Signing and verification keys for access tokens.
+///
+/// Either a single HS256 shared secret, or a set of RSA/Ed25519 key pairs
+/// identified by `kid`, one of which signs while the others only verify.
+/// Only asymmetric public keys are ever published through [`JwtKeys::jwks`].
+#[derive(Clone)]
+pub struct JwtKeys(Arc<Keys>);
+
+impl JwtKeys {
+    pub fn from_secret(secret: &str) -> Self {
+        JwtKeys(Arc::new(Keys {
+            signing: SigningKey {
+                kid: None,
+                algorithm: Algorithm::HS256,
+                key: EncodingKey::from_secret(secret.as_bytes()),
+            },
+            verifying: HashMap::new(),
+            secret: Some(DecodingKey::from_secret(secret.as_bytes())),
+            jwks: JwkSet { keys: vec![] },
+        }))
+    }
+
+    /// Loads every `<kid>.pem` private key in `dir`. The key named
+    /// `active_kid` signs new tokens; the rest stay valid for verification
+    /// until they are removed from the directory.
+    pub fn from_dir(dir: &str, active_kid: &str) -> Result<Self, JwtKeyError> {
+        let entries = std::fs::read_dir(dir).map_err(|e| JwtKeyError::Io(dir.to_string(), e))?;
+
+        let mut signing = None;
+        let mut verifying = HashMap::new();
+        let mut jwks = vec![];
+
+        for entry in entries {
+            let path = entry.map_err(|e| JwtKeyError::Io(dir.to_string(), e))?.path();
+            if path.extension().is_none_or(|ext| ext != "pem") {
+                continue;
+            }
+            let Some(kid) = path.file_stem().and_then(|s| s.to_str()).map(str::to_string) else {
+                continue;
+            };
+
+            let pem = std::fs::read(&path)
+                .map_err(|e| JwtKeyError::Io(path.display().to_string(), e))?;
+            let (algorithm, key, jwk) = load_key(&kid, &pem)?;
+
+            let decoding_key = DecodingKey::from_jwk(&jwk)
+                .map_err(|e| JwtKeyError::InvalidKey(kid.clone(), e.to_string()))?;
+            verifying.insert(kid.clone(), (algorithm, decoding_key));
+            jwks.push(jwk);
+
+            if kid == active_kid {
+                signing = Some(SigningKey {
+                    kid: Some(kid),
+                    algorithm,
+                    key,
+                });
+            }
+        }
+
+        let signing = signing.ok_or_else(|| JwtKeyError::MissingActiveKey(dir.to_string()))?;
+        jwks.sort_by(|a, b| a.common.key_id.cmp(&b.common.key_id));
+
+        Ok(JwtKeys(Arc::new(Keys {
+            signing,
+            verifying,
+            secret: None,
+            jwks: JwkSet { keys: jwks },
+        })))
+    }
+
+    pub(crate) fn header(&self) -> Header {
+        let mut header = Header::new(self.0.signing.algorithm);
+        header.kid = self.0.signing.kid.clone();
+        header
+    }
+
+    pub(crate) fn encoding_key(&self) -> &EncodingKey {
+        &self.0.signing.key
+    }
+
+    pub(crate) fn decoding_key(&self, kid: Option<&str>) -> Option<(Algorithm, &DecodingKey)> {
+        match kid {
+            Some(kid) => self
+                .0
+                .verifying
+                .get(kid)
+                .map(|(algorithm, key)| (*algorithm, key)),
+            None => self.0.secret.as_ref().map(|key| (Algorithm::HS256, key)),
+        }
+    }
+
+    pub fn jwks(&self) -> &JwkSet {
+        &self.0.jwks
+    }
+}
+
+fn common_parameters(kid: &str, algorithm: KeyAlgorithm) -> CommonParameters {
+    CommonParameters {
+        public_key_use: Some(PublicKeyUse::Signature),
+        key_algorithm: Some(algorithm),
+        key_id: Some(kid.to_string()),
+        ..Default::default()
+    }
+}
+
+fn pem_to_der(pem: &[u8]) -> Option<Vec<u8>> {
+    let body: String = std::str::from_utf8(pem)
+        .ok()?
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+
+    STANDARD.decode(body.trim()).ok()
+}
+
+fn load_key(kid: &str, pem: &[u8]) -> Result<(Algorithm, EncodingKey, Jwk), JwtKeyError> {
+    let invalid = |e: String| JwtKeyError::InvalidKey(kid.to_string(), e);
+
+    if let Ok(key) = EncodingKey::from_ed_pem(pem) {
+        let der = pem_to_der(pem).ok_or_else(|| invalid("malformed PEM".to_string()))?;
+        let key_pair =
+            Ed25519KeyPair::from_pkcs8_maybe_unchecked(&der).map_err(|e| invalid(e.to_string()))?;
+
+        let jwk = Jwk {
+            common: common_parameters(kid, KeyAlgorithm::EdDSA),
+            algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                key_type: OctetKeyPairType::OctetKeyPair,
+                curve: EllipticCurve::Ed25519,
+                x: URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref()),
+            }),
+        };
+
+        return Ok((Algorithm::EdDSA, key, jwk));
+    }
+
+    if let Ok(key) = EncodingKey::from_rsa_pem(pem) {
+        let mut jwk =
+            Jwk::from_encoding_key(&key, Algorithm::RS256).map_err(|e| invalid(e.to_string()))?;
+        jwk.common = common_parameters(kid, KeyAlgorithm::RS256);
+
+        return Ok((Algorithm::RS256, key, jwk));
+    }
+
+    Err(JwtKeyError::UnsupportedKey(kid.to_string()))
+}
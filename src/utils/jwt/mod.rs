@@ -1,44 +1,172 @@
+pub mod jwt_key_error;
+pub mod jwt_keys;
+
 use crate::app_error::AppError;
+use crate::model::role::Role;
+use crate::model::values::email::Email;
+use crate::model::values::session_id::SessionId;
 use crate::model::values::user_id::UserId;
 use anyhow::Context;
+use crate::utils::jwt::jwt_keys::JwtKeys;
 use chrono::{Duration, Utc};
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::{Validation, decode, decode_header, encode};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
     pub exp: i64,
     pub iat: i64,
+    /// Unique per token, so a single token can be revoked.
+    pub jti: Uuid,
+    /// Session the token was issued for; revoking the session revokes it too.
+    pub sid: SessionId,
+    /// Role of the user when the token was issued. Role changes take effect
+    /// on the next refresh.
+    #[serde(default)]
+    pub role: Role,
 }
 
-pub fn generate_token(secret: String, user_id: UserId) -> Result<String, AppError> {
+pub fn generate_token(
+    keys: &JwtKeys,
+    user_id: UserId,
+    session_id: SessionId,
+    role: Role,
+    ttl: Duration,
+) -> Result<String, AppError> {
     let now = Utc::now();
-    let expiration = now + Duration::days(30);
+    let expiration = now + ttl;
 
     let claims = Claims {
         sub: user_id.to_string(),
         exp: expiration.timestamp(),
         iat: now.timestamp(),
+        jti: Uuid::new_v4(),
+        sid: session_id,
+        role,
     };
 
-    let token = encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(secret.as_bytes()),
-    )
-    .context("Failed to generate JWT Token")?;
+    let token = encode(&keys.header(), &claims, keys.encoding_key())
+        .context("Failed to generate JWT Token")?;
 
     Ok(token)
 }
 
-pub fn verify_token(secret: &String, token: &str) -> Result<Claims, AppError> {
-    let token_data = decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(secret.as_bytes()),
-        &Validation::default(),
-    )
-    .map_err(|_| AppError::Unauthorized)?;
+/// Checks signature and expiry only; revocation is checked by
+/// `AuthService::authenticate`, which needs the database. The `kid` header
+/// picks the verification key, and that key's algorithm is enforced so a token
+/// cannot pick a weaker one.
+pub fn verify_token(keys: &JwtKeys, token: &str) -> Result<Claims, AppError> {
+    let header = decode_header(token).map_err(|_| AppError::Unauthorized)?;
+    let (algorithm, key) = keys
+        .decoding_key(header.kid.as_deref())
+        .ok_or(AppError::Unauthorized)?;
+
+    let token_data = decode::<Claims>(token, key, &Validation::new(algorithm))
+        .map_err(|_| AppError::Unauthorized)?;
 
     Ok(token_data.claims)
-}
\ No newline at end of file
+}
+
+/// Audience of email verification tokens, which keeps them apart from access
+/// tokens signed with the same keys.
+const EMAIL_VERIFICATION_AUDIENCE: &str = "email-verification";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailVerificationClaims {
+    pub sub: String,
+    /// Address being verified. The token is void once the user changes it.
+    pub email: String,
+    pub aud: String,
+    pub exp: i64,
+    pub iat: i64,
+}
+
+pub fn generate_email_verification_token(
+    keys: &JwtKeys,
+    user_id: UserId,
+    email: &Email,
+    ttl: Duration,
+) -> Result<String, AppError> {
+    let now = Utc::now();
+
+    let claims = EmailVerificationClaims {
+        sub: user_id.to_string(),
+        email: email.to_string(),
+        aud: EMAIL_VERIFICATION_AUDIENCE.to_string(),
+        exp: (now + ttl).timestamp(),
+        iat: now.timestamp(),
+    };
+
+    let token = encode(&keys.header(), &claims, keys.encoding_key())
+        .context("Failed to generate email verification token")?;
+
+    Ok(token)
+}
+
+pub fn verify_email_verification_token(
+    keys: &JwtKeys,
+    token: &str,
+) -> Result<EmailVerificationClaims, AppError> {
+    decode_for_audience(keys, token, EMAIL_VERIFICATION_AUDIENCE)
+}
+
+/// Audience of the tokens that stand between the password and the second
+/// factor of a login.
+const LOGIN_CHALLENGE_AUDIENCE: &str = "login-challenge";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoginChallengeClaims {
+    pub sub: String,
+    pub aud: String,
+    pub exp: i64,
+    pub iat: i64,
+}
+
+pub fn generate_login_challenge_token(
+    keys: &JwtKeys,
+    user_id: UserId,
+    ttl: Duration,
+) -> Result<String, AppError> {
+    let now = Utc::now();
+
+    let claims = LoginChallengeClaims {
+        sub: user_id.to_string(),
+        aud: LOGIN_CHALLENGE_AUDIENCE.to_string(),
+        exp: (now + ttl).timestamp(),
+        iat: now.timestamp(),
+    };
+
+    let token = encode(&keys.header(), &claims, keys.encoding_key())
+        .context("Failed to generate login challenge token")?;
+
+    Ok(token)
+}
+
+pub fn verify_login_challenge_token(
+    keys: &JwtKeys,
+    token: &str,
+) -> Result<LoginChallengeClaims, AppError> {
+    decode_for_audience(keys, token, LOGIN_CHALLENGE_AUDIENCE)
+}
+
+/// Like `verify_token`, for tokens meant for `audience` only.
+fn decode_for_audience<T: DeserializeOwned>(
+    keys: &JwtKeys,
+    token: &str,
+    audience: &str,
+) -> Result<T, AppError> {
+    let header = decode_header(token).map_err(|_| AppError::Unauthorized)?;
+    let (algorithm, key) = keys
+        .decoding_key(header.kid.as_deref())
+        .ok_or(AppError::Unauthorized)?;
+
+    let mut validation = Validation::new(algorithm);
+    validation.set_audience(&[audience]);
+
+    let token_data = decode::<T>(token, key, &validation).map_err(|_| AppError::Unauthorized)?;
+
+    Ok(token_data.claims)
+}
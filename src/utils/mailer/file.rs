@@ -0,0 +1,42 @@
+use crate::utils::mailer::{MailFuture, MailMessage, Mailer};
+use anyhow::Context;
+use chrono::Utc;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Saves each message as a text file in `dir`, named so that they sort in the
+/// order they were sent.
+pub struct FileMailer {
+    dir: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        FileMailer { dir: dir.into() }
+    }
+}
+
+impl Mailer for FileMailer {
+    fn send<'a>(&'a self, message: &'a MailMessage) -> MailFuture<'a> {
+        Box::pin(async move {
+            tokio::fs::create_dir_all(&self.dir)
+                .await
+                .with_context(|| format!("Failed to create mail directory {:?}", self.dir))?;
+
+            let name = format!(
+                "{}-{}.txt",
+                Utc::now().format("%Y%m%dT%H%M%S%.6f"),
+                Uuid::new_v4()
+            );
+            let contents = format!(
+                "To: {}\nSubject: {}\n\n{}\n",
+                message.to, message.subject, message.body
+            );
+            tokio::fs::write(self.dir.join(name), contents)
+                .await
+                .context("Failed to write mail file")?;
+
+            Ok(())
+        })
+    }
+}
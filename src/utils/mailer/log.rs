@@ -0,0 +1,17 @@
+use crate::utils::mailer::{MailFuture, MailMessage, Mailer};
+use tracing::info;
+
+/// Writes messages to the log instead of sending them.
+pub struct LogMailer;
+
+impl Mailer for LogMailer {
+    fn send<'a>(&'a self, message: &'a MailMessage) -> MailFuture<'a> {
+        Box::pin(async move {
+            info!(
+                "Mail to {}: {}\n{}",
+                message.to, message.subject, message.body
+            );
+            Ok(())
+        })
+    }
+}
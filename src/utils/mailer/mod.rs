@@ -0,0 +1,23 @@
+pub mod file;
+pub mod log;
+pub mod smtp;
+
+use crate::model::values::email::Email;
+use std::future::Future;
+use std::pin::Pin;
+
+pub type MailFuture<'a> = Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'a>>;
+
+/// A plain text email to a single recipient.
+#[derive(Debug, Clone)]
+pub struct MailMessage {
+    pub to: Email,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Delivers email. SMTP is for production; the file and log mailers keep
+/// messages local so development and tests need no mail server.
+pub trait Mailer: Send + Sync {
+    fn send<'a>(&'a self, message: &'a MailMessage) -> MailFuture<'a>;
+}
@@ -0,0 +1,61 @@
+use crate::utils::mailer::{MailFuture, MailMessage, Mailer};
+use anyhow::Context;
+use lettre::message::Mailbox;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+/// Sends messages through an SMTP relay.
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    /// Connects lazily, on the first message. Without `starttls` the
+    /// connection is unencrypted, which only suits a relay on the same host.
+    pub fn new(
+        host: &str,
+        port: u16,
+        starttls: bool,
+        credentials: Option<(String, String)>,
+        from: &str,
+    ) -> anyhow::Result<Self> {
+        let builder = if starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .context("Invalid SMTP host")?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        };
+        let builder = match credentials {
+            Some((username, password)) => builder.credentials(Credentials::new(username, password)),
+            None => builder,
+        };
+
+        Ok(SmtpMailer {
+            transport: builder.port(port).build(),
+            from: from.parse().context("Invalid sender address")?,
+        })
+    }
+}
+
+impl Mailer for SmtpMailer {
+    fn send<'a>(&'a self, message: &'a MailMessage) -> MailFuture<'a> {
+        Box::pin(async move {
+            let email = Message::builder()
+                .from(self.from.clone())
+                .to(message.to.to_string().parse().context("Invalid recipient address")?)
+                .subject(message.subject.clone())
+                .header(ContentType::TEXT_PLAIN)
+                .body(message.body.clone())
+                .context("Failed to build email")?;
+
+            self.transport
+                .send(email)
+                .await
+                .context("Failed to send email")?;
+
+            Ok(())
+        })
+    }
+}
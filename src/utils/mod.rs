@@ -1,2 +1,8 @@
 pub mod hasher;
 pub mod jwt;
+pub mod mailer;
+pub mod oidc;
+pub mod opaque_token;
+pub mod rate_limit;
+pub mod secret_cipher;
+pub mod totp;
@@ -0,0 +1,186 @@
+//! The client side of the OpenID Connect authorization code flow with PKCE
+//! (RFC 7636): discovery, the authorization URL, the code exchange and ID
+//! token verification.
+
+pub mod oidc_error;
+
+use crate::utils::oidc::oidc_error::OidcError;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
+use reqwest::Url;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+/// The parts of a provider's discovery document the flow needs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdTokenClaims {
+    /// The provider's stable id for the account.
+    pub sub: String,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: bool,
+    pub preferred_username: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+/// A client registered with a provider.
+pub struct ClientCredentials<'a> {
+    pub client_id: &'a str,
+    pub client_secret: &'a str,
+    pub redirect_uri: &'a str,
+}
+
+/// The S256 `code_challenge` for a PKCE `code_verifier`.
+pub fn code_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// Where to send the user to sign in at the provider.
+pub fn authorization_url(
+    metadata: &ProviderMetadata,
+    client: &ClientCredentials,
+    state: &str,
+    nonce: &str,
+    code_verifier: &str,
+) -> Result<String, OidcError> {
+    let url = Url::parse_with_params(
+        &metadata.authorization_endpoint,
+        [
+            ("response_type", "code"),
+            ("client_id", client.client_id),
+            ("redirect_uri", client.redirect_uri),
+            ("scope", "openid email profile"),
+            ("state", state),
+            ("nonce", nonce),
+            ("code_challenge", &code_challenge(code_verifier)),
+            ("code_challenge_method", "S256"),
+        ],
+    )
+    .map_err(|e| OidcError::InvalidMetadata(e.to_string()))?;
+
+    Ok(url.into())
+}
+
+#[derive(Clone)]
+pub struct OidcClient {
+    http: reqwest::Client,
+}
+
+impl OidcClient {
+    pub fn new(timeout: Duration) -> Result<Self, OidcError> {
+        let http = reqwest::Client::builder().timeout(timeout).build()?;
+        Ok(OidcClient { http })
+    }
+
+    pub async fn discover(&self, discovery_url: &str) -> Result<ProviderMetadata, OidcError> {
+        let metadata = self
+            .http
+            .get(discovery_url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(metadata)
+    }
+
+    /// Redeems an authorization code for the ID token.
+    pub async fn exchange_code(
+        &self,
+        metadata: &ProviderMetadata,
+        client: &ClientCredentials<'_>,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<String, OidcError> {
+        let response = self
+            .http
+            .post(&metadata.token_endpoint)
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", client.redirect_uri),
+                ("client_id", client.client_id),
+                ("client_secret", client.client_secret),
+                ("code_verifier", code_verifier),
+            ])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(OidcError::CodeRejected(format!("{status}: {body}")));
+        }
+
+        let tokens: TokenResponse = response.json().await?;
+        Ok(tokens.id_token)
+    }
+
+    /// Checks the signature against the provider's published keys, and the
+    /// issuer, audience, expiry and nonce. Symmetric algorithms are refused:
+    /// the keys have to come from the provider's JWKS.
+    pub async fn verify_id_token(
+        &self,
+        metadata: &ProviderMetadata,
+        client_id: &str,
+        id_token: &str,
+        nonce: &str,
+    ) -> Result<IdTokenClaims, OidcError> {
+        let invalid = |e: String| OidcError::InvalidIdToken(e);
+
+        let header = decode_header(id_token).map_err(|e| invalid(e.to_string()))?;
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            return Err(invalid(format!("unsupported algorithm {:?}", header.alg)));
+        }
+
+        let jwks: JwkSet = self
+            .http
+            .get(&metadata.jwks_uri)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let jwk = match header.kid.as_deref() {
+            Some(kid) => jwks.find(kid),
+            None if jwks.keys.len() == 1 => jwks.keys.first(),
+            None => None,
+        }
+        .ok_or_else(|| invalid("unknown signing key".to_string()))?;
+        let key = DecodingKey::from_jwk(jwk).map_err(|e| invalid(e.to_string()))?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[client_id]);
+
+        let claims = decode::<IdTokenClaims>(id_token, &key, &validation)
+            .map_err(|e| invalid(e.to_string()))?
+            .claims;
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(invalid("nonce does not match".to_string()));
+        }
+
+        Ok(claims)
+    }
+}
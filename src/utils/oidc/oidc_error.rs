@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum OidcError {
+    #[error("Request to the identity provider failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Invalid provider metadata: {0}")]
+    InvalidMetadata(String),
+    #[error("Identity provider rejected the authorization code: {0}")]
+    CodeRejected(String),
+    #[error("Invalid ID token: {0}")]
+    InvalidIdToken(String),
+}
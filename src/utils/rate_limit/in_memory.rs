@@ -0,0 +1,233 @@
+use crate::utils::rate_limit::{BackendFuture, LockoutPolicy, Quota, RateLimitBackend, TokenBucket};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Above this many keys, state that no longer limits anyone is dropped.
+const MAX_TRACKED_KEYS: usize = 100_000;
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+    limits: TokenBucket,
+}
+
+impl Bucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limits.refill_per_sec())
+            .min(f64::from(self.limits.capacity));
+        self.updated_at = now;
+    }
+
+    fn is_full(&self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens + elapsed * self.limits.refill_per_sec() >= f64::from(self.limits.capacity)
+    }
+}
+
+#[derive(Default)]
+struct Failures {
+    count: u32,
+    lockouts: u32,
+    locked_until: Option<Instant>,
+    /// Past this point the failures no longer count.
+    forget_at: Option<Instant>,
+}
+
+impl Failures {
+    fn is_forgotten(&self, now: Instant) -> bool {
+        self.forget_at.is_none_or(|forget_at| forget_at <= now)
+    }
+}
+
+/// Keeps rate limit state in this process.
+#[derive(Default)]
+pub struct InMemoryBackend {
+    buckets: Mutex<HashMap<String, Bucket>>,
+    failures: Mutex<HashMap<String, Failures>>,
+}
+
+impl InMemoryBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn acquire_at(&self, key: &str, limits: TokenBucket, now: Instant) -> Result<Quota, Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() > MAX_TRACKED_KEYS {
+            buckets.retain(|_, bucket| !bucket.is_full(now));
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert_with(|| Bucket {
+            tokens: f64::from(limits.capacity),
+            updated_at: now,
+            limits,
+        });
+        bucket.limits = limits;
+        bucket.refill(now);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(Quota {
+                remaining: bucket.tokens as u32,
+                reset_after: limits.time_to_refill(f64::from(limits.capacity) - bucket.tokens),
+            })
+        } else {
+            Err(limits.time_to_refill(1.0 - bucket.tokens))
+        }
+    }
+
+    fn locked_for_at(&self, key: &str, now: Instant) -> Option<Duration> {
+        let failures = self.failures.lock().unwrap();
+
+        failures
+            .get(key)
+            .and_then(|failures| failures.locked_until)
+            .filter(|locked_until| *locked_until > now)
+            .map(|locked_until| locked_until - now)
+    }
+
+    fn record_failure_at(&self, key: &str, policy: LockoutPolicy, now: Instant) -> Option<Duration> {
+        let mut all_failures = self.failures.lock().unwrap();
+        if all_failures.len() > MAX_TRACKED_KEYS {
+            all_failures.retain(|_, failures| !failures.is_forgotten(now));
+        }
+
+        let failures = all_failures.entry(key.to_string()).or_default();
+        if failures.is_forgotten(now) {
+            *failures = Failures::default();
+        }
+
+        failures.count += 1;
+        failures.forget_at = Some(now + policy.max);
+
+        if failures.count < policy.threshold {
+            return None;
+        }
+
+        let lockout = policy.duration(failures.lockouts);
+        failures.count = 0;
+        failures.lockouts += 1;
+        failures.locked_until = Some(now + lockout);
+        failures.forget_at = Some(now + lockout + policy.max);
+
+        Some(lockout)
+    }
+}
+
+impl RateLimitBackend for InMemoryBackend {
+    fn acquire<'a>(&'a self, key: &'a str, bucket: TokenBucket)
+    -> BackendFuture<'a, Result<Quota, Duration>> {
+        Box::pin(async move { self.acquire_at(key, bucket, Instant::now()) })
+    }
+
+    fn locked_for<'a>(&'a self, key: &'a str) -> BackendFuture<'a, Option<Duration>> {
+        Box::pin(async move { self.locked_for_at(key, Instant::now()) })
+    }
+
+    fn record_failure<'a>(
+        &'a self,
+        key: &'a str,
+        policy: LockoutPolicy,
+    ) -> BackendFuture<'a, Option<Duration>> {
+        Box::pin(async move { self.record_failure_at(key, policy, Instant::now()) })
+    }
+
+    fn reset_failures<'a>(&'a self, key: &'a str) -> BackendFuture<'a, ()> {
+        Box::pin(async move {
+            self.failures.lock().unwrap().remove(key);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUCKET: TokenBucket = TokenBucket {
+        capacity: 2,
+        refill_per_minute: 6,
+    };
+
+    const POLICY: LockoutPolicy = LockoutPolicy {
+        threshold: 3,
+        base: Duration::from_secs(30),
+        max: Duration::from_secs(100),
+    };
+
+    #[test]
+    fn test_bucket_allows_burst_then_refills() {
+        let backend = InMemoryBackend::new();
+        let start = Instant::now();
+
+        assert_eq!(
+            backend.acquire_at("ip", BUCKET, start),
+            Ok(Quota {
+                remaining: 1,
+                reset_after: Duration::from_secs(10)
+            })
+        );
+        assert_eq!(
+            backend.acquire_at("ip", BUCKET, start),
+            Ok(Quota {
+                remaining: 0,
+                reset_after: Duration::from_secs(20)
+            })
+        );
+        assert_eq!(
+            backend.acquire_at("ip", BUCKET, start),
+            Err(Duration::from_secs(10))
+        );
+        assert!(backend.acquire_at("other", BUCKET, start).is_ok());
+
+        assert!(backend.acquire_at("ip", BUCKET, start + Duration::from_secs(10)).is_ok());
+        assert!(backend.acquire_at("ip", BUCKET, start + Duration::from_secs(10)).is_err());
+    }
+
+    #[test]
+    fn test_lockout_doubles_up_to_max() {
+        assert_eq!(POLICY.duration(0), Duration::from_secs(30));
+        assert_eq!(POLICY.duration(1), Duration::from_secs(60));
+        assert_eq!(POLICY.duration(2), Duration::from_secs(100));
+        assert_eq!(POLICY.duration(40), Duration::from_secs(100));
+    }
+
+    #[test]
+    fn test_failures_lock_key_out() {
+        let backend = InMemoryBackend::new();
+        let start = Instant::now();
+
+        assert_eq!(backend.record_failure_at("email", POLICY, start), None);
+        assert_eq!(backend.record_failure_at("email", POLICY, start), None);
+        assert_eq!(
+            backend.record_failure_at("email", POLICY, start),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            backend.locked_for_at("email", start + Duration::from_secs(10)),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(backend.locked_for_at("email", start + Duration::from_secs(30)), None);
+
+        let later = start + Duration::from_secs(30);
+        backend.record_failure_at("email", POLICY, later);
+        backend.record_failure_at("email", POLICY, later);
+        assert_eq!(
+            backend.record_failure_at("email", POLICY, later),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn test_failures_are_forgotten_after_max() {
+        let backend = InMemoryBackend::new();
+        let start = Instant::now();
+
+        backend.record_failure_at("email", POLICY, start);
+        backend.record_failure_at("email", POLICY, start);
+
+        let later = start + POLICY.max;
+        assert_eq!(backend.record_failure_at("email", POLICY, later), None);
+    }
+}
@@ -0,0 +1,81 @@
+pub mod in_memory;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+pub type BackendFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Allows bursts of up to `capacity` requests, then `refill_per_minute`
+/// requests spread evenly over each minute.
+#[derive(Debug, Clone, Copy)]
+pub struct TokenBucket {
+    pub capacity: u32,
+    pub refill_per_minute: u32,
+}
+
+impl TokenBucket {
+    fn refill_per_sec(&self) -> f64 {
+        f64::from(self.refill_per_minute) / 60.0
+    }
+
+    /// Time for `tokens` to come back, or `Duration::MAX` if they never do.
+    fn time_to_refill(&self, tokens: f64) -> Duration {
+        if tokens <= 0.0 {
+            Duration::ZERO
+        } else if self.refill_per_minute == 0 {
+            Duration::MAX
+        } else {
+            Duration::from_secs_f64(tokens / self.refill_per_sec())
+        }
+    }
+}
+
+/// What is left of a bucket after a token was taken from it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quota {
+    /// Whole tokens still in the bucket.
+    pub remaining: u32,
+    /// Time until the bucket is full again.
+    pub reset_after: Duration,
+}
+
+/// Locks a key out after `threshold` failures in a row. The first lockout
+/// lasts `base`, and each one after it twice as long, up to `max`.
+#[derive(Debug, Clone, Copy)]
+pub struct LockoutPolicy {
+    pub threshold: u32,
+    pub base: Duration,
+    pub max: Duration,
+}
+
+impl LockoutPolicy {
+    /// Length of the lockout following `previous` earlier ones.
+    pub fn duration(&self, previous: u32) -> Duration {
+        2u32.checked_pow(previous)
+            .and_then(|factor| self.base.checked_mul(factor))
+            .map_or(self.max, |duration| duration.min(self.max))
+    }
+}
+
+/// Where rate limit state lives. The in-memory backend suits a single
+/// instance; instances behind a load balancer need a shared one.
+pub trait RateLimitBackend: Send + Sync {
+    /// Takes a token from `key`'s bucket, or says how long until one is free.
+    fn acquire<'a>(&'a self, key: &'a str, bucket: TokenBucket)
+    -> BackendFuture<'a, Result<Quota, Duration>>;
+
+    /// How much longer `key` is locked out, if it is.
+    fn locked_for<'a>(&'a self, key: &'a str) -> BackendFuture<'a, Option<Duration>>;
+
+    /// Counts a failure against `key` and returns the lockout it triggered, if
+    /// any.
+    fn record_failure<'a>(
+        &'a self,
+        key: &'a str,
+        policy: LockoutPolicy,
+    ) -> BackendFuture<'a, Option<Duration>>;
+
+    /// Forgets earlier failures of `key`, including past lockouts.
+    fn reset_failures<'a>(&'a self, key: &'a str) -> BackendFuture<'a, ()>;
+}
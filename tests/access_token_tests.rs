@@ -0,0 +1,207 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use tower::ServiceExt;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn test_app() -> axum::Router {
+    let config = common::create_test_config().await;
+    router(create_app_state(&config).await)
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Creates a token and returns its id and value.
+async fn create_token(app: axum::Router, session: &str, scopes: &[&str]) -> (String, String) {
+    let payload = json!({ "accessToken": { "name": "release bot", "scopes": scopes } });
+    let (status, body) = send(
+        app,
+        "POST",
+        "/api/user/tokens",
+        Some(session),
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    (
+        body["accessToken"]["id"].as_str().unwrap().to_string(),
+        body["accessToken"]["token"].as_str().unwrap().to_string(),
+    )
+}
+
+async fn create_article(app: axum::Router, token: &str, title: &str) -> StatusCode {
+    let payload = json!({
+        "article": { "title": title, "description": "Notes", "body": "What changed" }
+    });
+    send(app, "POST", "/api/articles", Some(token), Some(payload))
+        .await
+        .0
+}
+
+#[tokio::test]
+async fn token_is_shown_once_and_authenticates() {
+    let app = test_app().await;
+    let session = register_user(app.clone(), "alice").await;
+    let (id, token) = create_token(app.clone(), &session, &["articles:write"]).await;
+    assert!(token.starts_with("pat_"));
+
+    let (status, body) = send(app.clone(), "GET", "/api/user", Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alice");
+
+    let (status, body) = send(app, "GET", "/api/user/tokens", Some(&session), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let tokens = body["accessTokens"].as_array().unwrap();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0]["id"], id.as_str());
+    assert_eq!(tokens[0]["name"], "release bot");
+    assert_eq!(tokens[0]["scopes"], json!(["articles:write"]));
+    assert!(tokens[0]["lastUsedAt"].is_string());
+    assert!(tokens[0].get("token").is_none());
+}
+
+#[tokio::test]
+async fn scopes_limit_what_a_token_can_change() {
+    let app = test_app().await;
+    let session = register_user(app.clone(), "alice").await;
+    let (_, writer) = create_token(app.clone(), &session, &["articles:write"]).await;
+    let (_, reader) = create_token(app.clone(), &session, &[]).await;
+
+    assert_eq!(
+        create_article(app.clone(), &writer, "Release 1.0").await,
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        create_article(app.clone(), &reader, "Release 1.1").await,
+        StatusCode::FORBIDDEN
+    );
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/articles/release-1-0/comments",
+        Some(&writer),
+        Some(json!({ "comment": { "body": "Shipped" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(
+        body["errors"]["body"][0],
+        "Token is missing the comments:write scope"
+    );
+
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        "/api/articles/release-1-0",
+        Some(&reader),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Tokens cannot mint more tokens or change the account.
+    let payload = json!({ "accessToken": { "name": "another", "scopes": ["comments:write"] } });
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/user/tokens",
+        Some(&writer),
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let payload = json!({ "user": { "bio": "bot" } });
+    let (status, _) = send(app, "PUT", "/api/user", Some(&writer), Some(payload)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn revoked_tokens_stop_working() {
+    let app = test_app().await;
+    let alice = register_user(app.clone(), "alice").await;
+    let bob = register_user(app.clone(), "bob").await;
+    let (id, token) = create_token(app.clone(), &alice, &["articles:write"]).await;
+
+    let uri = format!("/api/user/tokens/{id}");
+    let (status, _) = send(app.clone(), "DELETE", &uri, Some(&bob), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(app.clone(), "DELETE", &uri, Some(&alice), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app.clone(), "GET", "/api/user", Some(&token), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (_, body) = send(app, "GET", "/api/user/tokens", Some(&alice), None).await;
+    assert!(body["accessTokens"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn invalid_tokens_are_rejected() {
+    let app = test_app().await;
+    let session = register_user(app.clone(), "alice").await;
+
+    for payload in [
+        json!({ "accessToken": { "name": "  " } }),
+        json!({ "accessToken": { "name": "bot", "expiresInDays": 0 } }),
+        json!({ "accessToken": { "name": "bot", "scopes": ["admin"] } }),
+    ] {
+        let (status, _) = send(
+            app.clone(),
+            "POST",
+            "/api/user/tokens",
+            Some(&session),
+            Some(payload),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let (status, _) = send(app, "GET", "/api/user", Some("pat_unknown"), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,193 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use shining_clouds::app_config::AppConfig;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use tower::ServiceExt;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn test_app() -> (AppConfig, axum::Router) {
+    let config = common::create_test_config().await;
+    let app = router(create_app_state(&config).await);
+    (config, app)
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+async fn login(app: axum::Router, username: &str) -> (StatusCode, serde_json::Value) {
+    let payload = json!({
+        "user": { "email": format!("{username}@example.com"), "password": "password123" }
+    });
+    send(app, "POST", "/api/users/login", None, Some(payload)).await
+}
+
+/// Registers an admin and returns their token.
+async fn register_admin(config: &AppConfig, app: axum::Router) -> String {
+    register_user(app.clone(), "admin").await;
+    common::set_user_role(config, "admin", "admin").await;
+
+    let (status, body) = login(app, "admin").await;
+    assert_eq!(status, StatusCode::OK);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_admin_endpoints_require_admin_role() {
+    let (config, app) = test_app().await;
+    let user_token = register_user(app.clone(), "alice").await;
+
+    let (status, _) = send(app.clone(), "GET", "/api/admin/users", Some(&user_token), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    register_user(app.clone(), "mod").await;
+    common::set_user_role(&config, "mod", "moderator").await;
+    let (_, body) = login(app.clone(), "mod").await;
+    let mod_token = body["user"]["token"].as_str().unwrap();
+
+    let (status, _) = send(app.clone(), "POST", "/api/admin/users/alice/suspension", Some(mod_token), Some(json!({}))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(app, "GET", "/api/admin/users", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_list_and_search_users_with_activity_counts() {
+    let (config, app) = test_app().await;
+    let admin_token = register_admin(&config, app.clone()).await;
+    let alice_token = register_user(app.clone(), "alice").await;
+    register_user(app.clone(), "bob").await;
+
+    let payload = json!({
+        "article": { "title": "Hello", "description": "Description", "body": "Body" }
+    });
+    let (status, _) = send(app.clone(), "POST", "/api/articles", Some(&alice_token), Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = send(app.clone(), "GET", "/api/admin/users", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["usersCount"], 3);
+
+    let (status, body) = send(app.clone(), "GET", "/api/admin/users?q=ALI", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["usersCount"], 1);
+    assert_eq!(body["users"][0]["username"], "alice");
+    assert_eq!(body["users"][0]["email"], "alice@example.com");
+    assert_eq!(body["users"][0]["articlesCount"], 1);
+    assert_eq!(body["users"][0]["commentsCount"], 0);
+
+    let (status, body) = send(app, "GET", "/api/admin/users/bob", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "bob");
+    assert_eq!(body["user"]["suspendedAt"], serde_json::Value::Null);
+}
+
+#[tokio::test]
+async fn test_suspended_user_cannot_log_in_or_use_tokens() {
+    let (config, app) = test_app().await;
+    let admin_token = register_admin(&config, app.clone()).await;
+    let alice_token = register_user(app.clone(), "alice").await;
+
+    let payload = json!({ "reason": "spam" });
+    let (status, body) = send(app.clone(), "POST", "/api/admin/users/alice/suspension", Some(&admin_token), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["user"]["suspendedAt"].is_string());
+    assert_eq!(body["user"]["suspensionReason"], "spam");
+
+    let (status, _) = send(app.clone(), "GET", "/api/user", Some(&alice_token), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = login(app.clone(), "alice").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["errors"]["body"][0], "Account is suspended");
+
+    let (status, body) = send(app.clone(), "GET", "/api/admin/users?suspended=true", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["usersCount"], 1);
+
+    let (status, _) = send(app.clone(), "DELETE", "/api/admin/users/alice/suspension", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = login(app, "alice").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_forced_password_reset_blocks_login() {
+    let (config, app) = test_app().await;
+    let admin_token = register_admin(&config, app.clone()).await;
+    let alice_token = register_user(app.clone(), "alice").await;
+
+    let (status, body) = send(app.clone(), "POST", "/api/admin/users/alice/password-reset", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["passwordResetRequired"], true);
+
+    let (status, _) = send(app.clone(), "GET", "/api/user", Some(&alice_token), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, body) = login(app, "alice").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["errors"]["body"][0], "Password reset required before signing in");
+}
+
+#[tokio::test]
+async fn test_delete_user() {
+    let (config, app) = test_app().await;
+    let admin_token = register_admin(&config, app.clone()).await;
+    register_user(app.clone(), "alice").await;
+
+    let (status, _) = send(app.clone(), "DELETE", "/api/admin/users/admin", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(app.clone(), "DELETE", "/api/admin/users/alice", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app.clone(), "GET", "/api/profiles/alice", None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(app, "DELETE", "/api/admin/users/alice", Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
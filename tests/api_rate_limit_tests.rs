@@ -0,0 +1,261 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, Request, StatusCode};
+use serde_json::json;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use std::net::SocketAddr;
+use tower::ServiceExt;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    peer: Option<SocketAddr>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, HeaderMap, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+    let mut request = request.body(body).unwrap();
+    if let Some(peer) = peer {
+        request.extensions_mut().insert(ConnectInfo(peer));
+    }
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        headers,
+        serde_json::from_slice(&body).unwrap_or_default(),
+    )
+}
+
+async fn test_app(vars: &[(&str, &str)]) -> axum::Router {
+    let vars = [&[("API_RATE_LIMIT_ENABLED", "true")], vars].concat();
+    let config = common::create_test_config_with(&vars).await;
+    router(create_app_state(&config).await)
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, _, body) = send(app, "POST", "/api/users", None, None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+fn header(headers: &HeaderMap, name: &str) -> u64 {
+    headers[name].to_str().unwrap().parse().unwrap()
+}
+
+fn peer(ip: &str) -> Option<SocketAddr> {
+    Some(format!("{ip}:40000").parse().unwrap())
+}
+
+#[tokio::test]
+async fn anonymous_reads_are_limited_per_ip() {
+    let app = test_app(&[
+        ("API_ANONYMOUS_READ_BURST", "2"),
+        ("API_ANONYMOUS_READS_PER_MINUTE", "6"),
+    ])
+    .await;
+
+    let (status, headers, _) = send(
+        app.clone(),
+        "GET",
+        "/api/tags",
+        None,
+        peer("203.0.113.1"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(header(&headers, "ratelimit-limit"), 2);
+    assert_eq!(header(&headers, "ratelimit-remaining"), 1);
+    assert_eq!(header(&headers, "ratelimit-reset"), 10);
+
+    let (status, headers, _) = send(
+        app.clone(),
+        "GET",
+        "/api/tags",
+        None,
+        peer("203.0.113.1"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(header(&headers, "ratelimit-remaining"), 0);
+
+    let (status, headers, body) = send(
+        app.clone(),
+        "GET",
+        "/api/tags",
+        None,
+        peer("203.0.113.1"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(header(&headers, "ratelimit-remaining"), 0);
+    assert_eq!(header(&headers, "retry-after"), 10);
+    assert_eq!(
+        body["errors"]["body"][0],
+        "Too many attempts, try again later"
+    );
+
+    let (status, _, _) = send(app, "GET", "/api/tags", None, peer("203.0.113.2"), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn users_are_limited_by_account_with_separate_write_budget() {
+    let app = test_app(&[
+        ("API_USER_WRITE_BURST", "1"),
+        ("API_USER_WRITES_PER_MINUTE", "1"),
+        ("API_ANONYMOUS_READ_BURST", "1"),
+    ])
+    .await;
+    let token = register_user(app.clone(), "alice").await;
+
+    let article = |n: u32| {
+        Some(json!({
+            "article": {
+                "title": format!("Rate limited {n}"),
+                "description": "d",
+                "body": "b",
+                "tagList": []
+            }
+        }))
+    };
+
+    let (status, _, _) = send(
+        app.clone(),
+        "POST",
+        "/api/articles",
+        Some(&token),
+        peer("203.0.113.1"),
+        article(1),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // A different IP does not help a signed-in user.
+    let (status, headers, _) = send(
+        app.clone(),
+        "POST",
+        "/api/articles",
+        Some(&token),
+        peer("203.0.113.2"),
+        article(2),
+    )
+    .await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(header(&headers, "ratelimit-limit"), 1);
+
+    // Reads have their own budget, and users are not held to anonymous limits.
+    for _ in 0..3 {
+        let (status, headers, _) = send(
+            app.clone(),
+            "GET",
+            "/api/user",
+            Some(&token),
+            peer("203.0.113.1"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(header(&headers, "ratelimit-limit"), 120);
+    }
+}
+
+#[tokio::test]
+async fn access_tokens_share_their_owners_budget() {
+    let app = test_app(&[("API_ANONYMOUS_READ_BURST", "1")]).await;
+    let session = register_user(app.clone(), "alice").await;
+
+    let payload = json!({ "accessToken": { "name": "release bot", "scopes": ["articles:write"] } });
+    let (status, _, body) = send(
+        app.clone(),
+        "POST",
+        "/api/user/tokens",
+        Some(&session),
+        peer("203.0.113.1"),
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let token = body["accessToken"]["token"].as_str().unwrap().to_string();
+
+    let mut remaining = 0;
+    for ip in ["203.0.113.2", "203.0.113.3"] {
+        let (status, headers, _) = send(
+            app.clone(),
+            "GET",
+            "/api/user",
+            Some(&token),
+            peer(ip),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(header(&headers, "ratelimit-limit"), 120);
+        remaining = header(&headers, "ratelimit-remaining");
+    }
+
+    let (_, headers, _) = send(
+        app,
+        "GET",
+        "/api/user",
+        Some(&session),
+        peer("203.0.113.4"),
+        None,
+    )
+    .await;
+    assert_eq!(header(&headers, "ratelimit-remaining"), remaining - 1);
+}
+
+#[tokio::test]
+async fn health_and_disabled_limits_send_no_headers() {
+    let app = test_app(&[]).await;
+    let (status, headers, _) = send(
+        app,
+        "GET",
+        "/api/health/live",
+        None,
+        peer("203.0.113.1"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!headers.contains_key("ratelimit-limit"));
+
+    let config = common::create_test_config().await;
+    let app = router(create_app_state(&config).await);
+    let (status, headers, _) = send(app, "GET", "/api/tags", None, peer("203.0.113.1"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!headers.contains_key("ratelimit-limit"));
+}
@@ -0,0 +1,203 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use tower::ServiceExt;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Creates an article and edits it twice, leaving three revisions.
+async fn create_edited_article(app: axum::Router, token: &str) -> String {
+    let payload = json!({
+        "article": {
+            "title": "Draft Title",
+            "description": "First description",
+            "body": "line one\nline two\nline three"
+        }
+    });
+    let (status, body) = send(app.clone(), "POST", "/api/articles", Some(token), Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let slug = body["article"]["slug"].as_str().unwrap().to_string();
+
+    let payload = json!({ "article": { "body": "line one\nline 2\nline three\nline four" } });
+    let (status, _) = send(app.clone(), "PUT", &format!("/api/articles/{slug}"), Some(token), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let payload = json!({ "article": { "title": "Final Title", "description": "First description" } });
+    let (status, body) = send(app, "PUT", &format!("/api/articles/{slug}"), Some(token), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    body["article"]["slug"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_every_edit_records_a_revision() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author").await;
+    let slug = create_edited_article(app.clone(), &token).await;
+
+    let (status, body) = send(app.clone(), "GET", &format!("/api/articles/{slug}/revisions"), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let revisions = body["revisions"].as_array().unwrap();
+    assert_eq!(revisions.len(), 3);
+    assert_eq!(revisions[0]["number"], 3);
+    assert_eq!(revisions[0]["author"], "author");
+    assert_eq!(revisions[0]["changedFields"], json!(["title"]));
+    assert_eq!(revisions[1]["changedFields"], json!(["body"]));
+    assert_eq!(revisions[2]["changedFields"], json!(["title", "description", "body"]));
+
+    let (status, body) = send(app.clone(), "GET", &format!("/api/articles/{slug}/revisions/1"), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["revision"]["title"], "Draft Title");
+    assert_eq!(body["revision"]["body"], "line one\nline two\nline three");
+
+    let (status, _) = send(app, "GET", &format!("/api/articles/{slug}/revisions/9"), None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_diff_between_revisions() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author").await;
+    let slug = create_edited_article(app.clone(), &token).await;
+
+    let (status, body) = send(app.clone(), "GET", &format!("/api/articles/{slug}/revisions/2/diff"), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["from"], 1);
+    assert_eq!(body["to"], 2);
+
+    let fields = body["fields"].as_array().unwrap();
+    assert_eq!(fields[0]["field"], "title");
+    assert_eq!(fields[0]["changed"], false);
+    assert_eq!(fields[2]["field"], "body");
+    assert_eq!(fields[2]["changed"], true);
+    assert_eq!(
+        fields[2]["lines"],
+        json!([
+            { "op": "equal", "text": "line one" },
+            { "op": "delete", "text": "line two" },
+            { "op": "insert", "text": "line 2" },
+            { "op": "equal", "text": "line three" },
+            { "op": "insert", "text": "line four" },
+        ])
+    );
+
+    let (_, body) = send(app, "GET", &format!("/api/articles/{slug}/revisions/3/diff?from=1"), None, None).await;
+    let fields = body["fields"].as_array().unwrap();
+    assert_eq!(fields[0]["changed"], true);
+    assert_eq!(fields[1]["changed"], false);
+    assert_eq!(fields[2]["changed"], true);
+}
+
+#[tokio::test]
+async fn test_author_can_restore_revision() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author").await;
+    let slug = create_edited_article(app.clone(), &token).await;
+
+    let (status, body) = send(app.clone(), "POST", &format!("/api/articles/{slug}/revisions/1/restore"), Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["title"], "Draft Title");
+    assert_eq!(body["article"]["body"], "line one\nline two\nline three");
+
+    let slug = body["article"]["slug"].as_str().unwrap();
+    let (_, body) = send(app, "GET", &format!("/api/articles/{slug}/revisions"), None, None).await;
+    let revisions = body["revisions"].as_array().unwrap();
+    assert_eq!(revisions.len(), 4);
+    assert_eq!(revisions[0]["changedFields"], json!(["title", "body"]));
+}
+
+#[tokio::test]
+async fn test_only_author_can_restore_revision() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author").await;
+    let other = register_user(app.clone(), "other").await;
+    let slug = create_edited_article(app.clone(), &token).await;
+
+    let uri = format!("/api/articles/{slug}/revisions/1/restore");
+    let (status, _) = send(app.clone(), "POST", &uri, Some(&other), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(app, "POST", &uri, None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_concurrent_edits_get_consecutive_revisions() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author").await;
+    let payload = json!({ "article": { "title": "Busy Article", "description": "d", "body": "b" } });
+    let (status, body) = send(app.clone(), "POST", "/api/articles", Some(&token), Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let slug = body["article"]["slug"].as_str().unwrap().to_string();
+
+    // Spawned so the edits actually race on the database.
+    let handles: Vec<_> = (0..4)
+        .map(|n| {
+            let app = app.clone();
+            let token = token.clone();
+            let uri = format!("/api/articles/{slug}");
+            tokio::spawn(async move {
+                let payload = json!({ "article": { "body": format!("edit {n}") } });
+                send(app, "PUT", &uri, Some(&token), Some(payload)).await
+            })
+        })
+        .collect();
+    for handle in handles {
+        let (status, _) = handle.await.unwrap();
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, body) = send(app, "GET", &format!("/api/articles/{slug}/revisions"), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let numbers: Vec<_> = body["revisions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|revision| revision["number"].as_i64().unwrap())
+        .collect();
+    assert_eq!(numbers, [5, 4, 3, 2, 1]);
+}
@@ -0,0 +1,207 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use serde_json::json;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use tower::ServiceExt;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+async fn create_article(
+    app: axum::Router,
+    token: &str,
+    title: &str,
+    publication: serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let mut article = json!({
+        "title": title,
+        "description": "Description",
+        "body": "Body"
+    });
+    article
+        .as_object_mut()
+        .unwrap()
+        .extend(publication.as_object().unwrap().clone());
+
+    send(app, "POST", "/api/articles", Some(token), Some(json!({ "article": article }))).await
+}
+
+fn titles(body: &serde_json::Value) -> Vec<&str> {
+    body["articles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|article| article["title"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_draft_is_only_visible_to_author() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let reader = register_user(app.clone(), "reader").await;
+
+    let (status, body) = create_article(app.clone(), &author, "Secret Plans", json!({ "status": "draft" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["article"]["status"], "draft");
+    let slug = body["article"]["slug"].as_str().unwrap().to_string();
+    create_article(app.clone(), &author, "Public Post", json!({})).await;
+
+    let uri = format!("/api/articles/{slug}");
+    let (status, body) = send(app.clone(), "GET", &uri, Some(&author), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["status"], "draft");
+    assert_eq!(send(app.clone(), "GET", &uri, Some(&reader), None).await.0, StatusCode::NOT_FOUND);
+    assert_eq!(send(app.clone(), "GET", &uri, None, None).await.0, StatusCode::NOT_FOUND);
+    assert_eq!(
+        send(app.clone(), "GET", &format!("{uri}/comments"), None, None).await.0,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        send(app.clone(), "GET", &format!("{uri}/revisions"), Some(&reader), None).await.0,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        send(app.clone(), "POST", &format!("{uri}/favorite"), Some(&reader), None).await.0,
+        StatusCode::NOT_FOUND
+    );
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles", None, None).await;
+    assert_eq!(titles(&body), vec!["Public Post"]);
+    assert_eq!(body["articlesCount"], 1);
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles", Some(&author), None).await;
+    assert_eq!(titles(&body), vec!["Public Post", "Secret Plans"]);
+
+    send(app.clone(), "POST", "/api/profiles/author/follow", Some(&reader), None).await;
+    let (_, body) = send(app, "GET", "/api/articles/feed", Some(&reader), None).await;
+    assert_eq!(titles(&body), vec!["Public Post"]);
+    assert_eq!(body["articlesCount"], 1);
+}
+
+#[tokio::test]
+async fn test_publishing_a_draft_makes_it_visible() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author").await;
+
+    let (_, body) = create_article(app.clone(), &author, "Work In Progress", json!({ "status": "draft" })).await;
+    let uri = format!("/api/articles/{}", body["article"]["slug"].as_str().unwrap());
+
+    let payload = json!({ "article": { "status": "published" } });
+    let (status, body) = send(app.clone(), "PUT", &uri, Some(&author), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["status"], "published");
+
+    let (status, _) = send(app.clone(), "GET", &uri, None, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let payload = json!({ "article": { "status": "archived" } });
+    send(app.clone(), "PUT", &uri, Some(&author), Some(payload)).await;
+    let (status, _) = send(app, "GET", &uri, None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_drafts_endpoint_lists_unpublished_articles_of_current_user() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let other = register_user(app.clone(), "other").await;
+
+    let publish_at = Utc::now() + Duration::days(1);
+    create_article(app.clone(), &author, "Draft", json!({ "status": "draft" })).await;
+    let (status, body) = create_article(app.clone(), &author, "Scheduled", json!({ "publishAt": publish_at })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["article"]["status"], "scheduled");
+    create_article(app.clone(), &author, "Published", json!({})).await;
+    create_article(app.clone(), &other, "Other Draft", json!({ "status": "draft" })).await;
+
+    let (status, body) = send(app.clone(), "GET", "/api/user/drafts", Some(&author), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&body), vec!["Scheduled", "Draft"]);
+    assert_eq!(body["articlesCount"], 2);
+
+    let (status, _) = send(app, "GET", "/api/user/drafts", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_invalid_publish_time_is_rejected() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author").await;
+
+    let past = Utc::now() - Duration::minutes(5);
+    let future = Utc::now() + Duration::hours(1);
+
+    for publication in [
+        json!({ "publishAt": past }),
+        json!({ "status": "scheduled" }),
+        json!({ "status": "draft", "publishAt": future }),
+    ] {
+        let (status, _) = create_article(app.clone(), &author, "Bad Schedule", publication).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[tokio::test]
+async fn test_scheduled_article_is_published_when_due() {
+    let config = common::create_test_config_with(&[("PUBLISH_SCHEDULER_INTERVAL_SECS", "1")]).await;
+    let app = router(create_app_state(&config).await);
+    let author = register_user(app.clone(), "author").await;
+
+    let publish_at = Utc::now() + Duration::seconds(2);
+    let (_, body) = create_article(app.clone(), &author, "Coming Soon", json!({ "publishAt": publish_at })).await;
+    let uri = format!("/api/articles/{}", body["article"]["slug"].as_str().unwrap());
+
+    let (status, _) = send(app.clone(), "GET", &uri, None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    tokio::time::sleep(std::time::Duration::from_secs(4)).await;
+
+    let (status, body) = send(app, "GET", &uri, None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["status"], "published");
+}
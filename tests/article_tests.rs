@@ -290,7 +290,7 @@ async fn test_update_article_by_author() {
 }
 
 #[tokio::test]
-async fn test_update_article_to_existing_slug_fails() {
+async fn test_update_article_to_existing_slug_adds_suffix() {
     let app = common::create_test_app().await;
     let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
 
@@ -357,7 +357,15 @@ async fn test_update_article_to_existing_slug_fails() {
         .await
         .unwrap();
 
-    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let slug = body["article"]["slug"].as_str().unwrap();
+    assert!(slug.starts_with("original-title-1-"), "{slug}");
+    assert_eq!(slug.len(), "original-title-1-".len() + 4);
 }
 
 #[tokio::test]
@@ -1201,3 +1209,410 @@ async fn test_combine_multiple_filters() {
             .contains(&json!("rust"))
     );
 }
+
+async fn get_json(app: axum::Router, uri: &str, token: Option<&str>) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method("GET").uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn create_titled_article(app: axum::Router, token: &str, title: &str) {
+    let article = json!({
+        "article": {
+            "title": title,
+            "description": "Description",
+            "body": "Body",
+            "tagList": []
+        }
+    });
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/articles")
+                .header("content-type", "application/json")
+                .header("authorization", format!("Token {}", token))
+                .body(Body::from(serde_json::to_string(&article).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+fn titles(body: &serde_json::Value) -> Vec<String> {
+    body["articles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_paginate_articles_with_cursor() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    for i in 0..7 {
+        create_titled_article(app.clone(), &token, &format!("Article {}", i)).await;
+    }
+
+    let (status, first) = get_json(app.clone(), "/api/articles?limit=3", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&first), ["Article 6", "Article 5", "Article 4"]);
+    assert_eq!(first["articlesCount"], 7);
+    assert!(first["prevCursor"].is_null());
+
+    // Articles created after the first page must not shift the next one.
+    create_titled_article(app.clone(), &token, "Article 7").await;
+
+    let next = first["nextCursor"].as_str().unwrap();
+    let (_, second) = get_json(app.clone(), &format!("/api/articles?limit=3&cursor={next}"), None).await;
+    assert_eq!(titles(&second), ["Article 3", "Article 2", "Article 1"]);
+
+    let next = second["nextCursor"].as_str().unwrap();
+    let (_, third) = get_json(app.clone(), &format!("/api/articles?limit=3&cursor={next}"), None).await;
+    assert_eq!(titles(&third), ["Article 0"]);
+    assert!(third["nextCursor"].is_null());
+
+    let prev = third["prevCursor"].as_str().unwrap();
+    let (_, back) = get_json(app.clone(), &format!("/api/articles?limit=3&cursor={prev}"), None).await;
+    assert_eq!(titles(&back), ["Article 3", "Article 2", "Article 1"]);
+
+    let prev = back["prevCursor"].as_str().unwrap();
+    let (_, newest) = get_json(app.clone(), &format!("/api/articles?limit=3&cursor={prev}"), None).await;
+    assert_eq!(titles(&newest), ["Article 6", "Article 5", "Article 4"]);
+
+    let prev = newest["prevCursor"].as_str().unwrap();
+    let (_, latest) = get_json(app, &format!("/api/articles?limit=3&cursor={prev}"), None).await;
+    assert_eq!(titles(&latest), ["Article 7"]);
+    assert!(latest["prevCursor"].is_null());
+}
+
+#[tokio::test]
+async fn test_paginate_feed_with_cursor() {
+    let app = common::create_test_app().await;
+    let author_token =
+        register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let follower_token = register_user(
+        app.clone(),
+        "follower",
+        "follower@example.com",
+        "password123",
+    )
+    .await;
+
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/profiles/author/follow")
+                .header("authorization", format!("Token {}", follower_token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    for i in 0..4 {
+        create_titled_article(app.clone(), &author_token, &format!("Feed Article {}", i)).await;
+    }
+
+    let (_, first) = get_json(app.clone(), "/api/articles/feed?limit=2", Some(&follower_token)).await;
+    assert_eq!(titles(&first), ["Feed Article 3", "Feed Article 2"]);
+
+    let next = first["nextCursor"].as_str().unwrap();
+    let (status, second) = get_json(
+        app,
+        &format!("/api/articles/feed?limit=2&cursor={next}"),
+        Some(&follower_token),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&second), ["Feed Article 1", "Feed Article 0"]);
+    assert_eq!(second["articlesCount"], 4);
+    assert!(second["nextCursor"].is_null());
+}
+
+#[tokio::test]
+async fn test_offset_page_links_to_cursor_pages() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    for i in 0..5 {
+        create_titled_article(app.clone(), &token, &format!("Article {}", i)).await;
+    }
+
+    let (_, page) = get_json(app.clone(), "/api/articles?limit=2&offset=2", None).await;
+    assert_eq!(titles(&page), ["Article 2", "Article 1"]);
+
+    let prev = page["prevCursor"].as_str().unwrap();
+    let (_, newer) = get_json(app, &format!("/api/articles?limit=2&cursor={prev}"), None).await;
+    assert_eq!(titles(&newer), ["Article 4", "Article 3"]);
+}
+
+#[tokio::test]
+async fn test_list_articles_with_invalid_cursor_fails() {
+    let app = common::create_test_app().await;
+
+    let (status, _) = get_json(app, "/api/articles?cursor=not-a-cursor", None).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+async fn create_article_with(app: axum::Router, token: &str, title: &str, description: &str, body: &str, tags: &[&str]) {
+    let article = json!({
+        "article": {
+            "title": title,
+            "description": description,
+            "body": body,
+            "tagList": tags
+        }
+    });
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/articles")
+                .header("content-type", "application/json")
+                .header("authorization", format!("Token {}", token))
+                .body(Body::from(serde_json::to_string(&article).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_search_articles_ranks_and_highlights_matches() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    create_article_with(app.clone(), &token, "Gardening basics", "Soil and seeds", "Water your tomatoes daily.", &[]).await;
+    create_article_with(app.clone(), &token, "Borrow checker deep dive", "Ownership in Rust", "The borrow checker enforces ownership rules at compile time.", &[]).await;
+    create_article_with(app.clone(), &token, "Weekly notes", "Misc", "I spent some time fighting the borrow checker again.", &[]).await;
+
+    let (status, body) = get_json(app, "/api/articles/search?q=borrow%20checker", None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["articlesCount"], 2);
+    assert_eq!(titles(&body), ["Borrow checker deep dive", "Weekly notes"]);
+
+    let first = &body["articles"][0];
+    let second = &body["articles"][1];
+    assert!(first["score"].as_f64().unwrap() > second["score"].as_f64().unwrap());
+    assert!(first["snippet"].as_str().unwrap().contains("<mark>borrow</mark>"));
+    assert_eq!(first["slug"], "borrow-checker-deep-dive");
+    assert_eq!(first["author"]["username"], "author");
+}
+
+#[tokio::test]
+async fn test_search_articles_combines_with_tag_and_author_filters() {
+    let app = common::create_test_app().await;
+    let author1_token =
+        register_user(app.clone(), "author1", "author1@example.com", "password123").await;
+    let author2_token =
+        register_user(app.clone(), "author2", "author2@example.com", "password123").await;
+
+    create_article_with(app.clone(), &author1_token, "Async runtimes", "Tokio", "Comparing async executors.", &["rust"]).await;
+    create_article_with(app.clone(), &author1_token, "Async in Python", "asyncio", "An async event loop tour.", &["python"]).await;
+    create_article_with(app.clone(), &author2_token, "Async traits", "Rust", "Async functions in traits.", &["rust"]).await;
+
+    let (_, by_tag) = get_json(app.clone(), "/api/articles/search?q=async&tag=rust", None).await;
+    assert_eq!(by_tag["articlesCount"], 2);
+
+    let (_, by_both) = get_json(app, "/api/articles/search?q=async&tag=rust&author=author1", None).await;
+    assert_eq!(by_both["articlesCount"], 1);
+    assert_eq!(titles(&by_both), ["Async runtimes"]);
+}
+
+#[tokio::test]
+async fn test_search_articles_requires_query() {
+    let app = common::create_test_app().await;
+
+    let (missing, _) = get_json(app.clone(), "/api/articles/search", None).await;
+    let (blank, _) = get_json(app, "/api/articles/search?q=%20", None).await;
+
+    assert_eq!(missing, StatusCode::BAD_REQUEST);
+    assert_eq!(blank, StatusCode::BAD_REQUEST);
+}
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> axum::response::Response {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    app.oneshot(request.body(body).unwrap()).await.unwrap()
+}
+
+async fn rename_article(app: axum::Router, token: &str, slug: &str, title: &str) -> StatusCode {
+    let payload = json!({ "article": { "title": title } });
+    send(app, "PUT", &format!("/api/articles/{slug}"), Some(token), Some(payload))
+        .await
+        .status()
+}
+
+fn location(response: &axum::response::Response) -> &str {
+    response.headers()["location"].to_str().unwrap()
+}
+
+#[tokio::test]
+async fn test_old_slug_redirects_to_current_slug() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    create_titled_article(app.clone(), &token, "First Name").await;
+
+    assert_eq!(rename_article(app.clone(), &token, "first-name", "Second Name").await, StatusCode::OK);
+    assert_eq!(rename_article(app.clone(), &token, "second-name", "Third Name").await, StatusCode::OK);
+
+    let response = send(app.clone(), "GET", "/api/articles/first-name", None, None).await;
+    assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(location(&response), "/api/articles/third-name");
+
+    let response = send(app.clone(), "GET", "/api/articles/second-name/comments", None, None).await;
+    assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(location(&response), "/api/articles/third-name/comments");
+
+    let response = send(app.clone(), "GET", "/api/articles/first-name/revisions/2/diff?from=1", None, None).await;
+    assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(location(&response), "/api/articles/third-name/revisions/2/diff?from=1");
+
+    let (status, body) = get_json(app, "/api/articles/third-name", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["title"], "Third Name");
+}
+
+#[tokio::test]
+async fn test_writes_through_old_slug_reach_current_article() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    create_titled_article(app.clone(), &author, "Old Title").await;
+    rename_article(app.clone(), &author, "old-title", "New Title").await;
+
+    let response = send(app.clone(), "POST", "/api/articles/old-title/favorite", Some(&reader), None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let payload = json!({ "comment": { "body": "Found it" } });
+    let response = send(app.clone(), "POST", "/api/articles/old-title/comments", Some(&reader), Some(payload)).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let (_, body) = get_json(app.clone(), "/api/articles/new-title", Some(&reader)).await;
+    assert_eq!(body["article"]["favorited"], true);
+    assert_eq!(body["article"]["favoritesCount"], 1);
+
+    let (_, body) = get_json(app, "/api/articles/new-title/comments", None).await;
+    assert_eq!(body["comments"][0]["body"], "Found it");
+}
+
+#[tokio::test]
+async fn test_reused_slug_no_longer_redirects() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    create_titled_article(app.clone(), &token, "Popular Title").await;
+    rename_article(app.clone(), &token, "popular-title", "Renamed").await;
+
+    // Another article taking the retired slug wins over the redirect.
+    create_titled_article(app.clone(), &token, "Popular Title").await;
+
+    let (status, body) = get_json(app.clone(), "/api/articles/popular-title", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["description"], "Description");
+
+    // Renaming back to an earlier title reclaims that slug.
+    create_titled_article(app.clone(), &token, "Alpha").await;
+    rename_article(app.clone(), &token, "alpha", "Beta").await;
+    assert_eq!(rename_article(app.clone(), &token, "beta", "Alpha").await, StatusCode::OK);
+
+    let (status, _) = get_json(app.clone(), "/api/articles/alpha", None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let response = send(app, "GET", "/api/articles/beta", None, None).await;
+    assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(location(&response), "/api/articles/alpha");
+}
+
+#[tokio::test]
+async fn test_same_title_gets_unique_slug() {
+    let app = common::create_test_app().await;
+    let first = register_user(app.clone(), "first", "first@example.com", "password123").await;
+    let second = register_user(app.clone(), "second", "second@example.com", "password123").await;
+
+    let payload = json!({ "article": { "title": "Hello World", "description": "d", "body": "b" } });
+    let response = send(app.clone(), "POST", "/api/articles", Some(&first), Some(payload.clone())).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = send(app.clone(), "POST", "/api/articles", Some(&second), Some(payload)).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let (_, body) = get_json(app, "/api/articles?author=second", None).await;
+    let slug = body["articles"][0]["slug"].as_str().unwrap();
+    assert_ne!(slug, "hello-world");
+    assert!(slug.starts_with("hello-world-"), "{slug}");
+}
+
+#[tokio::test]
+async fn test_concurrent_articles_with_same_title_get_distinct_slugs() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    // Spawned so the inserts actually race on the database.
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let app = app.clone();
+            let token = token.clone();
+            tokio::spawn(async move {
+                let payload = json!({ "article": { "title": "Breaking News", "description": "d", "body": "b" } });
+                send(app, "POST", "/api/articles", Some(&token), Some(payload)).await
+            })
+        })
+        .collect();
+
+    let mut slugs = vec![];
+    for handle in handles {
+        let response = handle.await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        slugs.push(body["article"]["slug"].as_str().unwrap().to_string());
+    }
+
+    slugs.sort();
+    slugs.dedup();
+    assert_eq!(slugs.len(), 4);
+    assert!(slugs.contains(&"breaking-news".to_string()));
+}
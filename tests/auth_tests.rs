@@ -215,3 +215,234 @@ async fn test_login_with_nonexistent_email_fails() {
     // Then
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
+
+async fn send_json(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn register_session(app: axum::Router) -> (String, String) {
+    let payload = json!({
+        "user": {
+            "username": "sessionuser",
+            "email": "session@example.com",
+            "password": "sessionpass123"
+        }
+    });
+
+    let (status, body) = send_json(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    (
+        body["user"]["token"].as_str().unwrap().to_string(),
+        body["user"]["refreshToken"].as_str().unwrap().to_string(),
+    )
+}
+
+async fn login_session(app: axum::Router) -> (String, String) {
+    let payload = json!({
+        "user": {
+            "email": "session@example.com",
+            "password": "sessionpass123"
+        }
+    });
+
+    let (status, body) = send_json(app, "POST", "/api/users/login", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    (
+        body["user"]["token"].as_str().unwrap().to_string(),
+        body["user"]["refreshToken"].as_str().unwrap().to_string(),
+    )
+}
+
+async fn refresh(app: axum::Router, refresh_token: &str) -> (StatusCode, serde_json::Value) {
+    send_json(
+        app,
+        "POST",
+        "/api/users/token/refresh",
+        None,
+        Some(json!({ "refreshToken": refresh_token })),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_refresh_token_rotates_and_issues_new_access_token() {
+    // Given
+    let app = common::create_test_app().await;
+    let (_, refresh_token) = register_session(app.clone()).await;
+
+    // When
+    let (status, body) = refresh(app.clone(), &refresh_token).await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK);
+    let access_token = body["token"].as_str().unwrap();
+    let rotated = body["refreshToken"].as_str().unwrap();
+    assert_ne!(rotated, refresh_token);
+
+    let (status, body) = send_json(app.clone(), "GET", "/api/user", Some(access_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "sessionuser");
+
+    let (status, _) = refresh(app, rotated).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_reused_refresh_token_revokes_session() {
+    // Given
+    let app = common::create_test_app().await;
+    let (_, refresh_token) = register_session(app.clone()).await;
+    let (_, body) = refresh(app.clone(), &refresh_token).await;
+    let access_token = body["token"].as_str().unwrap().to_string();
+    let rotated = body["refreshToken"].as_str().unwrap().to_string();
+
+    // When
+    let (status, _) = refresh(app.clone(), &refresh_token).await;
+
+    // Then
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = refresh(app.clone(), &rotated).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = send_json(app, "GET", "/api/user", Some(&access_token), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_refresh_with_unknown_token_fails() {
+    // Given
+    let app = common::create_test_app().await;
+
+    // When
+    let (status, _) = refresh(app, "not-a-refresh-token").await;
+
+    // Then
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_logout_revokes_current_session_only() {
+    // Given
+    let app = common::create_test_app().await;
+    let (first_access, first_refresh) = register_session(app.clone()).await;
+    let (second_access, _) = login_session(app.clone()).await;
+
+    // When
+    let (status, _) = send_json(app.clone(), "POST", "/api/users/logout", Some(&first_access), None).await;
+
+    // Then
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send_json(app.clone(), "GET", "/api/user", Some(&first_access), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = refresh(app.clone(), &first_refresh).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = send_json(app, "GET", "/api/user", Some(&second_access), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_logout_all_revokes_every_session() {
+    // Given
+    let app = common::create_test_app().await;
+    let (first_access, _) = register_session(app.clone()).await;
+    let (second_access, second_refresh) = login_session(app.clone()).await;
+
+    // When
+    let (status, _) = send_json(
+        app.clone(),
+        "POST",
+        "/api/users/logout?all=true",
+        Some(&first_access),
+        None,
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send_json(app.clone(), "GET", "/api/user", Some(&second_access), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = refresh(app, &second_refresh).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+async fn get_current_user(app: axum::Router, authorization: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .uri("/api/user")
+        .header("authorization", authorization)
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+#[tokio::test]
+async fn test_bearer_scheme_is_accepted() {
+    // Given
+    let app = common::create_test_app().await;
+    let (access_token, _) = register_session(app.clone()).await;
+
+    // When
+    let (status, body) = get_current_user(app.clone(), &format!("Bearer {access_token}")).await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "sessionuser");
+
+    let (status, _) = get_current_user(app, &format!("bearer {access_token}")).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_rejected_credentials_get_json_errors() {
+    // Given
+    let app = common::create_test_app().await;
+    let (access_token, _) = register_session(app.clone()).await;
+
+    // When
+    let (status, body) = get_current_user(app.clone(), &format!("Basic {access_token}")).await;
+
+    // Then
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["errors"]["body"][0], "Unauthorized");
+
+    let (status, body) = send_json(app, "GET", "/api/user", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["errors"]["body"][0], "Unauthorized");
+}
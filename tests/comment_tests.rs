@@ -357,3 +357,282 @@ async fn test_delete_comment_without_authentication_fails() {
 
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
+
+async fn post_comment(
+    app: axum::Router,
+    token: &str,
+    slug: &str,
+    body: &str,
+    parent_id: Option<&str>,
+) -> (StatusCode, serde_json::Value) {
+    let payload = json!({
+        "comment": {
+            "body": body,
+            "parentId": parent_id
+        }
+    });
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/articles/{}/comments", slug))
+                .header("content-type", "application/json")
+                .header("authorization", format!("Token {}", token))
+                .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+async fn get_comments(app: axum::Router, slug: &str) -> serde_json::Value {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/articles/{}/comments", slug))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+async fn delete_comment(app: axum::Router, token: &str, slug: &str, comment_id: &str) -> StatusCode {
+    app.oneshot(
+        Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/articles/{}/comments/{}", slug, comment_id))
+            .header("authorization", format!("Token {}", token))
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await
+    .unwrap()
+    .status()
+}
+
+#[tokio::test]
+async fn test_replies_are_nested_under_parent() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+    let slug = create_article(app.clone(), &token, "Threaded Article").await;
+
+    let (_, root) = post_comment(app.clone(), &token, &slug, "Root", None).await;
+    let root_id = root["comment"]["id"].as_str().unwrap();
+
+    let (status, first) = post_comment(app.clone(), &token, &slug, "First reply", Some(root_id)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(first["comment"]["parentId"], root_id);
+    assert_eq!(first["comment"]["depth"], 1);
+
+    let first_id = first["comment"]["id"].as_str().unwrap();
+    post_comment(app.clone(), &token, &slug, "Second reply", Some(root_id)).await;
+    post_comment(app.clone(), &token, &slug, "Nested reply", Some(first_id)).await;
+    post_comment(app.clone(), &token, &slug, "Other root", None).await;
+
+    let body = get_comments(app, &slug).await;
+    let comments = body["comments"].as_array().unwrap();
+
+    assert_eq!(comments.len(), 2);
+    assert_eq!(comments[0]["body"], "Other root");
+    assert_eq!(comments[1]["body"], "Root");
+
+    let replies = comments[1]["replies"].as_array().unwrap();
+    assert_eq!(replies.len(), 2);
+    assert_eq!(replies[0]["body"], "First reply");
+    assert_eq!(replies[1]["body"], "Second reply");
+    assert_eq!(replies[0]["replies"][0]["body"], "Nested reply");
+    assert_eq!(replies[0]["replies"][0]["depth"], 2);
+}
+
+#[tokio::test]
+async fn test_reply_to_comment_on_other_article_fails() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+    let slug = create_article(app.clone(), &token, "First Article").await;
+    let other_slug = create_article(app.clone(), &token, "Second Article").await;
+
+    let (_, root) = post_comment(app.clone(), &token, &other_slug, "Elsewhere", None).await;
+    let root_id = root["comment"]["id"].as_str().unwrap();
+
+    let (status, _) = post_comment(app.clone(), &token, &slug, "Reply", Some(root_id)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = post_comment(
+        app,
+        &token,
+        &slug,
+        "Reply",
+        Some("00000000-0000-0000-0000-000000000000"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_deleting_comment_with_replies_leaves_placeholder() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+    let slug = create_article(app.clone(), &token, "Placeholder Article").await;
+
+    let (_, root) = post_comment(app.clone(), &token, &slug, "Root", None).await;
+    let root_id = root["comment"]["id"].as_str().unwrap();
+    let (_, reply) = post_comment(app.clone(), &token, &slug, "Reply", Some(root_id)).await;
+    let reply_id = reply["comment"]["id"].as_str().unwrap();
+
+    let status = delete_comment(app.clone(), &token, &slug, root_id).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let body = get_comments(app.clone(), &slug).await;
+    let root = &body["comments"][0];
+    assert_eq!(root["deleted"], true);
+    assert_eq!(root["body"], "[deleted]");
+    assert!(root["author"].is_null());
+    assert_eq!(root["replies"][0]["body"], "Reply");
+
+    let (status, _) = post_comment(app.clone(), &token, &slug, "Too late", Some(root_id)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Removing the last reply also removes the now empty placeholder.
+    let status = delete_comment(app.clone(), &token, &slug, reply_id).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let body = get_comments(app, &slug).await;
+    assert!(body["comments"].as_array().unwrap().is_empty());
+}
+
+async fn put_comment(
+    app: axum::Router,
+    token: &str,
+    slug: &str,
+    comment_id: &str,
+    body: &str,
+) -> (StatusCode, serde_json::Value) {
+    let payload = json!({ "comment": { "body": body } });
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(format!("/api/articles/{}/comments/{}", slug, comment_id))
+                .header("content-type", "application/json")
+                .header("authorization", format!("Token {}", token))
+                .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn get_revisions(
+    app: axum::Router,
+    token: &str,
+    slug: &str,
+    comment_id: &str,
+) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/articles/{}/comments/{}/revisions", slug, comment_id))
+                .header("authorization", format!("Token {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+#[tokio::test]
+async fn test_edit_comment_records_revisions() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+    let slug = create_article(app.clone(), &token, "Edited Article").await;
+
+    let (_, created) = post_comment(app.clone(), &token, &slug, "First draft", None).await;
+    assert_eq!(created["comment"]["edited"], false);
+    let comment_id = created["comment"]["id"].as_str().unwrap();
+
+    let (status, updated) = put_comment(app.clone(), &token, &slug, comment_id, "Second draft").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(updated["comment"]["body"], "Second draft");
+    assert_eq!(updated["comment"]["edited"], true);
+    assert_ne!(updated["comment"]["updatedAt"], created["comment"]["updatedAt"]);
+
+    put_comment(app.clone(), &token, &slug, comment_id, "Final").await;
+
+    let body = get_comments(app.clone(), &slug).await;
+    assert_eq!(body["comments"][0]["body"], "Final");
+    assert_eq!(body["comments"][0]["edited"], true);
+
+    let (status, body) = get_revisions(app, &token, &slug, comment_id).await;
+    assert_eq!(status, StatusCode::OK);
+    let revisions = body["revisions"].as_array().unwrap();
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0]["body"], "First draft");
+    assert_eq!(revisions[1]["body"], "Second draft");
+}
+
+#[tokio::test]
+async fn test_edit_comment_validates_body() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+    let slug = create_article(app.clone(), &token, "Validated Article").await;
+
+    let (_, created) = post_comment(app.clone(), &token, &slug, "Original", None).await;
+    let comment_id = created["comment"]["id"].as_str().unwrap();
+
+    let (status, _) = put_comment(app.clone(), &token, &slug, comment_id, "   ").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (_, body) = get_revisions(app, &token, &slug, comment_id).await;
+    assert!(body["revisions"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_only_author_can_edit_comment_or_see_revisions() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let other = register_user(app.clone(), "other", "other@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Guarded Article").await;
+
+    let (_, created) = post_comment(app.clone(), &author, &slug, "Mine", None).await;
+    let comment_id = created["comment"]["id"].as_str().unwrap();
+
+    let (status, _) = put_comment(app.clone(), &other, &slug, comment_id, "Hijacked").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = get_revisions(app.clone(), &other, &slug, comment_id).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let other_slug = create_article(app.clone(), &author, "Another Article").await;
+    let (status, _) = put_comment(app, &author, &other_slug, comment_id, "Moved").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
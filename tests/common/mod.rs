@@ -2,6 +2,7 @@ use axum::Router;
 use rand::Rng;
 use shining_clouds::app_config::AppConfig;
 use shining_clouds::application::create_app_state;
+use shining_clouds::database::connect_db;
 use shining_clouds::http::router;
 use sqlx::postgres::PgPoolOptions;
 use std::sync::Once;
@@ -19,7 +20,11 @@ pub fn init_tracing() {
     });
 }
 
-pub async fn create_test_app() -> Router {
+pub async fn create_test_config() -> AppConfig {
+    create_test_config_with(&[]).await
+}
+
+pub async fn create_test_config_with(vars: &[(&str, &str)]) -> AppConfig {
     let db = TestDatabase::new("bluesky".to_string(), "password".to_string())
         .await
         .unwrap();
@@ -27,8 +32,20 @@ pub async fn create_test_app() -> Router {
     let mut env_overrides = EnvOverrides::init();
 
     env_overrides.set("DATABASE_NAME", &db.name);
+    // Most tests fire requests faster than any client should, and publish
+    // without verifying an email first; the tests about either turn the
+    // checks back on.
+    env_overrides.set("API_RATE_LIMIT_ENABLED", "false");
+    env_overrides.set("AUTH_EMAIL_VERIFICATION_REQUIRED", "false");
+    for (key, value) in vars {
+        env_overrides.set(key, value);
+    }
+
+    AppConfig::load().unwrap()
+}
 
-    let config = AppConfig::load().unwrap();
+pub async fn create_test_app() -> Router {
+    let config = create_test_config().await;
 
     let app_state = create_app_state(&config).await;
 
@@ -37,6 +54,20 @@ pub async fn create_test_app() -> Router {
     router(app_state)
 }
 
+/// Gives `username` a role directly in the database; it shows up in tokens
+/// issued afterwards.
+#[allow(dead_code)]
+pub async fn set_user_role(config: &AppConfig, username: &str, role: &str) {
+    let db = connect_db(&config.database).await.unwrap();
+
+    sqlx::query("UPDATE users SET role = $1 WHERE username = $2")
+        .bind(role)
+        .bind(username)
+        .execute(db.pool())
+        .await
+        .unwrap();
+}
+
 struct TestDatabase {
     name: String,
 }
@@ -20,3 +20,74 @@ async fn test_docs_endpoint_returns_ok() {
 
     assert_eq!(response.status(), StatusCode::OK);
 }
+
+#[tokio::test]
+async fn test_openapi_spec_describes_routes_and_constraints() {
+    let app = common::create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/openapi.json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert!(spec["openapi"].as_str().unwrap().starts_with("3.1"));
+
+    let paths = &spec["paths"];
+    assert!(paths["/api/articles"]["get"].is_object());
+    assert!(paths["/api/articles"]["post"].is_object());
+    assert!(paths["/api/articles/search"]["get"].is_object());
+    assert!(paths["/api/articles/{slug}/revisions/{number}/diff"]["get"].is_object());
+    assert!(paths["/.well-known/jwks.json"]["get"].is_object());
+    assert!(paths["/api/articles/{slug}/comments/{id}"]["delete"].is_object());
+    assert!(paths["/api/articles/{slug}/comments/{id}"]["put"].is_object());
+    assert!(paths["/api/articles/{slug}/comments/{id}/revisions"]["get"].is_object());
+    assert!(paths["/api/profiles/{username}/follow"]["post"].is_object());
+    assert!(paths["/api/users/login"]["post"].is_object());
+    assert!(paths["/api/user/drafts"]["get"].is_object());
+    assert!(paths["/api/admin/users"]["get"].is_object());
+    assert!(paths["/api/admin/users/{username}"]["delete"].is_object());
+    assert!(paths["/api/admin/users/{username}/suspension"]["post"].is_object());
+    assert!(paths["/api/admin/users/{username}/password-reset"]["post"].is_object());
+    assert!(paths["/api/articles/{slug}/report"]["post"].is_object());
+    assert!(paths["/api/articles/{slug}/comments/{id}/report"]["post"].is_object());
+    assert!(paths["/api/admin/reports"]["get"].is_object());
+    assert!(paths["/api/users/login"]["post"]["responses"]["429"].is_object());
+    assert!(paths["/api/users/verify"]["post"].is_object());
+    assert!(paths["/api/users/verify/resend"]["post"].is_object());
+    assert!(paths["/api/users/password/forgot"]["post"].is_object());
+    assert!(paths["/api/users/password/reset"]["post"].is_object());
+    assert!(paths["/api/users/login"]["post"]["responses"]["202"].is_object());
+    assert!(paths["/api/users/login/2fa"]["post"].is_object());
+    assert!(paths["/api/user/2fa"]["post"].is_object());
+    assert!(paths["/api/user/2fa/confirm"]["post"].is_object());
+    assert!(paths["/api/user/2fa/disable"]["post"].is_object());
+    assert!(paths["/api/user/tokens"]["post"].is_object());
+    assert!(paths["/api/user/tokens"]["get"].is_object());
+    assert!(paths["/api/user/tokens/{id}"]["delete"].is_object());
+    assert!(paths["/api/auth/oidc/{provider}/authorize"]["post"].is_object());
+    assert!(paths["/api/auth/oidc/{provider}/callback"]["post"].is_object());
+    assert!(paths["/api/user/identities"]["get"].is_object());
+    assert!(paths["/api/user/identities/{id}"]["delete"].is_object());
+    assert!(paths["/api/admin/reports/articles/{slug}/resolve"]["post"].is_object());
+
+    let schemas = &spec["components"]["schemas"];
+    assert!(schemas["ArticleResponse"].is_object());
+    assert!(schemas["CommentsResponse"].is_object());
+    assert!(schemas["ProfileResponse"].is_object());
+    assert_eq!(schemas["ArticleTitle"]["maxLength"], 255);
+    assert_eq!(schemas["Password"]["minLength"], 8);
+    assert_eq!(schemas["Password"]["maxLength"], 128);
+    assert_eq!(schemas["Username"]["minLength"], 2);
+}
@@ -0,0 +1,275 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use std::path::{Path, PathBuf};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+/// An app that requires verification and saves its mail in a fresh directory.
+async fn test_app() -> (PathBuf, axum::Router) {
+    let mail_dir = std::env::temp_dir().join(format!("mail-{}", Uuid::new_v4()));
+    let config = common::create_test_config_with(&[
+        ("AUTH_EMAIL_VERIFICATION_REQUIRED", "true"),
+        ("MAIL_TRANSPORT", "File"),
+        ("MAIL_FILE_DIR", mail_dir.to_str().unwrap()),
+    ])
+    .await;
+    let app = router(create_app_state(&config).await);
+    (mail_dir, app)
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["user"]["verified"], false);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Mail saved so far, oldest first.
+fn sent_mail(mail_dir: &Path) -> Vec<String> {
+    let mut paths: Vec<_> = std::fs::read_dir(mail_dir)
+        .map(|entries| entries.map(|entry| entry.unwrap().path()).collect())
+        .unwrap_or_default();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| std::fs::read_to_string(path).unwrap())
+        .collect()
+}
+
+fn verification_token(mail: &str) -> String {
+    mail.split("/verify-email?token=")
+        .nth(1)
+        .and_then(|rest| rest.split_whitespace().next())
+        .expect("mail has no verification link")
+        .to_string()
+}
+
+fn article(status: &str) -> serde_json::Value {
+    json!({
+        "article": {
+            "title": format!("Article {}", Uuid::new_v4()),
+            "description": "d",
+            "body": "b",
+            "tagList": [],
+            "status": status
+        }
+    })
+}
+
+#[tokio::test]
+async fn registration_mails_a_token_that_verifies_the_email() {
+    let (mail_dir, app) = test_app().await;
+    let token = register_user(app.clone(), "alice").await;
+
+    let mail = sent_mail(&mail_dir);
+    assert_eq!(mail.len(), 1);
+    assert!(mail[0].contains("To: alice@example.com"));
+    assert!(mail[0].contains("/verify-email?token="));
+
+    let payload = json!({ "token": verification_token(&mail[0]) });
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/users/verify",
+        None,
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, body) = send(app, "GET", "/api/user", Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["verified"], true);
+}
+
+#[tokio::test]
+async fn unverified_users_can_only_write_drafts() {
+    let (mail_dir, app) = test_app().await;
+    let token = register_user(app.clone(), "bob").await;
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/articles",
+        Some(&token),
+        Some(article("published")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["errors"]["body"][0], "Verify your email address first");
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/articles",
+        Some(&token),
+        Some(article("draft")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let slug = body["article"]["slug"].as_str().unwrap().to_string();
+
+    let publish = json!({ "article": { "status": "published" } });
+    let uri = format!("/api/articles/{slug}");
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        &uri,
+        Some(&token),
+        Some(publish.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let payload = json!({ "token": verification_token(&sent_mail(&mail_dir)[0]) });
+    send(
+        app.clone(),
+        "POST",
+        "/api/users/verify",
+        None,
+        Some(payload),
+    )
+    .await;
+
+    let (status, body) = send(app, "PUT", &uri, Some(&token), Some(publish)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["status"], "published");
+}
+
+#[tokio::test]
+async fn invalid_tokens_are_rejected() {
+    let (_mail_dir, app) = test_app().await;
+    let access_token = register_user(app.clone(), "carol").await;
+
+    for token in ["not-a-token", access_token.as_str()] {
+        let payload = json!({ "token": token });
+        let (status, _) = send(
+            app.clone(),
+            "POST",
+            "/api/users/verify",
+            None,
+            Some(payload),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[tokio::test]
+async fn verification_can_be_resent_until_verified() {
+    let (mail_dir, app) = test_app().await;
+    let token = register_user(app.clone(), "dave").await;
+
+    let (status, _) = send(app.clone(), "POST", "/api/users/verify/resend", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/users/verify/resend",
+        Some(&token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+
+    let mail = sent_mail(&mail_dir);
+    assert_eq!(mail.len(), 2);
+
+    let payload = json!({ "token": verification_token(&mail[1]) });
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/users/verify",
+        None,
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app, "POST", "/api/users/verify/resend", Some(&token), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn changing_email_requires_verifying_it_again() {
+    let (mail_dir, app) = test_app().await;
+    let token = register_user(app.clone(), "erin").await;
+    let old_token = verification_token(&sent_mail(&mail_dir)[0]);
+    let payload = json!({ "token": old_token });
+    send(
+        app.clone(),
+        "POST",
+        "/api/users/verify",
+        None,
+        Some(payload),
+    )
+    .await;
+
+    let update = json!({ "user": { "email": "erin.new@example.com" } });
+    let (status, body) = send(app.clone(), "PUT", "/api/user", Some(&token), Some(update)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["verified"], false);
+
+    let mail = sent_mail(&mail_dir);
+    assert_eq!(mail.len(), 2);
+    assert!(mail[1].contains("To: erin.new@example.com"));
+
+    // A token for the old address no longer counts.
+    let payload = json!({ "token": old_token });
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/users/verify",
+        None,
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let payload = json!({ "token": verification_token(&mail[1]) });
+    let (status, _) = send(app, "POST", "/api/users/verify", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
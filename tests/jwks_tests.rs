@@ -0,0 +1,166 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde_json::json;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use tower::ServiceExt;
+
+const KEYS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/jwt_keys");
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+fn token_header(token: &str) -> serde_json::Value {
+    let header = token.split('.').next().unwrap();
+    serde_json::from_slice(&URL_SAFE_NO_PAD.decode(header).unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_jwks_publishes_public_keys_only() {
+    // Given
+    let config = common::create_test_config_with(&[
+        ("JWT_KEYS_DIR", KEYS_DIR),
+        ("JWT_ACTIVE_KEY_ID", "ed-2026"),
+    ])
+    .await;
+    let app = router(create_app_state(&config).await);
+
+    // When
+    let (status, body) = send(app, "GET", "/.well-known/jwks.json", None, None).await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK);
+
+    let keys = body["keys"].as_array().unwrap();
+    assert_eq!(keys.len(), 2);
+    assert_eq!(keys[0]["kid"], "ed-2026");
+    assert_eq!(keys[0]["kty"], "OKP");
+    assert_eq!(keys[0]["crv"], "Ed25519");
+    assert_eq!(keys[0]["alg"], "EdDSA");
+    assert_eq!(keys[1]["kid"], "rs-2025");
+    assert_eq!(keys[1]["kty"], "RSA");
+    assert_eq!(keys[1]["alg"], "RS256");
+    assert!(keys.iter().all(|k| k["use"] == "sig" && k["d"].is_null()));
+}
+
+#[tokio::test]
+async fn test_jwks_is_empty_with_shared_secret() {
+    // Given
+    let app = common::create_test_app().await;
+
+    // When
+    let (status, body) = send(app, "GET", "/.well-known/jwks.json", None, None).await;
+
+    // Then
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["keys"], json!([]));
+}
+
+#[tokio::test]
+async fn test_tokens_signed_by_retired_key_stay_valid_after_rotation() {
+    // Given
+    let config = common::create_test_config_with(&[
+        ("JWT_KEYS_DIR", KEYS_DIR),
+        ("JWT_ACTIVE_KEY_ID", "rs-2025"),
+    ])
+    .await;
+    let app = router(create_app_state(&config).await);
+
+    let payload = json!({
+        "user": {
+            "username": "rotator",
+            "email": "rotator@example.com",
+            "password": "rotatorpass123"
+        }
+    });
+    let (_, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    let old_token = body["user"]["token"].as_str().unwrap().to_string();
+    assert_eq!(token_header(&old_token)["kid"], "rs-2025");
+    assert_eq!(token_header(&old_token)["alg"], "RS256");
+
+    // When
+    let mut rotated = config.clone();
+    rotated.jwt.active_key_id = Some("ed-2026".to_string());
+    let app = router(create_app_state(&rotated).await);
+
+    // Then
+    let (status, _) = send(app.clone(), "GET", "/api/user", Some(&old_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let login = json!({
+        "user": {
+            "email": "rotator@example.com",
+            "password": "rotatorpass123"
+        }
+    });
+    let (_, body) = send(app.clone(), "POST", "/api/users/login", None, Some(login)).await;
+    let new_token = body["user"]["token"].as_str().unwrap();
+    assert_eq!(token_header(new_token)["kid"], "ed-2026");
+    assert_eq!(token_header(new_token)["alg"], "EdDSA");
+
+    let (status, _) = send(app, "GET", "/api/user", Some(new_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_token_with_unknown_kid_is_rejected() {
+    // Given
+    let config = common::create_test_config_with(&[
+        ("JWT_KEYS_DIR", KEYS_DIR),
+        ("JWT_ACTIVE_KEY_ID", "ed-2026"),
+    ])
+    .await;
+    let app = router(create_app_state(&config).await);
+
+    let payload = json!({
+        "user": {
+            "username": "kiduser",
+            "email": "kid@example.com",
+            "password": "kidpass12345"
+        }
+    });
+    let (_, body) = send(app.clone(), "POST", "/api/users", None, Some(payload)).await;
+    let token = body["user"]["token"].as_str().unwrap();
+
+    let mut parts: Vec<String> = token.split('.').map(str::to_string).collect();
+    let mut header = token_header(token);
+    header["kid"] = json!("unknown");
+    parts[0] = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header).unwrap());
+    let forged = parts.join(".");
+
+    // When
+    let (status, _) = send(app, "GET", "/api/user", Some(&forged), None).await;
+
+    // Then
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
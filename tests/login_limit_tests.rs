@@ -0,0 +1,185 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, Request, StatusCode};
+use serde_json::json;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use std::net::SocketAddr;
+use tower::ServiceExt;
+
+/// Posts `payload` as if it came from `peer`, like the server does with
+/// connect info enabled.
+async fn post_from(
+    app: axum::Router,
+    uri: &str,
+    peer: Option<SocketAddr>,
+    payload: serde_json::Value,
+) -> (StatusCode, HeaderMap) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_string(&payload).unwrap()))
+        .unwrap();
+    if let Some(peer) = peer {
+        request.extensions_mut().insert(ConnectInfo(peer));
+    }
+
+    let response = app.oneshot(request).await.unwrap();
+    (response.status(), response.headers().clone())
+}
+
+async fn test_app(vars: &[(&str, &str)]) -> axum::Router {
+    let config = common::create_test_config_with(vars).await;
+    router(create_app_state(&config).await)
+}
+
+async fn register_user(app: axum::Router, username: &str) {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, _) = post_from(app, "/api/users", None, payload).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+fn login_payload(username: &str, password: &str) -> serde_json::Value {
+    json!({ "user": { "email": format!("{username}@example.com"), "password": password } })
+}
+
+fn retry_after(headers: &HeaderMap) -> u64 {
+    headers["retry-after"].to_str().unwrap().parse().unwrap()
+}
+
+#[tokio::test]
+async fn repeated_failed_logins_lock_the_account_out() {
+    let app = test_app(&[
+        ("LOGIN_LOCKOUT_THRESHOLD", "3"),
+        ("LOGIN_LOCKOUT_BASE_SECS", "60"),
+    ])
+    .await;
+    register_user(app.clone(), "alice").await;
+
+    for _ in 0..3 {
+        let (status, _) = post_from(
+            app.clone(),
+            "/api/users/login",
+            None,
+            login_payload("alice", "wrong-password"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // Even the right password is refused until the lockout runs out.
+    let (status, headers) = post_from(
+        app.clone(),
+        "/api/users/login",
+        None,
+        login_payload("alice", "password123"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    let wait = retry_after(&headers);
+    assert!((1..=60).contains(&wait), "unexpected Retry-After: {wait}");
+}
+
+#[tokio::test]
+async fn successful_login_resets_failed_attempts() {
+    let app = test_app(&[("LOGIN_LOCKOUT_THRESHOLD", "3")]).await;
+    register_user(app.clone(), "bob").await;
+
+    for password in ["wrong", "wrong", "password123", "wrong", "wrong"] {
+        post_from(
+            app.clone(),
+            "/api/users/login",
+            None,
+            login_payload("bob", password),
+        )
+        .await;
+    }
+
+    let (status, _) = post_from(
+        app,
+        "/api/users/login",
+        None,
+        login_payload("bob", "password123"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn attempts_are_limited_per_ip_across_emails() {
+    let app = test_app(&[
+        ("LOGIN_RATE_LIMIT_BURST", "3"),
+        ("LOGIN_RATE_LIMIT_PER_MINUTE", "1"),
+    ])
+    .await;
+    let peer: SocketAddr = "203.0.113.7:40000".parse().unwrap();
+    let other_peer: SocketAddr = "203.0.113.8:40000".parse().unwrap();
+
+    for name in ["carol", "dave", "erin"] {
+        let (status, _) = post_from(
+            app.clone(),
+            "/api/users/login",
+            Some(peer),
+            login_payload(name, "password123"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    let (status, headers) = post_from(
+        app.clone(),
+        "/api/users/login",
+        Some(peer),
+        login_payload("frank", "password123"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert!(retry_after(&headers) >= 1);
+
+    let (status, _) = post_from(
+        app,
+        "/api/users/login",
+        Some(other_peer),
+        login_payload("frank", "password123"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn registration_is_rate_limited() {
+    let app = test_app(&[
+        ("LOGIN_RATE_LIMIT_BURST", "1"),
+        ("LOGIN_RATE_LIMIT_PER_MINUTE", "1"),
+    ])
+    .await;
+    let peer: SocketAddr = "198.51.100.1:40000".parse().unwrap();
+
+    let register = |username: &str| {
+        json!({
+            "user": {
+                "username": username,
+                "email": format!("{username}@example.com"),
+                "password": "password123"
+            }
+        })
+    };
+
+    let (status, _) = post_from(app.clone(), "/api/users", Some(peer), register("grace")).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, headers) = post_from(app, "/api/users", Some(peer), register("heidi")).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert!(headers.contains_key("retry-after"));
+}
@@ -0,0 +1,50 @@
+#[allow(dead_code)]
+mod common;
+
+use shining_clouds::database::{connect_db, run_migrations};
+use sqlx::migrate::MigrateError;
+
+#[tokio::test]
+async fn test_migrations_are_idempotent() {
+    let config = common::create_test_config().await;
+    let db = connect_db(&config.database).await.unwrap();
+
+    run_migrations(&db).await.unwrap();
+    run_migrations(&db).await.unwrap();
+
+    let tables: Vec<String> = sqlx::query_scalar(
+        "SELECT table_name::text FROM information_schema.tables WHERE table_schema = 'public' ORDER BY table_name",
+    )
+    .fetch_all(db.pool())
+    .await
+    .unwrap();
+
+    for table in [
+        "article_favorites",
+        "article_tags",
+        "articles",
+        "comments",
+        "tags",
+        "user_follows",
+        "users",
+    ] {
+        assert!(tables.contains(&table.to_string()), "missing table {table}");
+    }
+}
+
+#[tokio::test]
+async fn test_migration_checksum_drift_is_rejected() {
+    let config = common::create_test_config().await;
+    let db = connect_db(&config.database).await.unwrap();
+
+    run_migrations(&db).await.unwrap();
+
+    sqlx::query("UPDATE _sqlx_migrations SET checksum = '\\x00' WHERE version = (SELECT MIN(version) FROM _sqlx_migrations)")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let result = run_migrations(&db).await;
+
+    assert!(matches!(result, Err(MigrateError::VersionMismatch(_))));
+}
@@ -0,0 +1,567 @@
+#[allow(dead_code)]
+mod common;
+
+use aws_lc_rs::rand::SystemRandom;
+use aws_lc_rs::signature::{Ed25519KeyPair, KeyPair};
+use axum::body::Body;
+use axum::extract::{Form, State};
+use axum::http::{Request, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const CLIENT_ID: &str = "shining-clouds";
+const CLIENT_SECRET: &str = "client-secret";
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<Value>,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+/// An account at the mock provider.
+struct Account<'a> {
+    subject: &'a str,
+    email: &'a str,
+    email_verified: bool,
+    preferred_username: Option<&'a str>,
+}
+
+impl<'a> Account<'a> {
+    fn verified(subject: &'a str, email: &'a str) -> Account<'a> {
+        Account {
+            subject,
+            email,
+            email_verified: true,
+            preferred_username: None,
+        }
+    }
+}
+
+/// What the provider remembers between the sign-in and the code exchange.
+struct Grant {
+    code_challenge: String,
+    nonce: String,
+    subject: String,
+    email: String,
+    email_verified: bool,
+    preferred_username: Option<String>,
+}
+
+#[derive(Clone)]
+struct MockIdp {
+    issuer: String,
+    key: Arc<EncodingKey>,
+    jwk: Value,
+    grants: Arc<Mutex<HashMap<String, Grant>>>,
+}
+
+impl MockIdp {
+    /// Serves discovery, the JWKS and the token endpoint on a free local port.
+    async fn start() -> MockIdp {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let jwk = json!({
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "kid": "mock-key",
+            "alg": "EdDSA",
+            "use": "sig",
+            "x": URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref()),
+        });
+
+        let idp = MockIdp {
+            issuer,
+            key: Arc::new(EncodingKey::from_ed_der(pkcs8.as_ref())),
+            jwk,
+            grants: Arc::default(),
+        };
+
+        let routes = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/jwks", get(jwks))
+            .route("/token", post(token))
+            .with_state(idp.clone());
+        tokio::spawn(async move { axum::serve(listener, routes).await.unwrap() });
+
+        idp
+    }
+
+    fn providers_config(&self) -> String {
+        json!([{
+            "name": "mock",
+            "discoveryUrl": format!("{}/.well-known/openid-configuration", self.issuer),
+            "clientId": CLIENT_ID,
+            "clientSecret": CLIENT_SECRET,
+            "redirectUri": "http://localhost:3000/auth/callback"
+        }])
+        .to_string()
+    }
+
+    /// Stands in for the user signing in at the provider; returns the code
+    /// and state the provider would redirect back with.
+    fn sign_in(&self, authorization_url: &str, account: Account) -> (String, String) {
+        assert!(authorization_url.starts_with(&format!("{}/authorize?", self.issuer)));
+        assert_eq!(query_param(authorization_url, "client_id"), CLIENT_ID);
+        assert_eq!(
+            query_param(authorization_url, "code_challenge_method"),
+            "S256"
+        );
+
+        let code = Uuid::new_v4().to_string();
+        self.grants.lock().unwrap().insert(
+            code.clone(),
+            Grant {
+                code_challenge: query_param(authorization_url, "code_challenge"),
+                nonce: query_param(authorization_url, "nonce"),
+                subject: account.subject.to_string(),
+                email: account.email.to_string(),
+                email_verified: account.email_verified,
+                preferred_username: account.preferred_username.map(str::to_string),
+            },
+        );
+
+        (code, query_param(authorization_url, "state"))
+    }
+}
+
+fn query_param(url: &str, name: &str) -> String {
+    let (_, query) = url.split_once('?').unwrap();
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(&format!("{name}=")))
+        .unwrap()
+        .to_string()
+}
+
+async fn discovery(State(idp): State<MockIdp>) -> Json<Value> {
+    Json(json!({
+        "issuer": idp.issuer,
+        "authorization_endpoint": format!("{}/authorize", idp.issuer),
+        "token_endpoint": format!("{}/token", idp.issuer),
+        "jwks_uri": format!("{}/jwks", idp.issuer),
+    }))
+}
+
+async fn jwks(State(idp): State<MockIdp>) -> Json<Value> {
+    Json(json!({ "keys": [idp.jwk] }))
+}
+
+/// Redeems a code once, and only with the verifier behind its challenge.
+async fn token(State(idp): State<MockIdp>, Form(form): Form<HashMap<String, String>>) -> Response {
+    let invalid_grant = || {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "invalid_grant" })),
+        )
+            .into_response()
+    };
+
+    if form["client_id"] != CLIENT_ID || form["client_secret"] != CLIENT_SECRET {
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(json!({ "error": "invalid_client" })),
+        )
+            .into_response();
+    }
+    let Some(grant) = idp.grants.lock().unwrap().remove(&form["code"]) else {
+        return invalid_grant();
+    };
+    let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(form["code_verifier"].as_bytes()));
+    if challenge != grant.code_challenge {
+        return invalid_grant();
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let claims = json!({
+        "iss": idp.issuer,
+        "aud": CLIENT_ID,
+        "sub": grant.subject,
+        "iat": now,
+        "exp": now + 300,
+        "nonce": grant.nonce,
+        "email": grant.email,
+        "email_verified": grant.email_verified,
+        "preferred_username": grant.preferred_username,
+    });
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some("mock-key".to_string());
+    let id_token = encode(&header, &claims, &idp.key).unwrap();
+
+    Json(json!({ "access_token": "unused", "token_type": "Bearer", "id_token": id_token }))
+        .into_response()
+}
+
+async fn test_app() -> (MockIdp, axum::Router) {
+    let idp = MockIdp::start().await;
+    let config =
+        common::create_test_config_with(&[("OIDC_PROVIDERS", &idp.providers_config())]).await;
+    (idp, router(create_app_state(&config).await))
+}
+
+async fn authorize(app: axum::Router, token: Option<&str>) -> String {
+    let (status, body) = send(app, "POST", "/api/auth/oidc/mock/authorize", token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    body["authorizationUrl"].as_str().unwrap().to_string()
+}
+
+async fn callback(
+    app: axum::Router,
+    token: Option<&str>,
+    code: &str,
+    state: &str,
+) -> (StatusCode, Value) {
+    let payload = json!({ "code": code, "state": state });
+    send(
+        app,
+        "POST",
+        "/api/auth/oidc/mock/callback",
+        token,
+        Some(payload),
+    )
+    .await
+}
+
+/// Runs the whole flow for `account`, linking to the signed-in user if
+/// `token` is given; the same user finishes it.
+async fn sign_in(
+    idp: &MockIdp,
+    app: axum::Router,
+    token: Option<&str>,
+    account: Account<'_>,
+) -> (StatusCode, Value) {
+    let url = authorize(app.clone(), token).await;
+    let (code, state) = idp.sign_in(&url, account);
+    callback(app, token, &code, &state).await
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+async fn login(app: axum::Router, email: &str, password: &str) -> StatusCode {
+    let payload = json!({ "user": { "email": email, "password": password } });
+    send(app, "POST", "/api/users/login", None, Some(payload))
+        .await
+        .0
+}
+
+async fn identities(app: axum::Router, token: &str) -> Vec<Value> {
+    let (status, body) = send(app, "GET", "/api/user/identities", Some(token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    body["identities"].as_array().unwrap().clone()
+}
+
+#[tokio::test]
+async fn first_sign_in_creates_an_account_without_a_password() {
+    let (idp, app) = test_app().await;
+    let account = Account {
+        preferred_username: Some("Jane Doe!"),
+        ..Account::verified("jane-1", "jane@idp.example")
+    };
+
+    let (status, body) = sign_in(&idp, app.clone(), None, account).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "JaneDoe");
+    assert_eq!(body["user"]["email"], "jane@idp.example");
+    assert_eq!(body["user"]["verified"], true);
+    let token = body["user"]["token"].as_str().unwrap().to_string();
+
+    let (status, body) = sign_in(
+        &idp,
+        app.clone(),
+        None,
+        Account::verified("jane-1", "jane@idp.example"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "JaneDoe");
+
+    let identities = identities(app.clone(), &token).await;
+    assert_eq!(identities.len(), 1);
+    assert_eq!(identities[0]["provider"], "mock");
+    assert_eq!(identities[0]["email"], "jane@idp.example");
+
+    let payload = json!({ "user": { "password": "password123" } });
+    let (status, _) = send(app.clone(), "PUT", "/api/user", Some(&token), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        login(app, "jane@idp.example", "password123").await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn generated_usernames_are_unique() {
+    let (idp, app) = test_app().await;
+    register_user(app.clone(), "jane").await;
+
+    let (status, body) = sign_in(
+        &idp,
+        app,
+        None,
+        Account::verified("jane-2", "jane@idp.example"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let username = body["user"]["username"].as_str().unwrap();
+    assert_ne!(username, "jane");
+    assert!(username.starts_with("jane"));
+}
+
+#[tokio::test]
+async fn verified_emails_link_to_the_existing_account() {
+    let (idp, app) = test_app().await;
+    let (_, body) = sign_in(
+        &idp,
+        app.clone(),
+        None,
+        Account::verified("a-1", "alice@idp.example"),
+    )
+    .await;
+    let token = body["user"]["token"].as_str().unwrap().to_string();
+
+    let (status, body) = sign_in(
+        &idp,
+        app.clone(),
+        None,
+        Account::verified("a-2", "alice@idp.example"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alice");
+
+    assert_eq!(identities(app, &token).await.len(), 2);
+}
+
+#[tokio::test]
+async fn unverified_accounts_are_taken_over_by_the_verified_owner() {
+    let (idp, app) = test_app().await;
+    register_user(app.clone(), "alice").await;
+
+    let (status, body) = sign_in(
+        &idp,
+        app.clone(),
+        None,
+        Account::verified("a-1", "alice@example.com"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alice");
+    assert_eq!(body["user"]["verified"], true);
+
+    // Whoever registered the address without proving it loses the password.
+    assert_eq!(
+        login(app, "alice@example.com", "password123").await,
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn claims_end_every_way_back_into_the_account() {
+    let (idp, app) = test_app().await;
+    let squatter = register_user(app.clone(), "alice").await;
+
+    let payload = json!({ "accessToken": { "name": "backdoor", "scopes": ["articles:write"] } });
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/user/tokens",
+        Some(&squatter),
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let access_token = body["accessToken"]["token"].as_str().unwrap().to_string();
+
+    let squatter_account = || Account::verified("s-1", "squatter@evil.example");
+    let (status, _) = sign_in(&idp, app.clone(), Some(&squatter), squatter_account()).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, body) = sign_in(
+        &idp,
+        app.clone(),
+        None,
+        Account::verified("a-1", "alice@example.com"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alice");
+    let owner = body["user"]["token"].as_str().unwrap().to_string();
+    assert_eq!(identities(app.clone(), &owner).await.len(), 1);
+
+    let (status, _) = send(app.clone(), "GET", "/api/user", Some(&access_token), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // The squatter's identity now signs in to an account of its own.
+    let (status, body) = sign_in(&idp, app, None, squatter_account()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(body["user"]["username"], "alice");
+}
+
+#[tokio::test]
+async fn unverified_provider_emails_are_refused() {
+    let (idp, app) = test_app().await;
+    register_user(app.clone(), "alice").await;
+    let account = Account {
+        email_verified: false,
+        ..Account::verified("a-1", "alice@example.com")
+    };
+
+    let (status, _) = sign_in(&idp, app.clone(), None, account).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        login(app, "alice@example.com", "password123").await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn state_and_code_must_belong_to_the_same_login() {
+    let (idp, app) = test_app().await;
+
+    let (status, _) = callback(app.clone(), None, "code", "unknown-state").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // A code redeemed with another login's verifier fails PKCE.
+    let first = authorize(app.clone(), None).await;
+    let second = authorize(app.clone(), None).await;
+    let (code, _) = idp.sign_in(&first, Account::verified("a-1", "alice@idp.example"));
+    let (_, second_state) = idp.sign_in(&second, Account::verified("a-1", "alice@idp.example"));
+    let (status, _) = callback(app.clone(), None, &code, &second_state).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let url = authorize(app.clone(), None).await;
+    let (code, state) = idp.sign_in(&url, Account::verified("a-1", "alice@idp.example"));
+    let (status, _) = callback(app.clone(), None, &code, &state).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = callback(app, None, &code, &state).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn unknown_providers_are_not_found() {
+    let (_, app) = test_app().await;
+
+    let (status, _) = send(app, "POST", "/api/auth/oidc/other/authorize", None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn signed_in_users_link_identities_with_any_email() {
+    let (idp, app) = test_app().await;
+    let alice = register_user(app.clone(), "alice").await;
+    let bob = register_user(app.clone(), "bob").await;
+
+    let work_account = || Account::verified("a-work", "a.smith@corp.example");
+    let (status, _) = sign_in(&idp, app.clone(), Some(&alice), work_account()).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(identities(app.clone(), &alice).await.len(), 1);
+
+    let (status, _) = sign_in(&idp, app.clone(), Some(&bob), work_account()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, body) = sign_in(&idp, app, None, work_account()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alice");
+}
+
+#[tokio::test]
+async fn the_last_identity_stays_until_a_password_is_set() {
+    let (idp, app) = test_app().await;
+    let (_, body) = sign_in(
+        &idp,
+        app.clone(),
+        None,
+        Account::verified("a-1", "alice@idp.example"),
+    )
+    .await;
+    let token = body["user"]["token"].as_str().unwrap().to_string();
+    let id = identities(app.clone(), &token).await[0]["id"].clone();
+    let uri = format!("/api/user/identities/{}", id.as_str().unwrap());
+
+    let (status, _) = send(app.clone(), "DELETE", &uri, Some(&token), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let payload = json!({ "user": { "password": "password123" } });
+    send(app.clone(), "PUT", "/api/user", Some(&token), Some(payload)).await;
+
+    let (status, _) = send(app.clone(), "DELETE", &uri, Some(&token), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert!(identities(app.clone(), &token).await.is_empty());
+
+    let (status, _) = send(app, "DELETE", &uri, Some(&token), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn links_are_finished_by_the_user_who_started_them() {
+    let (idp, app) = test_app().await;
+    let mallory = register_user(app.clone(), "mallory").await;
+    let alice = register_user(app.clone(), "alice").await;
+    let alice_account = || Account::verified("a-1", "alice@idp.example");
+
+    // Mallory's link URL, opened by Alice, signed in or not.
+    for token in [None, Some(alice.as_str())] {
+        let url = authorize(app.clone(), Some(&mallory)).await;
+        let (code, state) = idp.sign_in(&url, alice_account());
+        let (status, body) = callback(app.clone(), token, &code, &state).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(body["user"]["token"].is_null());
+    }
+    assert!(identities(app.clone(), &mallory).await.is_empty());
+
+    let (status, body) = sign_in(&idp, app, None, alice_account()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(body["user"]["username"], "mallory");
+}
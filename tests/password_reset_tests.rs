@@ -0,0 +1,356 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use shining_clouds::app_config::AppConfig;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+/// An app that saves its mail in a fresh directory.
+async fn test_app(vars: &[(&str, &str)]) -> (AppConfig, PathBuf, axum::Router) {
+    let mail_dir = std::env::temp_dir().join(format!("mail-{}", Uuid::new_v4()));
+    let mail_vars = [
+        ("MAIL_TRANSPORT", "File"),
+        ("MAIL_FILE_DIR", mail_dir.to_str().unwrap()),
+    ];
+    let config = common::create_test_config_with(&[&mail_vars, vars].concat()).await;
+    let app = router(create_app_state(&config).await);
+    (config, mail_dir, app)
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+async fn login(app: axum::Router, username: &str, password: &str) -> StatusCode {
+    let payload = json!({
+        "user": { "email": format!("{username}@example.com"), "password": password }
+    });
+    send(app, "POST", "/api/users/login", None, Some(payload))
+        .await
+        .0
+}
+
+async fn forgot_password(app: axum::Router, email: &str) {
+    let payload = json!({ "email": email });
+    let (status, _) = send(
+        app,
+        "POST",
+        "/api/users/password/forgot",
+        None,
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+}
+
+/// Reset tokens mailed so far, oldest first.
+fn reset_tokens(mail_dir: &Path) -> Vec<String> {
+    let mut paths: Vec<_> = std::fs::read_dir(mail_dir)
+        .map(|entries| entries.map(|entry| entry.unwrap().path()).collect())
+        .unwrap_or_default();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| std::fs::read_to_string(path).unwrap())
+        .filter_map(|mail| {
+            // A mail still being written has no whitespace after the token yet.
+            let (_, rest) = mail.split_once("/reset-password?token=")?;
+            let (token, _) = rest.split_once(char::is_whitespace)?;
+            Some(token.to_string())
+        })
+        .collect()
+}
+
+/// Reset tokens mailed so far, once there are `count`; they are mailed in the
+/// background after the request is answered.
+async fn mailed_reset_tokens(mail_dir: &Path, count: usize) -> Vec<String> {
+    for _ in 0..100 {
+        let tokens = reset_tokens(mail_dir);
+        if tokens.len() >= count {
+            return tokens;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("no {count} reset emails in {mail_dir:?}");
+}
+
+async fn reset_password(app: axum::Router, token: &str, password: &str) -> StatusCode {
+    let payload = json!({ "token": token, "password": password });
+    send(
+        app,
+        "POST",
+        "/api/users/password/reset",
+        None,
+        Some(payload),
+    )
+    .await
+    .0
+}
+
+#[tokio::test]
+async fn reset_sets_new_password_and_signs_out_everywhere() {
+    let (_config, mail_dir, app) = test_app(&[]).await;
+    let access_token = register_user(app.clone(), "alice").await;
+
+    forgot_password(app.clone(), "alice@example.com").await;
+    let tokens = mailed_reset_tokens(&mail_dir, 1).await;
+    assert_eq!(tokens.len(), 1);
+
+    assert_eq!(
+        reset_password(app.clone(), &tokens[0], "new-password456").await,
+        StatusCode::NO_CONTENT
+    );
+
+    assert_eq!(
+        login(app.clone(), "alice", "password123").await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        login(app.clone(), "alice", "new-password456").await,
+        StatusCode::OK
+    );
+
+    let (status, _) = send(app.clone(), "GET", "/api/user", Some(&access_token), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // Tokens work once.
+    assert_eq!(
+        reset_password(app, &tokens[0], "another-password789").await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+}
+
+#[tokio::test]
+async fn unknown_email_is_accepted_without_sending_mail() {
+    let (_config, mail_dir, app) = test_app(&[]).await;
+
+    forgot_password(app, "nobody@example.com").await;
+
+    assert!(reset_tokens(&mail_dir).is_empty());
+}
+
+#[tokio::test]
+async fn mail_failures_are_not_reported() {
+    let (_config, _mail_dir, app) = test_app(&[
+        ("MAIL_TRANSPORT", "Smtp"),
+        ("SMTP_HOST", "127.0.0.1"),
+        ("SMTP_PORT", "1"),
+        ("SMTP_STARTTLS", "false"),
+    ])
+    .await;
+    register_user(app.clone(), "bob").await;
+
+    forgot_password(app, "bob@example.com").await;
+}
+
+#[tokio::test]
+async fn slow_mail_servers_do_not_delay_the_answer() {
+    // Hangs up on the first connection, so the verification email of the
+    // registration fails fast, and then accepts connections and never says a
+    // word.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port().to_string();
+    tokio::spawn(async move {
+        drop(listener.accept().await);
+        let mut connections = vec![];
+        while let Ok((connection, _)) = listener.accept().await {
+            connections.push(connection);
+        }
+    });
+    let (_config, _mail_dir, app) = test_app(&[
+        ("MAIL_TRANSPORT", "Smtp"),
+        ("SMTP_HOST", "127.0.0.1"),
+        ("SMTP_PORT", &port),
+        ("SMTP_STARTTLS", "false"),
+    ])
+    .await;
+    register_user(app.clone(), "bob").await;
+
+    tokio::time::timeout(
+        Duration::from_secs(2),
+        forgot_password(app, "bob@example.com"),
+    )
+    .await
+    .expect("the answer waited for the mail server");
+}
+
+#[tokio::test]
+async fn reset_emails_are_throttled_per_user() {
+    let (_config, mail_dir, app) = test_app(&[]).await;
+    register_user(app.clone(), "bob").await;
+
+    forgot_password(app.clone(), "bob@example.com").await;
+    mailed_reset_tokens(&mail_dir, 1).await;
+    forgot_password(app, "bob@example.com").await;
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(reset_tokens(&mail_dir).len(), 1);
+}
+
+#[tokio::test]
+async fn expired_and_unknown_tokens_are_rejected() {
+    let (_config, mail_dir, app) = test_app(&[("AUTH_PASSWORD_RESET_TTL_MINS", "0")]).await;
+    register_user(app.clone(), "carol").await;
+
+    forgot_password(app.clone(), "carol@example.com").await;
+    let tokens = mailed_reset_tokens(&mail_dir, 1).await;
+
+    assert_eq!(
+        reset_password(app.clone(), &tokens[0], "new-password456").await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(
+        reset_password(app.clone(), "made-up-token", "new-password456").await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(login(app, "carol", "password123").await, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn reset_lifts_a_reset_required_by_an_admin() {
+    let (config, mail_dir, app) = test_app(&[]).await;
+    register_user(app.clone(), "dave").await;
+    register_user(app.clone(), "root").await;
+    common::set_user_role(&config, "root", "admin").await;
+    let payload = json!({ "user": { "email": "root@example.com", "password": "password123" } });
+    let (_, body) = send(app.clone(), "POST", "/api/users/login", None, Some(payload)).await;
+    let admin_token = body["user"]["token"].as_str().unwrap().to_string();
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/admin/users/dave/password-reset",
+        Some(&admin_token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        login(app.clone(), "dave", "password123").await,
+        StatusCode::FORBIDDEN
+    );
+
+    forgot_password(app.clone(), "dave@example.com").await;
+    let tokens = mailed_reset_tokens(&mail_dir, 1).await;
+    assert_eq!(
+        reset_password(app.clone(), &tokens[0], "new-password456").await,
+        StatusCode::NO_CONTENT
+    );
+
+    assert_eq!(login(app, "dave", "new-password456").await, StatusCode::OK);
+}
+
+/// Registers `root` as an admin and returns its session token.
+async fn admin_token(config: &AppConfig, app: axum::Router) -> String {
+    register_user(app.clone(), "root").await;
+    common::set_user_role(config, "root", "admin").await;
+    let payload = json!({ "user": { "email": "root@example.com", "password": "password123" } });
+    let (_, body) = send(app, "POST", "/api/users/login", None, Some(payload)).await;
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+async fn force_reset(app: axum::Router, admin_token: &str, username: &str) -> StatusCode {
+    send(
+        app,
+        "POST",
+        &format!("/api/admin/users/{username}/password-reset"),
+        Some(admin_token),
+        None,
+    )
+    .await
+    .0
+}
+
+#[tokio::test]
+async fn forced_resets_mail_a_reset_link() {
+    let (config, mail_dir, app) = test_app(&[]).await;
+    register_user(app.clone(), "erin").await;
+    let admin_token = admin_token(&config, app.clone()).await;
+
+    assert_eq!(
+        force_reset(app.clone(), &admin_token, "erin").await,
+        StatusCode::OK
+    );
+    assert_eq!(reset_tokens(&mail_dir).len(), 1);
+
+    // Unlike a request by the user, a forced reset is never throttled.
+    assert_eq!(
+        force_reset(app.clone(), &admin_token, "erin").await,
+        StatusCode::OK
+    );
+    let tokens = reset_tokens(&mail_dir);
+    assert_eq!(tokens.len(), 2);
+
+    assert_eq!(
+        reset_password(app.clone(), &tokens[1], "new-password456").await,
+        StatusCode::NO_CONTENT
+    );
+    assert_eq!(login(app, "erin", "new-password456").await, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn forced_resets_report_mail_failures() {
+    let (config, _mail_dir, app) = test_app(&[
+        ("MAIL_TRANSPORT", "Smtp"),
+        ("SMTP_HOST", "127.0.0.1"),
+        ("SMTP_PORT", "1"),
+        ("SMTP_STARTTLS", "false"),
+    ])
+    .await;
+    register_user(app.clone(), "erin").await;
+    let admin_token = admin_token(&config, app.clone()).await;
+
+    assert_eq!(
+        force_reset(app.clone(), &admin_token, "erin").await,
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(
+        login(app, "erin", "password123").await,
+        StatusCode::FORBIDDEN
+    );
+}
@@ -0,0 +1,313 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use shining_clouds::app_config::AppConfig;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use tower::ServiceExt;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn test_app() -> (AppConfig, axum::Router) {
+    let config = common::create_test_config().await;
+    let app = router(create_app_state(&config).await);
+    (config, app)
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Registers `username` with `role` and returns a token carrying that role.
+async fn register_with_role(config: &AppConfig, app: axum::Router, username: &str, role: &str) -> String {
+    register_user(app.clone(), username).await;
+    common::set_user_role(config, username, role).await;
+
+    let payload = json!({
+        "user": { "email": format!("{username}@example.com"), "password": "password123" }
+    });
+    let (status, body) = send(app, "POST", "/api/users/login", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["role"], role);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Creates an article with one comment by its author.
+async fn create_article_with_comment(app: axum::Router, token: &str) -> (String, String) {
+    let payload = json!({
+        "article": { "title": "Some Article", "description": "Description", "body": "Body" }
+    });
+    let (_, body) = send(app.clone(), "POST", "/api/articles", Some(token), Some(payload)).await;
+    let slug = body["article"]["slug"].as_str().unwrap().to_string();
+
+    let payload = json!({ "comment": { "body": "Buy cheap stuff!" } });
+    let (_, body) = send(app, "POST", &format!("/api/articles/{slug}/comments"), Some(token), Some(payload)).await;
+
+    (slug, body["comment"]["id"].as_str().unwrap().to_string())
+}
+
+async fn report(app: axum::Router, uri: &str, token: &str, reason: &str) -> StatusCode {
+    let payload = json!({ "report": { "reason": reason, "details": "Please look at this" } });
+    let (status, _) = send(app, "POST", uri, Some(token), Some(payload)).await;
+    status
+}
+
+#[tokio::test]
+async fn test_report_article() {
+    let (_, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let reader = register_user(app.clone(), "reader").await;
+    let (slug, _) = create_article_with_comment(app.clone(), &author).await;
+    let uri = format!("/api/articles/{slug}/report");
+
+    let payload = json!({ "report": { "reason": "spam" } });
+    let (status, body) = send(app.clone(), "POST", &uri, Some(&reader), Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["report"]["reason"], "spam");
+
+    assert_eq!(report(app.clone(), &uri, &reader, "other").await, StatusCode::CONFLICT);
+    assert_eq!(report(app.clone(), &uri, &author, "spam").await, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        report(app.clone(), &uri, &reader, "boring").await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+
+    let (status, _) = send(app, "POST", &uri, None, Some(json!({ "report": { "reason": "spam" } }))).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_report_queue_groups_reports_per_target() {
+    let (config, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let alice = register_user(app.clone(), "alice").await;
+    let bob = register_user(app.clone(), "bob").await;
+    let moderator = register_with_role(&config, app.clone(), "moderator", "moderator").await;
+    let (slug, comment_id) = create_article_with_comment(app.clone(), &author).await;
+
+    let article_uri = format!("/api/articles/{slug}/report");
+    let comment_uri = format!("/api/articles/{slug}/comments/{comment_id}/report");
+    assert_eq!(report(app.clone(), &comment_uri, &alice, "spam").await, StatusCode::CREATED);
+    assert_eq!(report(app.clone(), &comment_uri, &bob, "harassment").await, StatusCode::CREATED);
+    assert_eq!(report(app.clone(), &article_uri, &alice, "misinformation").await, StatusCode::CREATED);
+
+    let (status, _) = send(app.clone(), "GET", "/api/admin/reports", Some(&alice), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = send(app, "GET", "/api/admin/reports", Some(&moderator), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["reportsCount"], 2);
+
+    let first = &body["reports"][0];
+    assert_eq!(first["commentId"], comment_id.as_str());
+    assert_eq!(first["commentBody"], "Buy cheap stuff!");
+    assert_eq!(first["author"], "author");
+    assert_eq!(first["reportsCount"], 2);
+    assert_eq!(first["reasons"], json!(["harassment", "spam"]));
+    assert_eq!(first["hidden"], false);
+
+    let second = &body["reports"][1];
+    assert_eq!(second["slug"], slug.as_str());
+    assert_eq!(second["commentId"], serde_json::Value::Null);
+    assert_eq!(second["reportsCount"], 1);
+}
+
+#[tokio::test]
+async fn test_hidden_article_is_only_visible_to_moderators() {
+    let (config, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let reader = register_user(app.clone(), "reader").await;
+    let moderator = register_with_role(&config, app.clone(), "moderator", "moderator").await;
+    let (slug, _) = create_article_with_comment(app.clone(), &author).await;
+
+    assert_eq!(
+        report(app.clone(), &format!("/api/articles/{slug}/report"), &reader, "spam").await,
+        StatusCode::CREATED
+    );
+
+    let resolve_uri = format!("/api/admin/reports/articles/{slug}/resolve");
+    let (status, _) = send(app.clone(), "POST", &resolve_uri, Some(&reader), Some(json!({ "action": "hide" }))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(app.clone(), "POST", &resolve_uri, Some(&moderator), Some(json!({ "action": "hide" }))).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    for token in [None, Some(reader.as_str()), Some(author.as_str())] {
+        let (status, _) = send(app.clone(), "GET", &format!("/api/articles/{slug}"), token, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (_, body) = send(app.clone(), "GET", "/api/articles", token, None).await;
+        assert_eq!(body["articlesCount"], 0);
+    }
+
+    let (status, _) = send(app.clone(), "GET", &format!("/api/articles/{slug}"), Some(&moderator), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, body) = send(app.clone(), "GET", "/api/articles", Some(&moderator), None).await;
+    assert_eq!(body["articlesCount"], 1);
+
+    let payload = json!({ "article": { "body": "Edited" } });
+    let (status, _) = send(app.clone(), "PUT", &format!("/api/articles/{slug}"), Some(&author), Some(payload)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, body) = send(app.clone(), "GET", "/api/admin/reports", Some(&moderator), None).await;
+    assert_eq!(body["reportsCount"], 0);
+
+    let (status, _) = send(app, "POST", &resolve_uri, Some(&moderator), Some(json!({ "action": "dismiss" }))).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_hidden_comment_is_only_visible_to_moderators() {
+    let (config, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let reader = register_user(app.clone(), "reader").await;
+    let moderator = register_with_role(&config, app.clone(), "moderator", "moderator").await;
+    let (slug, comment_id) = create_article_with_comment(app.clone(), &author).await;
+
+    let report_uri = format!("/api/articles/{slug}/comments/{comment_id}/report");
+    assert_eq!(report(app.clone(), &report_uri, &reader, "spam").await, StatusCode::CREATED);
+
+    let resolve_uri = format!("/api/admin/reports/articles/{slug}/comments/{comment_id}/resolve");
+    let (status, _) = send(app.clone(), "POST", &resolve_uri, Some(&moderator), Some(json!({ "action": "hide" }))).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let comments_uri = format!("/api/articles/{slug}/comments");
+    let (_, body) = send(app.clone(), "GET", &comments_uri, Some(&reader), None).await;
+    assert_eq!(body["comments"].as_array().unwrap().len(), 0);
+
+    let (_, body) = send(app.clone(), "GET", &comments_uri, Some(&moderator), None).await;
+    assert_eq!(body["comments"].as_array().unwrap().len(), 1);
+
+    let (status, _) = send(app.clone(), "GET", &format!("/api/articles/{slug}"), Some(&reader), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let payload = json!({ "comment": { "body": "Edited" } });
+    let (status, _) = send(app, "PUT", &format!("{comments_uri}/{comment_id}"), Some(&author), Some(payload)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_suspend_author_requires_admin() {
+    let (config, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let reader = register_user(app.clone(), "reader").await;
+    let moderator = register_with_role(&config, app.clone(), "moderator", "moderator").await;
+    let admin = register_with_role(&config, app.clone(), "admin", "admin").await;
+    let (slug, _) = create_article_with_comment(app.clone(), &author).await;
+
+    assert_eq!(
+        report(app.clone(), &format!("/api/articles/{slug}/report"), &reader, "spam").await,
+        StatusCode::CREATED
+    );
+
+    let resolve_uri = format!("/api/admin/reports/articles/{slug}/resolve");
+    let payload = json!({ "action": "suspend_author" });
+    let (status, _) = send(app.clone(), "POST", &resolve_uri, Some(&moderator), Some(payload.clone())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(app.clone(), "POST", &resolve_uri, Some(&admin), Some(payload)).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app.clone(), "GET", "/api/user", Some(&author), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(app, "GET", &format!("/api/articles/{slug}"), Some(&reader), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_dismiss_keeps_content() {
+    let (config, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let reader = register_user(app.clone(), "reader").await;
+    let moderator = register_with_role(&config, app.clone(), "moderator", "moderator").await;
+    let (slug, _) = create_article_with_comment(app.clone(), &author).await;
+    let report_uri = format!("/api/articles/{slug}/report");
+
+    assert_eq!(report(app.clone(), &report_uri, &reader, "spam").await, StatusCode::CREATED);
+
+    let resolve_uri = format!("/api/admin/reports/articles/{slug}/resolve");
+    let (status, _) = send(app.clone(), "POST", &resolve_uri, Some(&moderator), Some(json!({ "action": "dismiss" }))).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app.clone(), "GET", &format!("/api/articles/{slug}"), Some(&reader), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // The report is closed, so the reader may report the article again.
+    assert_eq!(report(app, &report_uri, &reader, "spam").await, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_concurrent_resolves_close_reports_once() {
+    let (config, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let reader = register_user(app.clone(), "reader").await;
+    let first = register_with_role(&config, app.clone(), "first", "admin").await;
+    let second = register_with_role(&config, app.clone(), "second", "admin").await;
+    let (slug, _) = create_article_with_comment(app.clone(), &author).await;
+
+    assert_eq!(
+        report(app.clone(), &format!("/api/articles/{slug}/report"), &reader, "spam").await,
+        StatusCode::CREATED
+    );
+
+    // Spawned so the resolves actually race on the database.
+    let resolve_uri = format!("/api/admin/reports/articles/{slug}/resolve");
+    let handles: Vec<_> = [first, second]
+        .into_iter()
+        .map(|admin| {
+            let app = app.clone();
+            let uri = resolve_uri.clone();
+            tokio::spawn(async move {
+                let payload = json!({ "action": "suspend_author" });
+                send(app, "POST", &uri, Some(&admin), Some(payload)).await.0
+            })
+        })
+        .collect();
+    let mut statuses = vec![];
+    for handle in handles {
+        statuses.push(handle.await.unwrap());
+    }
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::NO_CONTENT, StatusCode::NOT_FOUND]);
+
+    let (status, _) = send(app, "GET", "/api/user", Some(&author), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,196 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use shining_clouds::app_config::AppConfig;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use tower::ServiceExt;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn test_app() -> (AppConfig, axum::Router) {
+    let config = common::create_test_config().await;
+    let app = router(create_app_state(&config).await);
+    (config, app)
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Registers `username` with `role` and returns a token carrying that role.
+async fn register_with_role(config: &AppConfig, app: axum::Router, username: &str, role: &str) -> String {
+    register_user(app.clone(), username).await;
+    common::set_user_role(config, username, role).await;
+
+    let payload = json!({
+        "user": { "email": format!("{username}@example.com"), "password": "password123" }
+    });
+    let (status, body) = send(app, "POST", "/api/users/login", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["role"], role);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Creates an article with one comment by its author.
+async fn create_article_with_comment(app: axum::Router, token: &str) -> (String, String) {
+    let payload = json!({
+        "article": { "title": "Some Article", "description": "Description", "body": "Body" }
+    });
+    let (_, body) = send(app.clone(), "POST", "/api/articles", Some(token), Some(payload)).await;
+    let slug = body["article"]["slug"].as_str().unwrap().to_string();
+
+    let payload = json!({ "comment": { "body": "First!" } });
+    let (_, body) = send(app, "POST", &format!("/api/articles/{slug}/comments"), Some(token), Some(payload)).await;
+
+    (slug, body["comment"]["id"].as_str().unwrap().to_string())
+}
+
+#[tokio::test]
+async fn test_new_users_have_user_role() {
+    let (_, app) = test_app().await;
+    let token = register_user(app.clone(), "someone").await;
+
+    let (status, body) = send(app, "GET", "/api/user", Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["role"], "user");
+}
+
+#[tokio::test]
+async fn test_moderator_can_delete_any_article_and_comment() {
+    let (config, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let moderator = register_with_role(&config, app.clone(), "moderator", "moderator").await;
+    let (slug, comment_id) = create_article_with_comment(app.clone(), &author).await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{slug}/comments/{comment_id}/revisions"),
+        Some(&moderator),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{slug}/comments/{comment_id}"),
+        Some(&moderator),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app.clone(), "DELETE", &format!("/api/articles/{slug}"), Some(&moderator), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app, "GET", &format!("/api/articles/{slug}"), None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_moderator_cannot_edit_content_of_others() {
+    let (config, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let moderator = register_with_role(&config, app.clone(), "moderator", "moderator").await;
+    let (slug, comment_id) = create_article_with_comment(app.clone(), &author).await;
+
+    let payload = json!({ "article": { "body": "Rewritten" } });
+    let (status, _) = send(app.clone(), "PUT", &format!("/api/articles/{slug}"), Some(&moderator), Some(payload)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let payload = json!({ "comment": { "body": "Rewritten" } });
+    let (status, _) = send(
+        app,
+        "PUT",
+        &format!("/api/articles/{slug}/comments/{comment_id}"),
+        Some(&moderator),
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_regular_user_cannot_delete_content_of_others() {
+    let (_, app) = test_app().await;
+    let author = register_user(app.clone(), "author").await;
+    let other = register_user(app.clone(), "other").await;
+    let (slug, comment_id) = create_article_with_comment(app.clone(), &author).await;
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{slug}/comments/{comment_id}"),
+        Some(&other),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(app, "DELETE", &format!("/api/articles/{slug}"), Some(&other), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_refreshed_token_picks_up_role_change() {
+    let (config, app) = test_app().await;
+    let payload = json!({
+        "user": { "username": "promoted", "email": "promoted@example.com", "password": "password123" }
+    });
+    let (_, body) = send(app.clone(), "POST", "/api/users", None, Some(payload)).await;
+    let refresh_token = body["user"]["refreshToken"].as_str().unwrap().to_string();
+    let author = register_user(app.clone(), "author").await;
+    let (slug, _) = create_article_with_comment(app.clone(), &author).await;
+
+    common::set_user_role(&config, "promoted", "admin").await;
+
+    let payload = json!({ "refreshToken": refresh_token });
+    let (status, body) = send(app.clone(), "POST", "/api/users/token/refresh", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let token = body["token"].as_str().unwrap().to_string();
+
+    let (status, _) = send(app, "DELETE", &format!("/api/articles/{slug}"), Some(&token), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
@@ -0,0 +1,315 @@
+#[allow(dead_code)]
+mod common;
+
+use axum::body::Body;
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use tower::ServiceExt;
+
+/// What a request carries: cookies, an `X-CSRF-Token` header and an
+/// `Authorization` header value.
+#[derive(Default)]
+struct Credentials<'a> {
+    cookies: Vec<(&'a str, &'a str)>,
+    csrf: Option<&'a str>,
+    authorization: Option<String>,
+}
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    credentials: &Credentials<'_>,
+    payload: Option<Value>,
+) -> (StatusCode, Value, Vec<String>) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if !credentials.cookies.is_empty() {
+        let cookies: Vec<String> = credentials
+            .cookies
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect();
+        request = request.header(COOKIE, cookies.join("; "));
+    }
+    if let Some(csrf) = credentials.csrf {
+        request = request.header("x-csrf-token", csrf);
+    }
+    if let Some(authorization) = &credentials.authorization {
+        request = request.header("authorization", authorization);
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let set_cookies = response
+        .headers()
+        .get_all(SET_COOKIE)
+        .iter()
+        .map(|value| value.to_str().unwrap().to_string())
+        .collect();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&body).unwrap_or_default(),
+        set_cookies,
+    )
+}
+
+async fn test_app(vars: &[(&str, &str)]) -> axum::Router {
+    let config = common::create_test_config_with(vars).await;
+    router(create_app_state(&config).await)
+}
+
+async fn cookie_app() -> axum::Router {
+    test_app(&[("SESSION_COOKIES_ENABLED", "true")]).await
+}
+
+/// The `Set-Cookie` header for `name`.
+fn set_cookie<'a>(set_cookies: &'a [String], name: &str) -> &'a str {
+    set_cookies
+        .iter()
+        .find(|cookie| cookie.starts_with(&format!("{name}=")))
+        .unwrap_or_else(|| panic!("no {name} cookie in {set_cookies:?}"))
+}
+
+fn cookie_value(set_cookies: &[String], name: &str) -> String {
+    let cookie = set_cookie(set_cookies, name);
+    let (pair, _) = cookie.split_once(';').unwrap_or((cookie, ""));
+    pair[name.len() + 1..].to_string()
+}
+
+/// Session, refresh and CSRF cookie values of a registration.
+async fn register_user(app: axum::Router, username: &str) -> (String, String, String) {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, _, set_cookies) = send(
+        app,
+        "POST",
+        "/api/users",
+        &Credentials::default(),
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    (
+        cookie_value(&set_cookies, "session"),
+        cookie_value(&set_cookies, "refresh_token"),
+        cookie_value(&set_cookies, "csrf_token"),
+    )
+}
+
+async fn login(app: axum::Router, username: &str) -> (StatusCode, Vec<String>) {
+    let payload = json!({
+        "user": { "email": format!("{username}@example.com"), "password": "password123" }
+    });
+    let (status, _, set_cookies) = send(
+        app,
+        "POST",
+        "/api/users/login",
+        &Credentials::default(),
+        Some(payload),
+    )
+    .await;
+    (status, set_cookies)
+}
+
+#[tokio::test]
+async fn login_sets_http_only_session_cookies() {
+    let app = cookie_app().await;
+    register_user(app.clone(), "alice").await;
+
+    let (status, set_cookies) = login(app, "alice").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let session = set_cookie(&set_cookies, "session");
+    for attribute in [
+        "HttpOnly",
+        "Secure",
+        "SameSite=Lax",
+        "Path=/api;",
+        "Max-Age=900",
+    ] {
+        assert!(session.contains(attribute), "{session} lacks {attribute}");
+    }
+    let refresh = set_cookie(&set_cookies, "refresh_token");
+    assert!(refresh.contains("HttpOnly"));
+    assert!(refresh.contains("Path=/api/users/token/refresh"));
+    let csrf = set_cookie(&set_cookies, "csrf_token");
+    assert!(!csrf.contains("HttpOnly"));
+    assert!(csrf.contains("Path=/;"));
+}
+
+#[tokio::test]
+async fn cookie_attributes_follow_the_config() {
+    let app = test_app(&[
+        ("SESSION_COOKIES_ENABLED", "true"),
+        ("SESSION_COOKIE_SAME_SITE", "Strict"),
+        ("SESSION_COOKIE_SECURE", "false"),
+    ])
+    .await;
+    register_user(app.clone(), "alice").await;
+
+    let (_, set_cookies) = login(app, "alice").await;
+
+    let session = set_cookie(&set_cookies, "session");
+    assert!(session.contains("SameSite=Strict"));
+    assert!(!session.contains("Secure"));
+}
+
+#[tokio::test]
+async fn session_cookies_need_the_csrf_header_for_writes() {
+    let app = cookie_app().await;
+    let (session, _, csrf) = register_user(app.clone(), "alice").await;
+    let cookies = vec![("session", session.as_str()), ("csrf_token", csrf.as_str())];
+    let update = || json!({ "user": { "bio": "Hello" } });
+
+    let reading = Credentials {
+        cookies: cookies.clone(),
+        ..Credentials::default()
+    };
+    let (status, body, _) = send(app.clone(), "GET", "/api/user", &reading, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alice");
+
+    let (status, body, _) = send(app.clone(), "PUT", "/api/user", &reading, Some(update())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["errors"]["body"][0], "Missing or invalid CSRF token");
+
+    let forged = Credentials {
+        cookies: cookies.clone(),
+        csrf: Some("guessed"),
+        ..Credentials::default()
+    };
+    let (status, _, _) = send(app.clone(), "PUT", "/api/user", &forged, Some(update())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let writing = Credentials {
+        cookies,
+        csrf: Some(&csrf),
+        ..Credentials::default()
+    };
+    let (status, body, _) = send(app, "PUT", "/api/user", &writing, Some(update())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["bio"], "Hello");
+}
+
+#[tokio::test]
+async fn authorization_headers_need_no_csrf_header() {
+    let app = cookie_app().await;
+    let (session, _, _) = register_user(app.clone(), "alice").await;
+
+    let credentials = Credentials {
+        authorization: Some(format!("Bearer {session}")),
+        ..Credentials::default()
+    };
+    let payload = json!({ "user": { "bio": "Hello" } });
+    let (status, _, _) = send(app, "PUT", "/api/user", &credentials, Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn cookie_sessions_refresh_and_log_out() {
+    let app = cookie_app().await;
+    let (session, refresh_token, csrf) = register_user(app.clone(), "alice").await;
+
+    let without_csrf = Credentials {
+        cookies: vec![("refresh_token", &refresh_token), ("csrf_token", &csrf)],
+        ..Credentials::default()
+    };
+    let (status, _, _) = send(
+        app.clone(),
+        "POST",
+        "/api/users/token/refresh",
+        &without_csrf,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let refreshing = Credentials {
+        csrf: Some(&csrf),
+        ..without_csrf
+    };
+    let (status, _, set_cookies) = send(
+        app.clone(),
+        "POST",
+        "/api/users/token/refresh",
+        &refreshing,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let new_session = cookie_value(&set_cookies, "session");
+    let new_csrf = cookie_value(&set_cookies, "csrf_token");
+    assert_ne!(cookie_value(&set_cookies, "refresh_token"), refresh_token);
+    assert_ne!(new_csrf, csrf);
+
+    let signed_in = Credentials {
+        cookies: vec![("session", &new_session), ("csrf_token", &new_csrf)],
+        csrf: Some(&new_csrf),
+        ..Credentials::default()
+    };
+    let (status, _, set_cookies) =
+        send(app.clone(), "POST", "/api/users/logout", &signed_in, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    for name in ["session", "refresh_token", "csrf_token"] {
+        assert!(set_cookie(&set_cookies, name).contains("Max-Age=0"));
+    }
+
+    let (status, _, _) = send(app.clone(), "GET", "/api/user", &signed_in, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let old_session = Credentials {
+        cookies: vec![("session", &session)],
+        ..Credentials::default()
+    };
+    let (status, _, _) = send(app, "GET", "/api/user", &old_session, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn cookies_are_ignored_unless_enabled() {
+    let app = test_app(&[]).await;
+    let payload = json!({
+        "user": { "username": "alice", "email": "alice@example.com", "password": "password123" }
+    });
+    let (status, body, set_cookies) = send(
+        app.clone(),
+        "POST",
+        "/api/users",
+        &Credentials::default(),
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(set_cookies.is_empty());
+
+    let (_, set_cookies) = login(app.clone(), "alice").await;
+    assert!(set_cookies.is_empty());
+
+    let token = body["user"]["token"].as_str().unwrap();
+    let credentials = Credentials {
+        cookies: vec![("session", token)],
+        ..Credentials::default()
+    };
+    let (status, _, _) = send(app, "GET", "/api/user", &credentials, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,252 @@
+#[allow(dead_code)]
+mod common;
+
+use aws_lc_rs::hmac;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use shining_clouds::application::create_app_state;
+use shining_clouds::http::router;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tower::ServiceExt;
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn test_app() -> axum::Router {
+    let config = common::create_test_config().await;
+    router(create_app_state(&config).await)
+}
+
+async fn register_user(app: axum::Router, username: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": format!("{username}@example.com"),
+            "password": "password123"
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+async fn login(app: axum::Router, username: &str) -> (StatusCode, serde_json::Value) {
+    let payload = json!({
+        "user": { "email": format!("{username}@example.com"), "password": "password123" }
+    });
+    send(app, "POST", "/api/users/login", None, Some(payload)).await
+}
+
+async fn login_two_factor(
+    app: axum::Router,
+    challenge: &str,
+    code: &str,
+) -> (StatusCode, serde_json::Value) {
+    let payload = json!({ "challengeToken": challenge, "code": code });
+    send(app, "POST", "/api/users/login/2fa", None, Some(payload)).await
+}
+
+fn base32_decode(encoded: &str) -> Vec<u8> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    let mut bytes = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for c in encoded.bytes() {
+        let value = ALPHABET.iter().position(|&a| a == c).unwrap() as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    bytes
+}
+
+fn current_step() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+        / 30
+}
+
+/// What an authenticator app would show for `step`.
+fn totp_code(secret: &str, step: i64) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, &base32_decode(secret));
+    let digest = hmac::sign(&key, &step.to_be_bytes());
+    let digest = digest.as_ref();
+    let offset = (digest[19] & 0x0f) as usize;
+    let value = u32::from_be_bytes(digest[offset..offset + 4].try_into().unwrap()) & 0x7fff_ffff;
+    format!("{:06}", value % 1_000_000)
+}
+
+/// Enrolls the user and returns their secret, the step of the code used to
+/// confirm, and the recovery codes.
+async fn enable_two_factor(app: axum::Router, token: &str) -> (String, i64, Vec<String>) {
+    let (status, body) = send(app.clone(), "POST", "/api/user/2fa", Some(token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let secret = body["secret"].as_str().unwrap().to_string();
+    assert!(
+        body["provisioningUri"]
+            .as_str()
+            .unwrap()
+            .starts_with("otpauth://totp/")
+    );
+
+    let step = current_step();
+    let (status, body) = send(
+        app,
+        "POST",
+        "/api/user/2fa/confirm",
+        Some(token),
+        Some(json!({ "code": totp_code(&secret, step) })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let recovery_codes = body["recoveryCodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|code| code.as_str().unwrap().to_string())
+        .collect();
+
+    (secret, step, recovery_codes)
+}
+
+#[tokio::test]
+async fn unconfirmed_enrollment_leaves_login_unchanged() {
+    let app = test_app().await;
+    let token = register_user(app.clone(), "alice").await;
+
+    let (status, body) = send(app.clone(), "POST", "/api/user/2fa", Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let secret = body["secret"].as_str().unwrap().to_string();
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/user/2fa/confirm",
+        Some(&token),
+        Some(json!({ "code": totp_code(&secret, current_step() + 10) })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, body) = login(app, "alice").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["user"]["token"].is_string());
+}
+
+#[tokio::test]
+async fn login_with_two_factor_takes_a_code() {
+    let app = test_app().await;
+    let token = register_user(app.clone(), "alice").await;
+    let (secret, step, recovery_codes) = enable_two_factor(app.clone(), &token).await;
+    assert_eq!(recovery_codes.len(), 10);
+
+    let (status, body) = login(app.clone(), "alice").await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert!(body["user"].is_null());
+    let challenge = body["challengeToken"].as_str().unwrap().to_string();
+
+    // The challenge is no access token.
+    let (status, _) = send(app.clone(), "GET", "/api/user", Some(&challenge), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = login_two_factor(app.clone(), &challenge, "000000").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // The code used to confirm has been spent.
+    let (status, _) = login_two_factor(app.clone(), &challenge, &totp_code(&secret, step)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let code = totp_code(&secret, step + 1);
+    let (status, body) = login_two_factor(app.clone(), &challenge, &code).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alice");
+    assert!(body["user"]["token"].is_string());
+
+    let (status, _) = login_two_factor(app, &challenge, &code).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn recovery_codes_work_once() {
+    let app = test_app().await;
+    let token = register_user(app.clone(), "alice").await;
+    let (_secret, _step, recovery_codes) = enable_two_factor(app.clone(), &token).await;
+
+    let (_, body) = login(app.clone(), "alice").await;
+    let challenge = body["challengeToken"].as_str().unwrap().to_string();
+
+    let typed = recovery_codes[0].to_uppercase().replace('-', " ");
+    let (status, _) = login_two_factor(app.clone(), &challenge, &typed).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = login_two_factor(app, &challenge, &recovery_codes[0]).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn disabling_restores_password_login() {
+    let app = test_app().await;
+    let token = register_user(app.clone(), "alice").await;
+    let (_secret, _step, recovery_codes) = enable_two_factor(app.clone(), &token).await;
+
+    let (status, _) = send(app.clone(), "POST", "/api/user/2fa", Some(&token), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/user/2fa/disable",
+        Some(&token),
+        Some(json!({ "code": "not-a-recovery-code" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/user/2fa/disable",
+        Some(&token),
+        Some(json!({ "code": recovery_codes[3] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, body) = login(app, "alice").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["user"]["token"].is_string());
+}